@@ -12,6 +12,60 @@ const OUT_FILE_WINDOWS_10: &str = "windows_10_build_to_release_map.rs";
 const IN_FILE_WINDOWS_SERVER_2019FF: &str = "maps/windows/windows_server_2019ff/map-release-to-build.json";
 const OUT_FILE_WINDOWS_SERVER_2019FF: &str = "windows_server_2019ff_build_to_release_map.rs";
 
+const IN_FILE_WINDOWS_8: &str = "maps/windows/windows_8/map-release-to-build.json";
+const OUT_FILE_WINDOWS_8: &str = "windows_8_build_to_release_map.rs";
+
+const IN_FILE_WINDOWS_7: &str = "maps/windows/windows_7/map-release-to-build.json";
+const OUT_FILE_WINDOWS_7: &str = "windows_7_build_to_release_map.rs";
+
+const IN_FILE_WINDOWS_VISTA: &str = "maps/windows/windows_vista/map-release-to-build.json";
+const OUT_FILE_WINDOWS_VISTA: &str = "windows_vista_build_to_release_map.rs";
+
+const IN_FILE_WINDOWS_XP: &str = "maps/windows/windows_xp/map-release-to-build.json";
+const OUT_FILE_WINDOWS_XP: &str = "windows_xp_build_to_release_map.rs";
+
+const IN_FILE_WINDOWS_SERVER_2003: &str = "maps/windows/windows_server_2003/map-release-to-build.json";
+const OUT_FILE_WINDOWS_SERVER_2003: &str = "windows_server_2003_build_to_release_map.rs";
+
+const IN_FILE_WINDOWS_SERVER_2008: &str = "maps/windows/windows_server_2008/map-release-to-build.json";
+const OUT_FILE_WINDOWS_SERVER_2008: &str = "windows_server_2008_build_to_release_map.rs";
+
+const IN_FILE_WINDOWS_SERVER_2008_R2: &str = "maps/windows/windows_server_2008_r2/map-release-to-build.json";
+const OUT_FILE_WINDOWS_SERVER_2008_R2: &str = "windows_server_2008_r2_build_to_release_map.rs";
+
+const IN_FILE_WINDOWS_SERVER_2012: &str = "maps/windows/windows_server_2012/map-release-to-build.json";
+const OUT_FILE_WINDOWS_SERVER_2012: &str = "windows_server_2012_build_to_release_map.rs";
+
+const IN_FILE_WINDOWS_SERVER_2012_R2: &str = "maps/windows/windows_server_2012_r2/map-release-to-build.json";
+const OUT_FILE_WINDOWS_SERVER_2012_R2: &str = "windows_server_2012_r2_build_to_release_map.rs";
+
+const IN_FILE_WINDOWS_SERVER_2016: &str = "maps/windows/windows_server_2016/map-release-to-build.json";
+const OUT_FILE_WINDOWS_SERVER_2016: &str = "windows_server_2016_build_to_release_map.rs";
+
+const IN_FILE_WINDOWS_SERVER_1709FF: &str = "maps/windows/windows_server_1709ff/map-release-to-build.json";
+const OUT_FILE_WINDOWS_SERVER_1709FF: &str = "windows_server_1709ff_build_to_release_map.rs";
+
+const IN_FILE_ORACLE_UEK: &str = "maps/linux/oracle_uek/map-release-to-kernel.json";
+const OUT_FILE_ORACLE_UEK: &str = "oracle_uek_kernel_to_release_map.rs";
+
+const IN_FILE_ESXI: &str = "maps/hypervisor/esxi/map-release-to-build.json";
+const OUT_FILE_ESXI: &str = "esxi_build_to_release_map.rs";
+
+const IN_FILE_HYPERV_SERVER: &str = "maps/hypervisor/hyperv_server/map-release-to-build.json";
+const OUT_FILE_HYPERV_SERVER: &str = "hyperv_server_build_to_release_map.rs";
+
+const IN_FILE_ANDROID: &str = "maps/mobile/android/map-release-to-api.json";
+const OUT_FILE_ANDROID: &str = "android_api_to_release_map.rs";
+
+const IN_FILE_IOS: &str = "maps/mobile/ios/map-release-to-build.json";
+const OUT_FILE_IOS: &str = "ios_build_to_release_map.rs";
+
+const IN_FILE_MAC_OS_X: &str = "maps/macos/mac_os_x/map-release-to-darwin.json";
+const OUT_FILE_MAC_OS_X: &str = "mac_os_x_darwin_to_release_map.rs";
+
+const IN_FILE_MACOS_11FF: &str = "maps/macos/macos_11ff/map-release-to-darwin.json";
+const OUT_FILE_MACOS_11FF: &str = "macos_11ff_darwin_to_release_map.rs";
+
 fn main() -> Result<(), serde_json::Error> {
     // Run if build.rs has changed
     println!("cargo:rerun-if-changed=build.rs");
@@ -19,15 +73,52 @@ fn main() -> Result<(), serde_json::Error> {
     println!("cargo:rerun-if-changed={}", IN_FILE_WINDOWS_11);
     println!("cargo:rerun-if-changed={}", IN_FILE_WINDOWS_10);
     println!("cargo:rerun-if-changed={}", IN_FILE_WINDOWS_SERVER_2019FF);
+    println!("cargo:rerun-if-changed={}", IN_FILE_WINDOWS_8);
+    println!("cargo:rerun-if-changed={}", IN_FILE_WINDOWS_7);
+    println!("cargo:rerun-if-changed={}", IN_FILE_WINDOWS_VISTA);
+    println!("cargo:rerun-if-changed={}", IN_FILE_WINDOWS_XP);
+    println!("cargo:rerun-if-changed={}", IN_FILE_WINDOWS_SERVER_2003);
+    println!("cargo:rerun-if-changed={}", IN_FILE_WINDOWS_SERVER_2008);
+    println!("cargo:rerun-if-changed={}", IN_FILE_WINDOWS_SERVER_2008_R2);
+    println!("cargo:rerun-if-changed={}", IN_FILE_WINDOWS_SERVER_2012);
+    println!("cargo:rerun-if-changed={}", IN_FILE_WINDOWS_SERVER_2012_R2);
+    println!("cargo:rerun-if-changed={}", IN_FILE_WINDOWS_SERVER_2016);
+    println!("cargo:rerun-if-changed={}", IN_FILE_WINDOWS_SERVER_1709FF);
+    println!("cargo:rerun-if-changed={}", IN_FILE_ORACLE_UEK);
+    println!("cargo:rerun-if-changed={}", IN_FILE_ESXI);
+    println!("cargo:rerun-if-changed={}", IN_FILE_HYPERV_SERVER);
+    println!("cargo:rerun-if-changed={}", IN_FILE_ANDROID);
+    println!("cargo:rerun-if-changed={}", IN_FILE_IOS);
+    println!("cargo:rerun-if-changed={}", IN_FILE_MAC_OS_X);
+    println!("cargo:rerun-if-changed={}", IN_FILE_MACOS_11FF);
 
-    let _ = run(IN_FILE_WINDOWS_11, OUT_FILE_WINDOWS_11);
-    let _ = run(IN_FILE_WINDOWS_10, OUT_FILE_WINDOWS_10);
-    let _ = run(IN_FILE_WINDOWS_SERVER_2019FF, OUT_FILE_WINDOWS_SERVER_2019FF);
+    let _ = run(IN_FILE_WINDOWS_11, OUT_FILE_WINDOWS_11, true);
+    let _ = run(IN_FILE_WINDOWS_10, OUT_FILE_WINDOWS_10, true);
+    let _ = run(IN_FILE_WINDOWS_SERVER_2019FF, OUT_FILE_WINDOWS_SERVER_2019FF, true);
+    let _ = run(IN_FILE_WINDOWS_8, OUT_FILE_WINDOWS_8, true);
+    let _ = run(IN_FILE_WINDOWS_7, OUT_FILE_WINDOWS_7, true);
+    let _ = run(IN_FILE_WINDOWS_VISTA, OUT_FILE_WINDOWS_VISTA, true);
+    let _ = run(IN_FILE_WINDOWS_XP, OUT_FILE_WINDOWS_XP, true);
+    let _ = run(IN_FILE_WINDOWS_SERVER_2003, OUT_FILE_WINDOWS_SERVER_2003, true);
+    let _ = run(IN_FILE_WINDOWS_SERVER_2008, OUT_FILE_WINDOWS_SERVER_2008, true);
+    let _ = run(IN_FILE_WINDOWS_SERVER_2008_R2, OUT_FILE_WINDOWS_SERVER_2008_R2, true);
+    let _ = run(IN_FILE_WINDOWS_SERVER_2012, OUT_FILE_WINDOWS_SERVER_2012, true);
+    let _ = run(IN_FILE_WINDOWS_SERVER_2012_R2, OUT_FILE_WINDOWS_SERVER_2012_R2, true);
+    let _ = run(IN_FILE_WINDOWS_SERVER_2016, OUT_FILE_WINDOWS_SERVER_2016, true);
+    let _ = run(IN_FILE_WINDOWS_SERVER_1709FF, OUT_FILE_WINDOWS_SERVER_1709FF, true);
+    let _ = run(IN_FILE_ORACLE_UEK, OUT_FILE_ORACLE_UEK, true);
+    let _ = run(IN_FILE_ESXI, OUT_FILE_ESXI, true);
+    let _ = run(IN_FILE_HYPERV_SERVER, OUT_FILE_HYPERV_SERVER, true);
+    let _ = run(IN_FILE_ANDROID, OUT_FILE_ANDROID, true);
+    let _ = run(IN_FILE_IOS, OUT_FILE_IOS, false);
+    let _ = run(IN_FILE_MAC_OS_X, OUT_FILE_MAC_OS_X, true);
+    let _ = run(IN_FILE_MACOS_11FF, OUT_FILE_MACOS_11FF, true);
 
     Ok(())
 }
 
-fn run(input: &str, output: &str) -> Result<(), serde_json::Error> {
+// The release pattern is generated for parsers identifying a release by its name
+fn run(input: &str, output: &str, with_release_pattern: bool) -> Result<(), serde_json::Error> {
     // Create output file
     let out_dir = std::env::var("OUT_DIR")
         .expect("Error: env variable OUT_DIR not set.");
@@ -45,7 +136,7 @@ fn run(input: &str, output: &str) -> Result<(), serde_json::Error> {
     let release_to_builds: HashMap<String, Vec<String>> = serde_json::from_reader(reader)?;
 
     // Turn release to builds map, into a build to release map
-    let build_to_release = release_to_builds.iter()
+    let mut build_to_release = release_to_builds.iter()
         .flat_map(|(os, versions)| {
             versions.iter().map(move |version| (version.as_str(), os.as_str()))
         })
@@ -54,14 +145,23 @@ fn run(input: &str, output: &str) -> Result<(), serde_json::Error> {
             acc
         });
 
-    let release_pattern = format!("{}", release_to_builds.keys().map(|s| s.as_str()).collect::<Vec<_>>().join("|"));
+    // A build shared by several releases resolves to the first of them, e.g. GA before SP1
+    build_to_release.values_mut().for_each(|releases| releases.sort());
+
+    // Longest release first, so that "2012 R2" is tried before "2012"
+    let mut releases = release_to_builds.keys().map(|s| s.as_str()).collect::<Vec<_>>();
+    releases.sort_by(|a, b| b.len().cmp(&a.len()).then(a.cmp(b)));
+    let release_pattern = releases.join("|");
 
     // Create const declarations
-    let const_declarations = vec!
+    let mut const_declarations = vec!
     {
         const_declaration!(BUILD_TO_RELEASE_MAP = build_to_release),
-        const_declaration!(RELEASE_PATTERN = release_pattern),
-    }.join("\n");
+    };
+    if with_release_pattern {
+        const_declarations.push(const_declaration!(RELEASE_PATTERN = release_pattern));
+    }
+    let const_declarations = const_declarations.join("\n");
 
     // Lastly, write to output file
     out_file_handle.write_all(const_declarations.as_bytes())
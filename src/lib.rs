@@ -21,9 +21,17 @@
 //!     assert!(os.to_string().contains(&String::from("Microsoft Windows 11 Pro 24H2")));
 //! }
 //! ```
+
+// Service channels and editions are named as their vendors write them, e.g. LTS, ESM or MATE
+#![allow(clippy::upper_case_acronyms)]
+
 mod model;
 pub use model::OS;
+pub use model::BSD;
+pub use model::Hypervisor;
 pub use model::Linux;
+pub use model::MacOS;
+pub use model::Mobile;
 pub use model::Windows;
 
 mod parser;
@@ -0,0 +1,195 @@
+//
+// https://www.freebsd.org/releases/
+// https://www.freebsd.org/security/#sup
+//
+const VENDOR: &str = "The FreeBSD Project";
+const PRODUCT: &str = "FreeBSD";
+
+#[derive(Debug)]
+pub(crate) struct FreeBSD {
+    vendor: String,
+    product: String,
+    release: Release,
+    branch: Branch,
+    patch: Option<Patch>,
+}
+
+impl FreeBSD {
+    pub(crate) fn build(release: Release, branch: Branch) -> FreeBSD {
+        FreeBSD {
+            vendor: VENDOR.to_string(),
+            product: PRODUCT.to_string(),
+            release,
+            branch,
+            patch: None,
+        }
+    }
+
+    pub(crate) fn patch(mut self, patch: Patch) -> Self {
+        self.patch = Some(patch);
+        self
+    }
+
+    pub(super) fn vendor(&self) -> &str {
+        self.vendor.as_str()
+    }
+
+    pub(super) fn product(&self) -> &str {
+        self.product.as_str()
+    }
+
+    pub(super) fn release(&self) -> String {
+        self.release.to_string()
+    }
+
+    pub(super) fn is_enterprise(&self) -> bool {
+        false
+    }
+
+    pub(super) fn is_lts(&self) -> bool {
+        false
+    }
+
+    pub(super) fn to_string(&self) -> Vec<String> {
+        let out = match &self.patch {
+            Some(patch) => format!("{} {}-{}-{patch}", self.product, self.release, self.branch),
+            None => format!("{} {}-{}", self.product, self.release, self.branch),
+        };
+
+        vec![out]
+    }
+}
+
+impl TryFrom<&str> for FreeBSD {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        if let Ok(label) = crate::parser::endoflife::EndOfLifeLabel::try_from(value) {
+            crate::parser::endoflife::bsd::FreeBSDParser::parse(&label)
+        } else {
+            let label = crate::parser::generic::GenericLabel::from(value);
+            crate::parser::generic::bsd::FreeBSDParser::parse(&label)
+        }
+    }
+}
+
+/// Release of FreeBSD, e.g. 14.1 for a release or 14 for a stable branch.
+#[derive(Debug)]
+pub(crate) struct Release(String);
+
+impl From<&str> for Release {
+    fn from(value: &str) -> Self {
+        Release(value.to_string())
+    }
+}
+
+impl std::fmt::Display for Release {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Security patch level of a release, e.g. p5.
+#[derive(Debug)]
+pub(crate) struct Patch(String);
+
+impl From<&str> for Patch {
+    fn from(value: &str) -> Self {
+        Patch(value.to_lowercase())
+    }
+}
+
+impl std::fmt::Display for Patch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[derive(PartialEq, Debug, Default)]
+pub(crate) enum Branch {
+    // Point release built from a releng branch, e.g. 14.1-RELEASE
+    #[default]
+    Release,
+    // Stable branch of a major release, e.g. 14-STABLE
+    Stable,
+    // Development branch (main)
+    Current,
+}
+
+impl std::fmt::Display for Branch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let out = match self {
+            Branch::Release => "RELEASE",
+            Branch::Stable => "STABLE",
+            Branch::Current => "CURRENT",
+        };
+
+        write!(f, "{}", out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_string_1() {
+        let label = FreeBSD::try_from("freebsd-14.1").unwrap();
+
+        assert_eq!(label.vendor, "The FreeBSD Project".to_string());
+        assert_eq!(label.product, "FreeBSD".to_string());
+        assert_eq!(label.release.to_string(), "14.1".to_string());
+        assert_eq!(label.branch, Branch::Release);
+        assert_eq!(label.to_string(), vec!["FreeBSD 14.1-RELEASE".to_string()]);
+    }
+
+    #[test]
+    fn test_from_string_2() {
+        let label = FreeBSD::try_from("freebsd-14").unwrap();
+
+        assert_eq!(label.release.to_string(), "14".to_string());
+        assert_eq!(label.branch, Branch::Stable);
+        assert_eq!(label.to_string(), vec!["FreeBSD 14-STABLE".to_string()]);
+    }
+
+    #[test]
+    fn test_from_string_arbitrary1() {
+        let label = FreeBSD::try_from("FreeBSD 13.3-RELEASE").unwrap();
+
+        assert_eq!(label.release.to_string(), "13.3".to_string());
+        assert_eq!(label.branch, Branch::Release);
+        assert!(label.patch.is_none());
+    }
+
+    #[test]
+    fn test_from_string_arbitrary2() {
+        let label = FreeBSD::try_from("FreeBSD fw01 14.1-RELEASE-p5 FreeBSD 14.1-RELEASE-p5 GENERIC amd64").unwrap();
+
+        assert_eq!(label.release.to_string(), "14.1".to_string());
+        assert_eq!(label.branch, Branch::Release);
+        assert_eq!(label.patch.as_ref().unwrap().to_string(), "p5".to_string());
+        assert_eq!(label.to_string(), vec!["FreeBSD 14.1-RELEASE-p5".to_string()]);
+    }
+
+    #[test]
+    fn test_from_string_arbitrary3() {
+        let label = FreeBSD::try_from("FreeBSD 14.1-STABLE").unwrap();
+
+        assert_eq!(label.branch, Branch::Stable);
+    }
+
+    #[test]
+    fn test_from_string_arbitrary4() {
+        let label = FreeBSD::try_from("FreeBSD 15.0-CURRENT").unwrap();
+
+        assert_eq!(label.release.to_string(), "15.0".to_string());
+        assert_eq!(label.branch, Branch::Current);
+        assert_eq!(label.to_string(), vec!["FreeBSD 15.0-CURRENT".to_string()]);
+    }
+
+    #[test]
+    fn test_from_string_arbitrary_derivative() {
+        assert!(FreeBSD::try_from("FreeBSD-based pfSense 2.7").is_err());
+        assert!(FreeBSD::try_from("pfSense 2.7 on FreeBSD 14.0").is_err());
+    }
+}
@@ -0,0 +1,67 @@
+pub(crate) mod freebsd;
+pub(crate) use freebsd::FreeBSD;
+
+// OpenBSD and NetBSD are to be added as further variants.
+#[derive(Debug)]
+pub(crate) enum BSD {
+    FreeBSD(FreeBSD),
+}
+
+impl BSD {
+    pub fn to_string(&self) -> Vec<String> {
+        match self {
+            BSD::FreeBSD(bsd) => bsd.to_string(),
+        }
+    }
+
+    pub fn vendor(&self) -> String {
+        match self {
+            BSD::FreeBSD(bsd) => String::from(bsd.vendor()),
+        }
+    }
+
+    pub fn product(&self) -> String {
+        match self {
+            BSD::FreeBSD(bsd) => String::from(bsd.product()),
+        }
+    }
+
+    pub fn release(&self) -> String {
+        match self {
+            BSD::FreeBSD(bsd) => bsd.release(),
+        }
+    }
+
+    pub fn release_components(&self) -> (Option<u32>, Option<u32>, Option<u32>) {
+        crate::util::release_components(&self.release())
+    }
+
+    // FreeBSD is tracked by minor release, e.g. 14.1
+    pub fn release_cycle(&self) -> String {
+        crate::util::truncate_release(&self.release(), 2)
+    }
+
+    pub fn is_enterprise(&self) -> bool {
+        match self {
+            BSD::FreeBSD(bsd) => bsd.is_enterprise(),
+        }
+    }
+
+    pub fn is_lts(&self) -> bool {
+        match self {
+            BSD::FreeBSD(bsd) => bsd.is_lts(),
+        }
+    }
+}
+
+impl TryFrom<&str> for BSD {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        if let Ok(bsd) = FreeBSD::try_from(value) {
+            Ok(BSD::FreeBSD(bsd))
+        } else {
+            Err(format!("Not a BSD: {}", value))
+        }
+    }
+}
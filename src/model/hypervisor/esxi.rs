@@ -0,0 +1,175 @@
+//
+// https://knowledge.broadcom.com/external/article?legacyId=2143832
+//
+const VENDOR: &str = "VMware";
+const PRODUCT: &str = "VMware ESXi";
+
+#[derive(Debug)]
+pub(crate) struct VMwareESXi {
+    vendor: String,
+    product: String,
+    release: Release,
+    build: Option<String>,
+}
+
+impl VMwareESXi {
+    pub(crate) fn build(release: Release) -> VMwareESXi {
+        VMwareESXi {
+            vendor: VENDOR.to_string(),
+            product: PRODUCT.to_string(),
+            release,
+            build: None,
+        }
+    }
+
+    pub(crate) fn installed_build(mut self, build: &str) -> Self {
+        self.build = Some(build.to_string());
+        self
+    }
+
+    pub(super) fn vendor(&self) -> &str {
+        self.vendor.as_str()
+    }
+
+    pub(super) fn product(&self) -> &str {
+        self.product.as_str()
+    }
+
+    pub(super) fn release(&self) -> String {
+        self.release.to_string()
+    }
+
+    pub(super) fn build_number(&self) -> Option<String> {
+        self.build.clone()
+    }
+
+    pub(super) fn is_enterprise(&self) -> bool {
+        true
+    }
+
+    pub(super) fn is_lts(&self) -> bool {
+        false
+    }
+
+    pub(super) fn to_string(&self) -> Vec<String> {
+        vec![format!("{} {}", self.product, self.release)]
+    }
+}
+
+impl TryFrom<&str> for VMwareESXi {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        if let Ok(label) = crate::parser::endoflife::EndOfLifeLabel::try_from(value) {
+            crate::parser::endoflife::hypervisor::VMwareESXiParser::parse(&label)
+        } else {
+            let label = crate::parser::generic::GenericLabel::from(value);
+            crate::parser::generic::hypervisor::VMwareESXiParser::parse(&label)
+        }
+    }
+}
+
+/// Release of ESXi including its update level, e.g. 8.0 U3.
+#[derive(Debug)]
+pub(crate) struct Release(String);
+
+impl From<&str> for Release {
+    fn from(value: &str) -> Self {
+        Release(value.to_string())
+    }
+}
+
+impl std::fmt::Display for Release {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_string_1() {
+        let label = VMwareESXi::try_from("esxi-8.0").unwrap();
+
+        assert_eq!(label.vendor, "VMware".to_string());
+        assert_eq!(label.product, "VMware ESXi".to_string());
+        assert_eq!(label.release.to_string(), "8.0".to_string());
+        assert_eq!(label.to_string(), vec!["VMware ESXi 8.0".to_string()]);
+    }
+
+    #[test]
+    fn test_from_string_arbitrary1() {
+        let label = VMwareESXi::try_from("VMware ESXi 8.0.3 build-24022510").unwrap();
+
+        assert_eq!(label.release.to_string(), "8.0 U3".to_string());
+        assert_eq!(label.build, Some("24022510".to_string()));
+        assert_eq!(label.to_string(), vec!["VMware ESXi 8.0 U3".to_string()]);
+    }
+
+    #[test]
+    fn test_from_string_arbitrary2() {
+        let label = VMwareESXi::try_from("VMware ESXi 7.0 Update 2").unwrap();
+
+        assert_eq!(label.release.to_string(), "7.0 U2".to_string());
+        assert!(label.build.is_none());
+    }
+
+    #[test]
+    fn test_from_string_arbitrary3() {
+        let label = VMwareESXi::try_from("VMware ESXi 6.7.0 build-8169922").unwrap();
+
+        assert_eq!(label.release.to_string(), "6.7".to_string());
+    }
+
+    #[test]
+    fn test_from_string_arbitrary4() {
+        // Patch release of an update, 8.0 U2b
+        let label = VMwareESXi::try_from("VMware ESXi 8.0.2 build-23305546").unwrap();
+
+        assert_eq!(label.release.to_string(), "8.0 U2".to_string());
+        assert_eq!(label.build, Some("23305546".to_string()));
+    }
+
+    #[test]
+    fn test_from_string_arbitrary5() {
+        assert!(VMwareESXi::try_from("VMware Workstation 17.5").is_err());
+    }
+
+    #[test]
+    fn test_from_string_arbitrary_unknown_build() {
+        // A build newer than the map falls back to the version
+        let label = VMwareESXi::try_from("VMware ESXi 7.0.3 build-99999999").unwrap();
+
+        assert_eq!(label.release.to_string(), "7.0 U3".to_string());
+        assert_eq!(label.build, Some("99999999".to_string()));
+
+        assert!(VMwareESXi::try_from("VMware ESXi build-99999999").is_err());
+        assert!(VMwareESXi::try_from("VMware ESXi 7.0.2 build-21930508").is_err());
+
+        // The build refines a version without update
+        let label = VMwareESXi::try_from("VMware ESXi 7.0.0 build-21930508").unwrap();
+
+        assert_eq!(label.release.to_string(), "7.0 U3".to_string());
+    }
+
+    #[test]
+    fn test_from_string_arbitrary_version() {
+        let label = VMwareESXi::try_from("VMware ESXi 8.0U3").unwrap();
+
+        assert_eq!(label.release.to_string(), "8.0 U3".to_string());
+
+        let label = VMwareESXi::try_from("VMware ESXi 8.0 U3c").unwrap();
+
+        assert_eq!(label.release.to_string(), "8.0 U3".to_string());
+
+        // The version follows the product name
+        let label = VMwareESXi::try_from("Ubuntu 22.04 on VMware ESXi 7.0").unwrap();
+
+        assert_eq!(label.release.to_string(), "7.0".to_string());
+
+        assert!(VMwareESXi::try_from("VMware ESXi 7.0 U9").is_err());
+        assert!(VMwareESXi::try_from("VMware ESXi 9.9").is_err());
+    }
+}
@@ -0,0 +1,106 @@
+//
+// https://learn.microsoft.com/en-us/windows-server/get-started/hyper-v-server-2019
+//
+const VENDOR: &str = "Microsoft";
+const PRODUCT: &str = "Hyper-V Server";
+
+#[derive(Debug)]
+pub(crate) struct HyperVServer {
+    vendor: String,
+    product: String,
+    release: Release,
+}
+
+impl HyperVServer {
+    pub(crate) fn build(release: Release) -> HyperVServer {
+        HyperVServer {
+            vendor: VENDOR.to_string(),
+            product: PRODUCT.to_string(),
+            release,
+        }
+    }
+
+    pub(super) fn vendor(&self) -> &str {
+        self.vendor.as_str()
+    }
+
+    pub(super) fn product(&self) -> &str {
+        self.product.as_str()
+    }
+
+    pub(super) fn release(&self) -> String {
+        self.release.to_string()
+    }
+
+    pub(super) fn is_enterprise(&self) -> bool {
+        false
+    }
+
+    pub(super) fn is_lts(&self) -> bool {
+        true
+    }
+
+    pub(super) fn to_string(&self) -> Vec<String> {
+        vec![format!("{} {} {}", self.vendor, self.product, self.release)]
+    }
+}
+
+impl TryFrom<&str> for HyperVServer {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        // Hyper-V Server is not tracked by endoflife.date
+        let label = crate::parser::generic::GenericLabel::from(value);
+        crate::parser::generic::hypervisor::HyperVServerParser::parse(&label)
+    }
+}
+
+/// Release of Hyper-V Server, e.g. 2012 R2.
+#[derive(Debug)]
+pub(crate) struct Release(String);
+
+impl From<&str> for Release {
+    fn from(value: &str) -> Self {
+        Release(value.to_string())
+    }
+}
+
+impl std::fmt::Display for Release {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_string_arbitrary1() {
+        let label = HyperVServer::try_from("Microsoft Hyper-V Server 2019").unwrap();
+
+        assert_eq!(label.vendor, "Microsoft".to_string());
+        assert_eq!(label.product, "Hyper-V Server".to_string());
+        assert_eq!(label.release.to_string(), "2019".to_string());
+        assert_eq!(label.to_string(), vec!["Microsoft Hyper-V Server 2019".to_string()]);
+    }
+
+    #[test]
+    fn test_from_string_arbitrary2() {
+        let label = HyperVServer::try_from("Microsoft Hyper-V Server 2012 R2").unwrap();
+
+        assert_eq!(label.release.to_string(), "2012 R2".to_string());
+    }
+
+    #[test]
+    fn test_from_string_arbitrary3() {
+        let label = HyperVServer::try_from("Hyper-V Server 10.0.14393").unwrap();
+
+        assert_eq!(label.release.to_string(), "2016".to_string());
+    }
+
+    #[test]
+    fn test_from_string_arbitrary4() {
+        assert!(HyperVServer::try_from("Windows Server 2019 Datacenter").is_err());
+    }
+}
@@ -0,0 +1,120 @@
+pub(crate) mod esxi;
+pub(crate) use esxi::VMwareESXi;
+
+pub(crate) mod hyperv_server;
+pub(crate) use hyperv_server::HyperVServer;
+
+pub(crate) mod proxmox;
+pub(crate) use proxmox::ProxmoxVE;
+
+pub(crate) mod xenserver;
+pub(crate) use xenserver::XenServer;
+
+#[derive(Debug)]
+pub(crate) enum Hypervisor {
+    HyperVServer(HyperVServer),
+    ProxmoxVE(ProxmoxVE),
+    VMwareESXi(VMwareESXi),
+    XenServer(XenServer),
+}
+
+impl Hypervisor {
+    pub fn to_string(&self) -> Vec<String> {
+        match self {
+            Hypervisor::HyperVServer(h) => h.to_string(),
+            Hypervisor::ProxmoxVE(h) => h.to_string(),
+            Hypervisor::VMwareESXi(h) => h.to_string(),
+            Hypervisor::XenServer(h) => h.to_string(),
+        }
+    }
+
+    pub fn vendor(&self) -> String {
+        match self {
+            Hypervisor::HyperVServer(h) => String::from(h.vendor()),
+            Hypervisor::ProxmoxVE(h) => String::from(h.vendor()),
+            Hypervisor::VMwareESXi(h) => String::from(h.vendor()),
+            Hypervisor::XenServer(h) => String::from(h.vendor()),
+        }
+    }
+
+    pub fn product(&self) -> String {
+        match self {
+            Hypervisor::HyperVServer(h) => String::from(h.product()),
+            Hypervisor::ProxmoxVE(h) => String::from(h.product()),
+            Hypervisor::VMwareESXi(h) => String::from(h.product()),
+            Hypervisor::XenServer(h) => String::from(h.product()),
+        }
+    }
+
+    pub fn release(&self) -> String {
+        match self {
+            Hypervisor::HyperVServer(h) => h.release(),
+            Hypervisor::ProxmoxVE(h) => h.release(),
+            Hypervisor::VMwareESXi(h) => h.release(),
+            Hypervisor::XenServer(h) => h.release(),
+        }
+    }
+
+    // ESXi names the point release an update, e.g. 7.0 U3; Hyper-V Server is named after a year
+    pub fn release_components(&self) -> (Option<u32>, Option<u32>, Option<u32>) {
+        match self {
+            Hypervisor::HyperVServer(_) => (None, None, None),
+            Hypervisor::VMwareESXi(h) => crate::util::release_components(&h.release().replace(" U", ".")),
+            _ => crate::util::release_components(&self.release()),
+        }
+    }
+
+    // Release cycle endoflife.date tracks the product by, e.g. 7.0 for ESXi 7.0 U3 or 8 for Proxmox VE 8.2
+    pub fn release_cycle(&self) -> String {
+        match self {
+            Hypervisor::HyperVServer(h) => h.release(),
+            Hypervisor::ProxmoxVE(h) => crate::util::truncate_release(&h.release(), 1),
+            Hypervisor::VMwareESXi(h) => crate::util::truncate_release(&h.release().replace(" U", "."), 2),
+            Hypervisor::XenServer(h) => crate::util::truncate_release(&h.release(), 2),
+        }
+    }
+
+    // Build number the update level was resolved from, e.g. 24022510 for ESXi 8.0 U3
+    pub fn build(&self) -> Option<String> {
+        match self {
+            Hypervisor::VMwareESXi(h) => h.build_number(),
+            _ => None,
+        }
+    }
+
+    pub fn is_enterprise(&self) -> bool {
+        match self {
+            Hypervisor::HyperVServer(h) => h.is_enterprise(),
+            Hypervisor::ProxmoxVE(h) => h.is_enterprise(),
+            Hypervisor::VMwareESXi(h) => h.is_enterprise(),
+            Hypervisor::XenServer(h) => h.is_enterprise(),
+        }
+    }
+
+    pub fn is_lts(&self) -> bool {
+        match self {
+            Hypervisor::HyperVServer(h) => h.is_lts(),
+            Hypervisor::ProxmoxVE(h) => h.is_lts(),
+            Hypervisor::VMwareESXi(h) => h.is_lts(),
+            Hypervisor::XenServer(h) => h.is_lts(),
+        }
+    }
+}
+
+impl TryFrom<&str> for Hypervisor {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        if let Ok(esxi) = VMwareESXi::try_from(value) {
+            Ok(Hypervisor::VMwareESXi(esxi))
+        } else if let Ok(proxmox) = ProxmoxVE::try_from(value) {
+            Ok(Hypervisor::ProxmoxVE(proxmox))
+        } else if let Ok(xenserver) = XenServer::try_from(value) {
+            Ok(Hypervisor::XenServer(xenserver))
+        } else if let Ok(hyperv) = HyperVServer::try_from(value) {
+            Ok(Hypervisor::HyperVServer(hyperv))
+        } else {
+            Err(format!("Not a hypervisor: {}", value))
+        }
+    }
+}
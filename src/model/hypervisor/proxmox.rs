@@ -0,0 +1,112 @@
+//
+// https://pve.proxmox.com/wiki/FAQ
+//
+const VENDOR: &str = "Proxmox";
+const PRODUCT: &str = "Proxmox VE";
+
+#[derive(Debug)]
+pub(crate) struct ProxmoxVE {
+    vendor: String,
+    product: String,
+    release: Release,
+}
+
+impl ProxmoxVE {
+    pub(crate) fn build(release: Release) -> ProxmoxVE {
+        ProxmoxVE {
+            vendor: VENDOR.to_string(),
+            product: PRODUCT.to_string(),
+            release,
+        }
+    }
+
+    pub(super) fn vendor(&self) -> &str {
+        self.vendor.as_str()
+    }
+
+    pub(super) fn product(&self) -> &str {
+        self.product.as_str()
+    }
+
+    pub(super) fn release(&self) -> String {
+        self.release.to_string()
+    }
+
+    pub(super) fn is_enterprise(&self) -> bool {
+        false
+    }
+
+    pub(super) fn is_lts(&self) -> bool {
+        false
+    }
+
+    pub(super) fn to_string(&self) -> Vec<String> {
+        vec![format!("{} {}", self.product, self.release)]
+    }
+}
+
+impl TryFrom<&str> for ProxmoxVE {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        if let Ok(label) = crate::parser::endoflife::EndOfLifeLabel::try_from(value) {
+            crate::parser::endoflife::hypervisor::ProxmoxVEParser::parse(&label)
+        } else {
+            let label = crate::parser::generic::GenericLabel::from(value);
+            crate::parser::generic::hypervisor::ProxmoxVEParser::parse(&label)
+        }
+    }
+}
+
+/// Release of Proxmox VE, e.g. 8.2 or 8 for a release cycle.
+#[derive(Debug)]
+pub(crate) struct Release(String);
+
+impl From<&str> for Release {
+    fn from(value: &str) -> Self {
+        Release(value.to_string())
+    }
+}
+
+impl std::fmt::Display for Release {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_string_1() {
+        let label = ProxmoxVE::try_from("proxmox-ve-8").unwrap();
+
+        assert_eq!(label.vendor, "Proxmox".to_string());
+        assert_eq!(label.product, "Proxmox VE".to_string());
+        assert_eq!(label.release.to_string(), "8".to_string());
+        assert_eq!(label.to_string(), vec!["Proxmox VE 8".to_string()]);
+    }
+
+    #[test]
+    fn test_from_string_arbitrary1() {
+        let label = ProxmoxVE::try_from("Proxmox VE 8.2").unwrap();
+
+        assert_eq!(label.release.to_string(), "8.2".to_string());
+    }
+
+    #[test]
+    fn test_from_string_arbitrary2() {
+        let label = ProxmoxVE::try_from("pve-manager/8.2.4/faa83925c9641325 (running kernel: 6.8.12-1-pve)").unwrap();
+
+        assert_eq!(label.release.to_string(), "8.2.4".to_string());
+        assert_eq!(label.to_string(), vec!["Proxmox VE 8.2.4".to_string()]);
+    }
+
+    #[test]
+    fn test_from_string_arbitrary3() {
+        let label = ProxmoxVE::try_from("Proxmox Virtual Environment 7.4-3").unwrap();
+
+        assert_eq!(label.release.to_string(), "7.4".to_string());
+    }
+}
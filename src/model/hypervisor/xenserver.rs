@@ -0,0 +1,137 @@
+//
+// https://docs.xenserver.com/en-us/xenserver/8/whats-new
+// https://www.citrix.com/support/product-lifecycle/product-matrix.html
+//
+const VENDOR: &str = "Citrix";
+
+// Long Term Service Releases, serviced by cumulative updates
+const LTSR_RELEASES: [&str; 2] = ["7.1", "8.2"];
+
+#[derive(Debug)]
+pub(crate) struct XenServer {
+    vendor: String,
+    product: Product,
+    release: Release,
+    cumulative_update: Option<String>,
+}
+
+impl XenServer {
+    pub(crate) fn build(product: Product, release: Release) -> XenServer {
+        XenServer {
+            vendor: VENDOR.to_string(),
+            product,
+            release,
+            cumulative_update: None,
+        }
+    }
+
+    pub(crate) fn cumulative_update(mut self, cumulative_update: &str) -> Self {
+        self.cumulative_update = Some(cumulative_update.to_uppercase());
+        self
+    }
+
+    pub(super) fn vendor(&self) -> &str {
+        self.vendor.as_str()
+    }
+
+    pub(super) fn product(&self) -> &str {
+        self.product.as_str()
+    }
+
+    pub(super) fn release(&self) -> String {
+        self.release.to_string()
+    }
+
+    pub(super) fn is_enterprise(&self) -> bool {
+        true
+    }
+
+    pub(super) fn is_lts(&self) -> bool {
+        self.cumulative_update.is_some() || LTSR_RELEASES.contains(&self.release.0.as_str())
+    }
+
+    pub(super) fn to_string(&self) -> Vec<String> {
+        let out = match &self.cumulative_update {
+            Some(cu) => format!("{} {} {}", self.product.as_str(), self.release, cu),
+            None => format!("{} {}", self.product.as_str(), self.release),
+        };
+
+        vec![out]
+    }
+}
+
+impl TryFrom<&str> for XenServer {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        // XenServer is not tracked by endoflife.date
+        let label = crate::parser::generic::GenericLabel::from(value);
+        crate::parser::generic::hypervisor::XenServerParser::parse(&label)
+    }
+}
+
+/// The product was named Citrix Hypervisor from 8.0 to 8.2 and XenServer before and after.
+#[derive(PartialEq, Debug)]
+pub(crate) enum Product {
+    CitrixHypervisor,
+    XenServer,
+}
+
+impl Product {
+    fn as_str(&self) -> &str {
+        match self {
+            Product::CitrixHypervisor => "Citrix Hypervisor",
+            Product::XenServer => "XenServer",
+        }
+    }
+}
+
+/// Release of XenServer, e.g. 8.2 or 8.
+#[derive(Debug)]
+pub(crate) struct Release(String);
+
+impl From<&str> for Release {
+    fn from(value: &str) -> Self {
+        Release(value.to_string())
+    }
+}
+
+impl std::fmt::Display for Release {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_string_arbitrary1() {
+        let label = XenServer::try_from("Citrix Hypervisor 8.2 CU1").unwrap();
+
+        assert_eq!(label.vendor, "Citrix".to_string());
+        assert_eq!(label.product, Product::CitrixHypervisor);
+        assert_eq!(label.release.to_string(), "8.2".to_string());
+        assert!(label.is_lts());
+        assert_eq!(label.to_string(), vec!["Citrix Hypervisor 8.2 CU1".to_string()]);
+    }
+
+    #[test]
+    fn test_from_string_arbitrary2() {
+        let label = XenServer::try_from("XenServer 8").unwrap();
+
+        assert_eq!(label.product, Product::XenServer);
+        assert_eq!(label.release.to_string(), "8".to_string());
+        assert!(!label.is_lts());
+        assert_eq!(label.to_string(), vec!["XenServer 8".to_string()]);
+    }
+
+    #[test]
+    fn test_from_string_arbitrary3() {
+        let label = XenServer::try_from("Citrix XenServer 7.1.2").unwrap();
+
+        assert_eq!(label.product, Product::XenServer);
+        assert_eq!(label.release.to_string(), "7.1.2".to_string());
+    }
+}
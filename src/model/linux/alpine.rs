@@ -0,0 +1,180 @@
+//
+// https://alpinelinux.org/releases/
+//
+const VENDOR: &str = "Alpine";
+const PRODUCT: &str = "Alpine Linux";
+
+#[derive(Debug)]
+pub(crate) struct AlpineLinux {
+    vendor: String,
+    product: String,
+    release: Release,
+    #[allow(dead_code)]
+    service_channel: ServiceChannel,
+}
+
+impl AlpineLinux {
+    pub(crate) fn build(release: Release, service_channel: ServiceChannel) -> AlpineLinux {
+        AlpineLinux {
+            vendor: VENDOR.to_string(),
+            product: PRODUCT.to_string(),
+            release,
+            service_channel,
+        }
+    }
+
+    pub(super) fn vendor(&self) -> &str {
+        self.vendor.as_str()
+    }
+
+    pub(super) fn product(&self) -> &str {
+        self.product.as_str()
+    }
+
+    pub(super) fn release(&self) -> String {
+        self.release.to_string()
+    }
+
+    pub(crate) fn is_enterprise(&self) -> bool {
+        false
+    }
+
+    pub(crate) fn is_lts(&self) -> bool {
+        false
+    }
+
+    pub(crate) fn is_rolling(&self) -> bool {
+        self.service_channel == ServiceChannel::Edge
+    }
+
+    pub(super) fn to_string(&self) -> Vec<String> {
+        vec![format!(
+            "{} {}",
+            self.product, self.release
+        )]
+    }
+}
+
+impl TryFrom<&str> for AlpineLinux {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        if let Ok(label) = crate::parser::endoflife::EndOfLifeLabel::try_from(value) {
+            crate::parser::endoflife::linux::AlpineLinuxParser::parse(&label)
+        } else {
+            let label = crate::parser::generic::GenericLabel::from(value);
+            crate::parser::generic::linux::AlpineLinuxParser::parse(&label)
+        }
+    }
+}
+
+/// Release of Alpine Linux: a stable branch (3.20), a patch release (3.20.3) or the rolling branch edge.
+#[derive(Debug)]
+pub(crate) struct Release(String);
+
+impl Release {
+    fn is_edge(&self) -> bool {
+        self.0 == "edge"
+    }
+}
+
+impl From<&str> for Release {
+    fn from(value: &str) -> Self {
+        Release(value.to_lowercase())
+    }
+}
+
+impl std::fmt::Display for Release {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[derive(PartialEq, Debug, Default)]
+pub(crate) enum ServiceChannel {
+    // Stable branches, released every six months
+    #[default]
+    Stable,
+    // Rolling development branch
+    Edge,
+}
+
+impl From<&Release> for ServiceChannel {
+    fn from(value: &Release) -> ServiceChannel {
+        if value.is_edge() {
+            ServiceChannel::Edge
+        } else {
+            ServiceChannel::Stable
+        }
+    }
+}
+
+impl std::fmt::Display for ServiceChannel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let out = match self {
+            ServiceChannel::Stable => "Stable",
+            ServiceChannel::Edge => "Edge",
+        };
+
+        write!(f, "{}", out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_string_1() {
+        let label = AlpineLinux::try_from("alpine-3.20").unwrap();
+
+        assert_eq!(label.vendor, "Alpine".to_string());
+        assert_eq!(label.product, "Alpine Linux".to_string());
+        assert_eq!(label.release.to_string(), "3.20".to_string());
+
+        assert_eq!(label.service_channel, ServiceChannel::Stable);
+    }
+
+    #[test]
+    fn test_from_string_2() {
+        let label = AlpineLinux::try_from("alpine-linux-3.20.3").unwrap();
+
+        assert_eq!(label.release.to_string(), "3.20.3".to_string());
+        assert_eq!(label.service_channel, ServiceChannel::Stable);
+    }
+
+    #[test]
+    fn test_from_string_3() {
+        let label = AlpineLinux::try_from("alpine-edge").unwrap();
+
+        assert_eq!(label.release.to_string(), "edge".to_string());
+        assert_eq!(label.service_channel, ServiceChannel::Edge);
+    }
+
+    #[test]
+    fn test_from_string_arbitrary1() {
+        let label = AlpineLinux::try_from("Alpine Linux v3.20").unwrap();
+
+        assert_eq!(label.vendor, "Alpine".to_string());
+        assert_eq!(label.product, "Alpine Linux".to_string());
+        assert_eq!(label.release.to_string(), "3.20".to_string());
+
+        assert_eq!(label.service_channel, ServiceChannel::Stable);
+    }
+
+    #[test]
+    fn test_from_string_arbitrary2() {
+        let label = AlpineLinux::try_from("Alpine Linux 3.20.3").unwrap();
+
+        assert_eq!(label.release.to_string(), "3.20.3".to_string());
+        assert_eq!(label.service_channel, ServiceChannel::Stable);
+    }
+
+    #[test]
+    fn test_from_string_arbitrary3() {
+        let label = AlpineLinux::try_from("Alpine Linux edge").unwrap();
+
+        assert_eq!(label.release.to_string(), "edge".to_string());
+        assert_eq!(label.service_channel, ServiceChannel::Edge);
+    }
+}
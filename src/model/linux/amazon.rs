@@ -0,0 +1,221 @@
+//
+// https://aws.amazon.com/amazon-linux-ami/faqs/
+// https://aws.amazon.com/amazon-linux-2/faqs/
+// https://docs.aws.amazon.com/linux/al2023/ug/release-cadence.html
+//
+const VENDOR: &str = "Amazon";
+const PRODUCT: &str = "Amazon Linux";
+
+#[derive(Debug)]
+pub(crate) struct AmazonLinux {
+    vendor: String,
+    product: String,
+    release: Release,
+    service_channel: ServiceChannel,
+}
+
+impl AmazonLinux {
+    pub(crate) fn build(release: Release, service_channel: ServiceChannel) -> AmazonLinux {
+        AmazonLinux {
+            vendor: VENDOR.to_string(),
+            product: PRODUCT.to_string(),
+            release,
+            service_channel,
+        }
+    }
+
+    pub(super) fn vendor(&self) -> &str {
+        self.vendor.as_str()
+    }
+
+    pub(super) fn product(&self) -> &str {
+        self.product.as_str()
+    }
+
+    pub(super) fn release(&self) -> String {
+        self.release.to_string()
+    }
+
+    pub(crate) fn is_enterprise(&self) -> bool {
+        true
+    }
+
+    pub(crate) fn is_lts(&self) -> bool {
+        self.service_channel.is_lts()
+    }
+
+    pub(super) fn to_string(&self) -> Vec<String> {
+        let out = match Generation::from(&self.release) {
+            Generation::AMI => format!("{} AMI {}", self.product, self.release),
+            Generation::AL2 | Generation::AL2023 => format!("{} {}", self.product, self.release),
+        };
+
+        vec![out]
+    }
+}
+
+impl TryFrom<&str> for AmazonLinux {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        if let Ok(label) = crate::parser::endoflife::EndOfLifeLabel::try_from(value) {
+            crate::parser::endoflife::linux::AmazonLinuxParser::parse(&label)
+        } else {
+            let label = crate::parser::generic::GenericLabel::from(value);
+            crate::parser::generic::linux::AmazonLinuxParser::parse(&label)
+        }
+    }
+}
+
+/// Release of Amazon Linux, e.g. 2018.03 (AMI), 2 (AL2), 2023, 2023.6 or 2023.6.20241010 (AL2023).
+#[derive(Debug)]
+pub(crate) struct Release(String);
+
+impl From<&str> for Release {
+    fn from(value: &str) -> Self {
+        Release(value.to_uppercase())
+    }
+}
+
+impl std::fmt::Display for Release {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Amazon Linux comes in three generations, each with its own release scheme.
+#[derive(PartialEq, Debug)]
+pub(crate) enum Generation {
+    // Amazon Linux AMI: year.month releases, e.g. 2018.03
+    AMI,
+    // Amazon Linux 2
+    AL2,
+    // Amazon Linux 2023: year and optional version and date, e.g. 2023.6 or 2023.6.20241010
+    AL2023,
+}
+
+impl From<&Release> for Generation {
+    fn from(value: &Release) -> Self {
+        if value.0 == "2" {
+            Generation::AL2
+        } else if value.0 == "2023" || value.0.starts_with("2023.") {
+            Generation::AL2023
+        } else {
+            Generation::AMI
+        }
+    }
+}
+
+#[derive(PartialEq, Debug, Default)]
+pub(crate) enum ServiceChannel {
+    #[default]
+    LTS,
+}
+
+impl ServiceChannel {
+    fn is_lts(&self) -> bool {
+        match self {
+            ServiceChannel::LTS => true,
+        }
+    }
+}
+
+impl From<&Release> for ServiceChannel {
+    fn from(_: &Release) -> ServiceChannel {
+        ServiceChannel::LTS
+    }
+}
+
+impl std::fmt::Display for ServiceChannel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let out = match self {
+            ServiceChannel::LTS => "LTS",
+        };
+
+        write!(f, "{}", out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_string_1() {
+        let label = AmazonLinux::try_from("amazon-linux-2023").unwrap();
+
+        assert_eq!(label.vendor, "Amazon".to_string());
+        assert_eq!(label.product, "Amazon Linux".to_string());
+        assert_eq!(label.release.to_string(), "2023".to_string());
+        assert_eq!(Generation::from(&label.release), Generation::AL2023);
+
+        assert_eq!(label.service_channel, ServiceChannel::LTS);
+    }
+
+    #[test]
+    fn test_from_string_2() {
+        let label = AmazonLinux::try_from("amazon-linux-2").unwrap();
+
+        assert_eq!(label.release.to_string(), "2".to_string());
+        assert_eq!(Generation::from(&label.release), Generation::AL2);
+        assert_eq!(label.to_string(), vec!["Amazon Linux 2".to_string()]);
+    }
+
+    #[test]
+    fn test_from_string_3() {
+        let label = AmazonLinux::try_from("amazon-linux-2018.03").unwrap();
+
+        assert_eq!(label.release.to_string(), "2018.03".to_string());
+        assert_eq!(Generation::from(&label.release), Generation::AMI);
+        assert_eq!(label.to_string(), vec!["Amazon Linux AMI 2018.03".to_string()]);
+    }
+
+    #[test]
+    fn test_from_string_arbitrary1() {
+        let label = AmazonLinux::try_from("Amazon Linux 2").unwrap();
+
+        assert_eq!(label.vendor, "Amazon".to_string());
+        assert_eq!(label.product, "Amazon Linux".to_string());
+        assert_eq!(label.release.to_string(), "2".to_string());
+        assert_eq!(Generation::from(&label.release), Generation::AL2);
+    }
+
+    #[test]
+    fn test_from_string_arbitrary2() {
+        let label = AmazonLinux::try_from("Amazon Linux 2023.6.20241010").unwrap();
+
+        assert_eq!(label.release.to_string(), "2023.6.20241010".to_string());
+        assert_eq!(Generation::from(&label.release), Generation::AL2023);
+        assert_eq!(label.to_string(), vec!["Amazon Linux 2023.6.20241010".to_string()]);
+    }
+
+    #[test]
+    fn test_from_string_arbitrary3() {
+        let label = AmazonLinux::try_from("Amazon Linux AMI 2018.03").unwrap();
+
+        assert_eq!(label.release.to_string(), "2018.03".to_string());
+        assert_eq!(Generation::from(&label.release), Generation::AMI);
+    }
+
+    #[test]
+    fn test_from_string_arbitrary4() {
+        let label = AmazonLinux::try_from("Amazon Linux 2023").unwrap();
+
+        assert_eq!(label.release.to_string(), "2023".to_string());
+        assert_eq!(Generation::from(&label.release), Generation::AL2023);
+        assert_eq!(label.to_string(), vec!["Amazon Linux 2023".to_string()]);
+    }
+
+    #[test]
+    fn test_from_string_minor() {
+        let label = AmazonLinux::try_from("amazon-linux-2023.6").unwrap();
+
+        assert_eq!(label.release.to_string(), "2023.6".to_string());
+        assert_eq!(Generation::from(&label.release), Generation::AL2023);
+
+        let label = AmazonLinux::try_from("Amazon Linux 2023.6").unwrap();
+
+        assert_eq!(label.release.to_string(), "2023.6".to_string());
+        assert_eq!(label.to_string(), vec!["Amazon Linux 2023.6".to_string()]);
+    }
+}
@@ -0,0 +1,112 @@
+//
+// https://archlinux.org/releng/releases/
+//
+const VENDOR: &str = "Arch Linux";
+const PRODUCT: &str = "Arch Linux";
+
+#[derive(Debug)]
+pub(crate) struct ArchLinux {
+    vendor: String,
+    product: String,
+    release: Release,
+}
+
+impl ArchLinux {
+    pub(crate) fn build(release: Release) -> ArchLinux {
+        ArchLinux {
+            vendor: VENDOR.to_string(),
+            product: PRODUCT.to_string(),
+            release,
+        }
+    }
+
+    pub(super) fn vendor(&self) -> &str {
+        self.vendor.as_str()
+    }
+
+    pub(super) fn product(&self) -> &str {
+        self.product.as_str()
+    }
+
+    pub(super) fn release(&self) -> String {
+        self.release.to_string()
+    }
+
+    pub(crate) fn is_enterprise(&self) -> bool {
+        false
+    }
+
+    pub(crate) fn is_lts(&self) -> bool {
+        false
+    }
+
+    pub(crate) fn is_rolling(&self) -> bool {
+        true
+    }
+
+    pub(super) fn to_string(&self) -> Vec<String> {
+        vec![format!(
+            "{} {}",
+            self.product, self.release
+        )]
+    }
+}
+
+// Arch Linux is not tracked by endoflife.date
+impl TryFrom<&str> for ArchLinux {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let label = crate::parser::generic::GenericLabel::from(value);
+        crate::parser::generic::linux::ArchLinuxParser::parse(&label)
+    }
+}
+
+/// Release of Arch Linux: the monthly installation snapshot (2024.10.01) or rolling.
+#[derive(Debug)]
+pub(crate) struct Release(String);
+
+impl Release {
+    pub(crate) fn rolling() -> Self {
+        Release("rolling".to_string())
+    }
+}
+
+impl From<&str> for Release {
+    fn from(value: &str) -> Self {
+        Release(value.to_lowercase())
+    }
+}
+
+impl std::fmt::Display for Release {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_string_arbitrary1() {
+        let label = ArchLinux::try_from("Arch Linux").unwrap();
+
+        assert_eq!(label.vendor, "Arch Linux".to_string());
+        assert_eq!(label.product, "Arch Linux".to_string());
+        assert_eq!(label.release.to_string(), "rolling".to_string());
+        assert_eq!(label.to_string(), vec!["Arch Linux rolling".to_string()]);
+    }
+
+    #[test]
+    fn test_from_string_arbitrary2() {
+        let label = ArchLinux::try_from("archlinux-2024.10.01-x86_64.iso").unwrap();
+
+        assert_eq!(label.release.to_string(), "2024.10.01".to_string());
+    }
+
+    #[test]
+    fn test_from_string_arbitrary3() {
+        assert!(ArchLinux::try_from("Debian GNU/Linux 13 (trixie)").is_err());
+    }
+}
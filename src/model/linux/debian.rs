@@ -7,11 +7,12 @@ pub(crate) struct Debian {
     product: String,
     release: Release,
     editions: Editions,
-    service_channel: ServiceChannel,
+    // Support phase named in the label, e.g. "Debian 10 ELTS"
+    service_channel: Option<ServiceChannel>,
 }
 
 impl Debian {
-    pub(crate) fn build(release: Release, service_channel: ServiceChannel) -> Debian {
+    pub(crate) fn build(release: Release, service_channel: Option<ServiceChannel>) -> Debian {
         Debian {
             vendor: VENDOR.to_string(),
             product: PRODUCT.to_string(),
@@ -43,14 +44,52 @@ impl Debian {
     }
 
     pub(crate) fn is_lts(&self) -> bool {
-        false
+        self.service_channel.as_ref().is_some_and(|channel| channel.is_lts())
+    }
+
+    pub(crate) fn is_pre_release(&self) -> bool {
+        self.release.is_pre_release()
+    }
+
+    pub(super) fn service_channel(&self) -> Option<String> {
+        self.service_channel.as_ref().map(|channel| channel.to_string())
+    }
+
+    // Support phase on a date formatted as "YYYY-MM-DD" unless the label names one
+    pub(super) fn service_channel_at(&self, date: &str) -> Option<String> {
+        if !crate::util::is_iso_date(date) {
+            return None;
+        }
+
+        match &self.service_channel {
+            Some(channel) => Some(channel.to_string()),
+            None => ServiceChannel::at(&self.release, date)
+                .filter(|channel| !channel.is_default())
+                .map(|channel| channel.to_string()),
+        }
+    }
+
+    pub(super) fn release_components(&self) -> (Option<u32>, Option<u32>, Option<u32>) {
+        self.release.components()
+    }
+
+    pub(super) fn release_cycle(&self) -> String {
+        self.release.cycle()
     }
 
     pub(super) fn to_string(&self) -> Vec<String> {
-        vec![format!(
-            "{} {}",
-            self.product, self.release
-        )]
+        let out = match &self.service_channel {
+            Some(channel) => format!(
+                "{} {} {channel}",
+                self.product, self.release
+            ),
+            None => format!(
+                "{} {}",
+                self.product, self.release
+            ),
+        };
+
+        vec![out]
     }
 }
 
@@ -70,9 +109,32 @@ impl TryFrom<&str> for Debian {
 #[derive(Debug)]
 pub(crate) struct Release(String);
 
+impl Release {
+    pub(crate) fn components(&self) -> (Option<u32>, Option<u32>, Option<u32>) {
+        crate::util::release_components(&self.0)
+    }
+
+    // The testing and unstable (sid) distributions become the next release
+    pub(crate) fn is_pre_release(&self) -> bool {
+        self.0 == "testing" || self.0 == "unstable"
+    }
+
+    pub(crate) fn major(&self) -> Option<u32> {
+        self.components().0
+    }
+
+    // endoflife.date tracks Debian by major release, e.g. 12
+    pub(crate) fn cycle(&self) -> String {
+        match self.major() {
+            Some(major) => major.to_string(),
+            None => self.0.clone(),
+        }
+    }
+}
+
 impl From<&str> for Release {
     fn from(value: &str) -> Self {
-        Release(value.to_uppercase())
+        Release(value.to_lowercase())
     }
 }
 
@@ -121,35 +183,116 @@ impl std::fmt::Display for Edition {
     }
 }
 
+//
+// https://wiki.debian.org/LTS
+// https://wiki.debian.org/LTS/Extended
+//
+// End of regular security support, Debian LTS and Freexian ELTS per major release.
+// ELTS is only listed for releases Freexian has announced it for.
+const PHASES: &[(u32, &str, &str, Option<&str>)] = &[
+    (6, "2014-05-31", "2016-02-29", None),
+    (7, "2016-04-25", "2018-05-31", Some("2020-06-30")),
+    (8, "2018-06-17", "2020-06-30", Some("2025-06-30")),
+    (9, "2020-07-06", "2022-06-30", Some("2027-06-30")),
+    (10, "2022-09-10", "2024-06-30", Some("2029-06-30")),
+    (11, "2024-08-14", "2026-08-31", Some("2031-06-30")),
+    (12, "2026-06-10", "2028-06-30", None),
+    (13, "2028-08-09", "2030-06-30", None),
+];
+
+// Support phases of a release, one after another
 #[derive(PartialEq, Debug)]
 pub(crate) enum ServiceChannel {
+    // Security support by the Debian security team
+    Regular,
+    // Debian Long Term Support
     LTS,
+    // Extended LTS by Freexian
+    ELTS,
+    EndOfLife,
 }
 
 impl ServiceChannel {
     fn is_default(&self) -> bool {
         match self {
+            ServiceChannel::Regular => true,
+            ServiceChannel::LTS => false,
+            ServiceChannel::ELTS => false,
+            ServiceChannel::EndOfLife => false,
+        }
+    }
+
+    fn is_lts(&self) -> bool {
+        match self {
+            ServiceChannel::Regular => false,
             ServiceChannel::LTS => true,
+            ServiceChannel::ELTS => true,
+            ServiceChannel::EndOfLife => false,
+        }
+    }
+
+    // Support phase of a release on a date formatted as "YYYY-MM-DD",
+    // `None` for testing and unstable
+    pub(crate) fn at(release: &Release, date: &str) -> Option<ServiceChannel> {
+        let major = release.major()?;
+
+        let channel = match PHASES.iter().find(|(m, _, _, _)| *m == major) {
+            Some((_, regular, lts, elts)) => {
+                if date <= *regular {
+                    ServiceChannel::Regular
+                } else if date <= *lts {
+                    ServiceChannel::LTS
+                } else if elts.is_some_and(|elts| date <= elts) {
+                    ServiceChannel::ELTS
+                } else {
+                    ServiceChannel::EndOfLife
+                }
+            },
+            // Releases before the table are out of support, newer ones are in regular support
+            None if major < PHASES[0].0 => ServiceChannel::EndOfLife,
+            None => ServiceChannel::Regular,
+        };
+
+        Some(channel)
+    }
+
+    // Whether the release has the phase at all, e.g. Debian 12 has no ELTS
+    pub(crate) fn is_offered_for(&self, release: &Release) -> bool {
+        let phases = release.major().and_then(|major| PHASES.iter().find(|(m, _, _, _)| *m == major));
+
+        match (self, phases) {
+            (ServiceChannel::LTS, Some(_)) => true,
+            (ServiceChannel::ELTS, Some((_, _, _, elts))) => elts.is_some(),
+            (_, _) => false,
         }
     }
 }
 
-impl From<&Release> for ServiceChannel {
-    fn from(_: &Release) -> ServiceChannel {
-        ServiceChannel::LTS
+impl TryFrom<&str> for ServiceChannel {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "lts" => Ok(ServiceChannel::LTS),
+            "elts" => Ok(ServiceChannel::ELTS),
+            _ => Err(format!("Not a Debian support phase: {}", value)),
+        }
     }
 }
 
 impl Default for ServiceChannel {
     fn default() -> Self {
-        ServiceChannel::LTS
+        ServiceChannel::Regular
     }
 }
 
 impl std::fmt::Display for ServiceChannel {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let out = match self {
+            ServiceChannel::Regular => "Regular",
             ServiceChannel::LTS => "LTS",
+            ServiceChannel::ELTS => "ELTS",
+            ServiceChannel::EndOfLife => "EOL",
         };
 
         write!(f, "{}", out)
@@ -169,7 +312,7 @@ mod tests {
         assert_eq!(label.release.to_string(), "13".to_string());
 
         assert_eq!(label.editions.len(), Editions::all().len());
-        assert_eq!(label.service_channel, ServiceChannel::LTS);
+        assert_eq!(label.service_channel, None);
     }
 
     #[test]
@@ -181,7 +324,7 @@ mod tests {
         assert_eq!(label.release.to_string(), "6".to_string());
 
         assert_eq!(label.editions.len(), Editions::all().len());
-        assert_eq!(label.service_channel, ServiceChannel::LTS);
+        assert_eq!(label.service_channel, None);
     }
 
     #[test]
@@ -193,7 +336,7 @@ mod tests {
         assert_eq!(label.release.to_string(), "13.5".to_string());
 
         assert_eq!(label.editions.len(), Editions::all().len());
-        assert_eq!(label.service_channel, ServiceChannel::LTS);
+        assert_eq!(label.service_channel, None);
     }
 
     #[test]
@@ -205,7 +348,7 @@ mod tests {
         assert_eq!(label.release.to_string(), "6.0.10".to_string());
 
         assert_eq!(label.editions.len(), Editions::all().len());
-        assert_eq!(label.service_channel, ServiceChannel::LTS);
+        assert_eq!(label.service_channel, None);
     }
 
     #[test]
@@ -217,7 +360,7 @@ mod tests {
         assert_eq!(label.release.to_string(), "13".to_string());
 
         assert_eq!(label.editions.len(), 0);
-        assert_eq!(label.service_channel, ServiceChannel::LTS);
+        assert_eq!(label.service_channel, None);
     }
 
     #[test]
@@ -229,6 +372,88 @@ mod tests {
         assert_eq!(label.release.to_string(), "6.0".to_string());
 
         assert_eq!(label.editions.len(), 0);
-        assert_eq!(label.service_channel, ServiceChannel::LTS);
+        assert_eq!(label.service_channel, None);
+    }
+
+    #[test]
+    fn test_service_channel_at() {
+        let release = Release::from("10");
+
+        assert_eq!(ServiceChannel::at(&release, "2021-01-01"), Some(ServiceChannel::Regular));
+        assert_eq!(ServiceChannel::at(&release, "2023-01-01"), Some(ServiceChannel::LTS));
+        assert_eq!(ServiceChannel::at(&release, "2026-01-01"), Some(ServiceChannel::ELTS));
+        assert_eq!(ServiceChannel::at(&release, "2030-01-01"), Some(ServiceChannel::EndOfLife));
+    }
+
+    #[test]
+    fn test_service_channel_at_point_release() {
+        let release = Release::from("11.11");
+
+        assert_eq!(ServiceChannel::at(&release, "2025-01-01"), Some(ServiceChannel::LTS));
+        assert_eq!(ServiceChannel::at(&Release::from("6.0.10"), "2017-01-01"), Some(ServiceChannel::EndOfLife));
+        assert_eq!(ServiceChannel::at(&Release::from("5"), "2010-01-01"), Some(ServiceChannel::EndOfLife));
+        assert_eq!(ServiceChannel::at(&Release::from("14"), "2026-01-01"), Some(ServiceChannel::Regular));
+    }
+
+    #[test]
+    fn test_service_channel_at_invalid_date() {
+        let label = Debian::try_from("debian-10-elts").unwrap();
+
+        assert_eq!(label.service_channel_at("2025-01-01"), Some("ELTS".to_string()));
+        assert_eq!(label.service_channel_at("01/01/2025"), None);
+
+        // Would sort after every phase end and pass for end of life
+        let label = Debian::try_from("debian-10").unwrap();
+
+        assert_eq!(label.service_channel_at("2025-1-1"), None);
+        assert_eq!(label.service_channel_at("tomorrow"), None);
+    }
+
+    #[test]
+    fn test_from_string_elts() {
+        let label = Debian::try_from("debian-10-elts").unwrap();
+
+        assert_eq!(label.release.to_string(), "10".to_string());
+        assert_eq!(label.service_channel, Some(ServiceChannel::ELTS));
+        assert!(label.is_lts());
+    }
+
+    #[test]
+    fn test_from_string_arbitrary_elts() {
+        let label = Debian::try_from("Debian 10 ELTS").unwrap();
+
+        assert_eq!(label.release.to_string(), "10".to_string());
+        assert_eq!(label.service_channel, Some(ServiceChannel::ELTS));
+        assert_eq!(label.to_string(), vec!["Debian Linux 10 ELTS".to_string()]);
+    }
+
+    #[test]
+    fn test_from_string_arbitrary_lts() {
+        let label = Debian::try_from("Debian GNU/Linux 11 (bullseye) LTS").unwrap();
+
+        assert_eq!(label.release.to_string(), "11".to_string());
+        assert_eq!(label.service_channel, Some(ServiceChannel::LTS));
+    }
+
+    #[test]
+    fn test_from_string_arbitrary_testing() {
+        let label = Debian::try_from("Debian GNU/Linux trixie/sid").unwrap();
+
+        assert_eq!(label.release.to_string(), "testing".to_string());
+        assert!(label.is_pre_release());
+        assert_eq!(label.to_string(), vec!["Debian Linux testing".to_string()]);
+
+        let label = Debian::try_from("Debian testing").unwrap();
+
+        assert_eq!(label.release.to_string(), "testing".to_string());
+    }
+
+    #[test]
+    fn test_from_string_arbitrary_unstable() {
+        let label = Debian::try_from("Debian GNU/Linux sid").unwrap();
+
+        assert_eq!(label.release.to_string(), "unstable".to_string());
+        assert!(label.is_pre_release());
+        assert!(!label.is_lts());
     }
 }
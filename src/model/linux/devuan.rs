@@ -0,0 +1,129 @@
+//
+// https://www.devuan.org/os/releases
+//
+const VENDOR: &str = "Devuan";
+const PRODUCT: &str = "Devuan GNU+Linux";
+
+// Release and the Debian release it is built from
+const DEBIAN_RELEASES: [(&str, &str); 6] = [
+    ("1", "8"),
+    ("2", "9"),
+    ("3", "10"),
+    ("4", "11"),
+    ("5", "12"),
+    ("6", "13"),
+];
+
+#[derive(Debug)]
+pub(crate) struct Devuan {
+    vendor: String,
+    product: String,
+    release: Release,
+}
+
+impl Devuan {
+    pub(crate) fn build(release: Release) -> Devuan {
+        Devuan {
+            vendor: VENDOR.to_string(),
+            product: PRODUCT.to_string(),
+            release,
+        }
+    }
+
+    pub(super) fn vendor(&self) -> &str {
+        self.vendor.as_str()
+    }
+
+    pub(super) fn product(&self) -> &str {
+        self.product.as_str()
+    }
+
+    pub(super) fn release(&self) -> String {
+        self.release.to_string()
+    }
+
+    pub(crate) fn is_enterprise(&self) -> bool {
+        false
+    }
+
+    pub(crate) fn is_lts(&self) -> bool {
+        false
+    }
+
+    pub(super) fn based_on(&self) -> Option<String> {
+        self.release.debian().map(|debian| format!("debian-{debian}"))
+    }
+
+    pub(super) fn to_string(&self) -> Vec<String> {
+        vec![format!(
+            "{} {}",
+            self.product, self.release
+        )]
+    }
+}
+
+// Devuan reports its release and codename, e.g. "Devuan GNU/Linux 5 (daedalus)"
+impl TryFrom<&str> for Devuan {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let label = crate::parser::generic::GenericLabel::from(value);
+        crate::parser::generic::linux::DevuanParser::parse(&label)
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct Release(String);
+
+impl Release {
+    fn debian(&self) -> Option<&str> {
+        let major = crate::util::release_components(&self.0).0?.to_string();
+
+        DEBIAN_RELEASES
+            .iter()
+            .find(|(release, _)| *release == major)
+            .map(|(_, debian)| *debian)
+    }
+}
+
+impl From<&str> for Release {
+    fn from(value: &str) -> Self {
+        Release(value.to_lowercase())
+    }
+}
+
+impl std::fmt::Display for Release {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_string_arbitrary_os_release() {
+        let label = Devuan::try_from("Devuan GNU/Linux 5 (daedalus)").unwrap();
+
+        assert_eq!(label.vendor, "Devuan".to_string());
+        assert_eq!(label.product, "Devuan GNU+Linux".to_string());
+        assert_eq!(label.based_on(), Some("debian-12".to_string()));
+        assert_eq!(label.to_string(), vec!["Devuan GNU+Linux 5".to_string()]);
+    }
+
+    #[test]
+    fn test_from_string_arbitrary_point_release() {
+        let label = Devuan::try_from("Devuan 4.0 chimaera").unwrap();
+
+        assert_eq!(label.release.to_string(), "4.0".to_string());
+        assert_eq!(label.based_on(), Some("debian-11".to_string()));
+    }
+
+    #[test]
+    fn test_from_string_arbitrary_unreleased() {
+        let label = Devuan::try_from("Devuan 7").unwrap();
+
+        assert_eq!(label.based_on(), None);
+    }
+}
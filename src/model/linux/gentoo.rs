@@ -0,0 +1,115 @@
+//
+// https://www.gentoo.org/downloads/
+//
+const VENDOR: &str = "Gentoo";
+const PRODUCT: &str = "Gentoo Linux";
+
+#[derive(Debug)]
+pub(crate) struct Gentoo {
+    vendor: String,
+    product: String,
+    release: Release,
+}
+
+impl Gentoo {
+    pub(crate) fn build(release: Release) -> Gentoo {
+        Gentoo {
+            vendor: VENDOR.to_string(),
+            product: PRODUCT.to_string(),
+            release,
+        }
+    }
+
+    pub(super) fn vendor(&self) -> &str {
+        self.vendor.as_str()
+    }
+
+    pub(super) fn product(&self) -> &str {
+        self.product.as_str()
+    }
+
+    pub(super) fn release(&self) -> String {
+        self.release.to_string()
+    }
+
+    pub(crate) fn is_enterprise(&self) -> bool {
+        false
+    }
+
+    pub(crate) fn is_lts(&self) -> bool {
+        false
+    }
+
+    pub(crate) fn is_rolling(&self) -> bool {
+        true
+    }
+
+    pub(super) fn to_string(&self) -> Vec<String> {
+        vec![format!(
+            "{} {}",
+            self.product, self.release
+        )]
+    }
+}
+
+// Gentoo is not tracked by endoflife.date
+impl TryFrom<&str> for Gentoo {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let label = crate::parser::generic::GenericLabel::from(value);
+        crate::parser::generic::linux::GentooParser::parse(&label)
+    }
+}
+
+/// Release of Gentoo: the stage3 snapshot (20241013) or rolling.
+#[derive(Debug)]
+pub(crate) struct Release(String);
+
+impl Release {
+    pub(crate) fn rolling() -> Self {
+        Release("rolling".to_string())
+    }
+}
+
+impl From<&str> for Release {
+    fn from(value: &str) -> Self {
+        Release(value.to_lowercase())
+    }
+}
+
+impl std::fmt::Display for Release {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_string_arbitrary1() {
+        let label = Gentoo::try_from("Gentoo").unwrap();
+
+        assert_eq!(label.vendor, "Gentoo".to_string());
+        assert_eq!(label.product, "Gentoo Linux".to_string());
+        assert_eq!(label.release.to_string(), "rolling".to_string());
+    }
+
+    #[test]
+    fn test_from_string_arbitrary2() {
+        // The version of baselayout is no release of Gentoo
+        let label = Gentoo::try_from("Gentoo Base System release 2.17").unwrap();
+
+        assert_eq!(label.release.to_string(), "rolling".to_string());
+        assert_eq!(label.to_string(), vec!["Gentoo Linux rolling".to_string()]);
+    }
+
+    #[test]
+    fn test_from_string_arbitrary3() {
+        let label = Gentoo::try_from("stage3-amd64-openrc-20241013T170333Z Gentoo").unwrap();
+
+        assert_eq!(label.release.to_string(), "20241013".to_string());
+    }
+}
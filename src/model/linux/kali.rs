@@ -0,0 +1,117 @@
+//
+// https://www.kali.org/releases/
+//
+const VENDOR: &str = "OffSec";
+const PRODUCT: &str = "Kali Linux";
+
+#[derive(Debug)]
+pub(crate) struct KaliLinux {
+    vendor: String,
+    product: String,
+    release: Release,
+}
+
+impl KaliLinux {
+    pub(crate) fn build(release: Release) -> KaliLinux {
+        KaliLinux {
+            vendor: VENDOR.to_string(),
+            product: PRODUCT.to_string(),
+            release,
+        }
+    }
+
+    pub(super) fn vendor(&self) -> &str {
+        self.vendor.as_str()
+    }
+
+    pub(super) fn product(&self) -> &str {
+        self.product.as_str()
+    }
+
+    pub(super) fn release(&self) -> String {
+        self.release.to_string()
+    }
+
+    pub(crate) fn is_enterprise(&self) -> bool {
+        false
+    }
+
+    pub(crate) fn is_lts(&self) -> bool {
+        false
+    }
+
+    pub(crate) fn is_rolling(&self) -> bool {
+        true
+    }
+
+    // Kali Linux rolls along with Debian testing
+    pub(super) fn based_on(&self) -> Option<String> {
+        Some("Debian testing".to_string())
+    }
+
+    pub(super) fn to_string(&self) -> Vec<String> {
+        vec![format!(
+            "{} {}",
+            self.product, self.release
+        )]
+    }
+}
+
+// Kali Linux reports a quarterly snapshot or just "Rolling"
+impl TryFrom<&str> for KaliLinux {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let label = crate::parser::generic::GenericLabel::from(value);
+        crate::parser::generic::linux::KaliLinuxParser::parse(&label)
+    }
+}
+
+/// Release of Kali Linux: the quarterly snapshot (2024.3) or rolling.
+#[derive(Debug)]
+pub(crate) struct Release(String);
+
+impl Release {
+    pub(crate) fn rolling() -> Self {
+        Release("rolling".to_string())
+    }
+}
+
+impl From<&str> for Release {
+    fn from(value: &str) -> Self {
+        Release(value.to_lowercase())
+    }
+}
+
+impl std::fmt::Display for Release {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_string_arbitrary_rolling() {
+        let label = KaliLinux::try_from("Kali GNU/Linux Rolling").unwrap();
+
+        assert_eq!(label.vendor, "OffSec".to_string());
+        assert_eq!(label.product, "Kali Linux".to_string());
+        assert!(label.is_rolling());
+        assert_eq!(label.based_on(), Some("Debian testing".to_string()));
+    }
+
+    #[test]
+    fn test_from_string_arbitrary_snapshot() {
+        let label = KaliLinux::try_from("Kali Linux 2024.3").unwrap();
+
+        assert_eq!(label.to_string(), vec!["Kali Linux 2024.3".to_string()]);
+
+        // There are four snapshots a year
+        let label = KaliLinux::try_from("Kali Linux 2024.5").unwrap();
+
+        assert_eq!(label.release.to_string(), "rolling".to_string());
+    }
+}
@@ -0,0 +1,211 @@
+//
+// https://linuxmint.com/download_all.php
+//
+const VENDOR: &str = "Linux Mint";
+const PRODUCT: &str = "Linux Mint";
+
+// Release and the Ubuntu LTS release it is built from
+const UBUNTU_RELEASES: [(&str, &str); 6] = [
+    ("17", "14.04"),
+    ("18", "16.04"),
+    ("19", "18.04"),
+    ("20", "20.04"),
+    ("21", "22.04"),
+    ("22", "24.04"),
+];
+
+// Release of the Debian edition and the Debian release it is built from
+const DEBIAN_RELEASES: [(&str, &str); 5] = [
+    ("3", "9"),
+    ("4", "10"),
+    ("5", "11"),
+    ("6", "12"),
+    ("7", "13"),
+];
+
+#[derive(Debug)]
+pub(crate) struct LinuxMint {
+    vendor: String,
+    product: String,
+    release: Release,
+    editions: Editions,
+}
+
+impl LinuxMint {
+    pub(crate) fn build(release: Release) -> LinuxMint {
+        LinuxMint {
+            vendor: VENDOR.to_string(),
+            product: PRODUCT.to_string(),
+            release,
+            editions: Editions(vec![]),
+        }
+    }
+
+    pub(crate) fn editions(mut self, editions: Editions) -> LinuxMint {
+        self.editions = editions;
+        self
+    }
+
+    pub(super) fn vendor(&self) -> &str {
+        self.vendor.as_str()
+    }
+
+    pub(super) fn product(&self) -> &str {
+        self.product.as_str()
+    }
+
+    pub(super) fn release(&self) -> String {
+        self.release.to_string()
+    }
+
+    pub(crate) fn is_enterprise(&self) -> bool {
+        false
+    }
+
+    pub(crate) fn is_lts(&self) -> bool {
+        false
+    }
+
+    pub(super) fn based_on(&self) -> Option<String> {
+        if self.editions.contains(Edition::LMDE) {
+            self.release.base(&DEBIAN_RELEASES).map(|debian| format!("debian-{debian}"))
+        } else {
+            self.release.base(&UBUNTU_RELEASES).map(|ubuntu| format!("ubuntu-{ubuntu}"))
+        }
+    }
+
+    pub(super) fn to_string(&self) -> Vec<String> {
+        if self.editions.contains(Edition::LMDE) {
+            vec![format!(
+                "{} {} {}",
+                self.product, Edition::LMDE, self.release
+            )]
+        } else {
+            vec![format!(
+                "{} {}",
+                self.product, self.release
+            )]
+        }
+    }
+}
+
+// Linux Mint Debian Edition is read as an edition of Linux Mint
+impl TryFrom<&str> for LinuxMint {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let label = crate::parser::generic::GenericLabel::from(value);
+        crate::parser::generic::linux::LinuxMintParser::parse(&label)
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct Release(String);
+
+impl Release {
+    fn base<'a>(&self, releases: &[(&str, &'a str)]) -> Option<&'a str> {
+        let major = crate::util::release_components(&self.0).0?.to_string();
+
+        releases
+            .iter()
+            .find(|(release, _)| *release == major)
+            .map(|(_, base)| *base)
+    }
+}
+
+impl From<&str> for Release {
+    fn from(value: &str) -> Self {
+        Release(value.to_lowercase())
+    }
+}
+
+impl std::fmt::Display for Release {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct Editions(pub(crate) Vec<Edition>);
+
+impl Editions {
+    pub(crate) fn all() -> Self {
+        Editions(vec![
+            Edition::Cinnamon,
+            Edition::MATE,
+            Edition::Xfce,
+        ])
+    }
+
+    #[allow(dead_code)]
+    fn contains(&self, edition: Edition) -> bool {
+        self.0.contains(&edition)
+    }
+
+    #[allow(dead_code)]
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+}
+
+#[derive(PartialEq, Debug)]
+pub(crate) enum Edition {
+    Cinnamon,
+    // Linux Mint Debian Edition, built from Debian instead of Ubuntu
+    LMDE,
+    MATE,
+    Xfce,
+}
+
+impl std::fmt::Display for Edition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let out = match self {
+            Edition::Cinnamon => "Cinnamon",
+            Edition::LMDE => "Debian Edition",
+            Edition::MATE => "MATE",
+            Edition::Xfce => "Xfce",
+        };
+
+        write!(f, "{}", out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_string_arbitrary_all_editions() {
+        let label = LinuxMint::try_from("Linux Mint 22").unwrap();
+
+        assert_eq!(label.vendor, "Linux Mint".to_string());
+        assert_eq!(label.product, "Linux Mint".to_string());
+        assert_eq!(label.release.to_string(), "22".to_string());
+        assert_eq!(label.editions.len(), Editions::all().len());
+        assert_eq!(label.based_on(), Some("ubuntu-24.04".to_string()));
+        assert_eq!(label.to_string(), vec!["Linux Mint 22".to_string()]);
+    }
+
+    #[test]
+    fn test_from_string_arbitrary_edition() {
+        let label = LinuxMint::try_from("Linux Mint 21.3 Cinnamon").unwrap();
+
+        assert_eq!(label.release.to_string(), "21.3".to_string());
+        assert_eq!(label.editions.len(), 1);
+        assert!(label.editions.contains(Edition::Cinnamon));
+        assert_eq!(label.based_on(), Some("ubuntu-22.04".to_string()));
+    }
+
+    #[test]
+    fn test_from_string_arbitrary_lmde() {
+        let label = LinuxMint::try_from("LMDE 6 (faye)").unwrap();
+
+        assert!(label.editions.contains(Edition::LMDE));
+        assert_eq!(label.based_on(), Some("debian-12".to_string()));
+        assert_eq!(label.to_string(), vec!["Linux Mint Debian Edition 6".to_string()]);
+
+        let label = LinuxMint::try_from("Linux Mint Debian Edition 5").unwrap();
+
+        assert_eq!(label.based_on(), Some("debian-11".to_string()));
+    }
+}
@@ -1,9 +1,33 @@
+pub(crate) mod alpine;
+pub(crate) use alpine::AlpineLinux;
+
+pub(crate) mod amazon;
+pub(crate) use amazon::AmazonLinux;
+
+pub(crate) mod arch;
+pub(crate) use arch::ArchLinux;
+
 pub(crate) mod debian;
 pub(crate) use debian::Debian;
 
+pub(crate) mod devuan;
+pub(crate) use devuan::Devuan;
+
+pub(crate) mod gentoo;
+pub(crate) use gentoo::Gentoo;
+
+pub(crate) mod kali;
+pub(crate) use kali::KaliLinux;
+
+pub(crate) mod mint;
+pub(crate) use mint::LinuxMint;
+
 pub(crate) mod oracle;
 pub(crate) use oracle::OracleLinux;
 
+pub(crate) mod raspberry_pi_os;
+pub(crate) use raspberry_pi_os::RaspberryPiOS;
+
 pub(crate) mod rhel;
 pub(crate) use rhel::RedHatEnterpriseLinux;
 
@@ -12,8 +36,16 @@ pub(crate) use ubuntu::Ubuntu;
 
 #[derive(Debug)]
 pub(crate) enum Linux {
+    AlpineLinux(AlpineLinux),
+    AmazonLinux(AmazonLinux),
+    ArchLinux(ArchLinux),
     Debian(Debian),
+    Devuan(Devuan),
+    Gentoo(Gentoo),
+    KaliLinux(KaliLinux),
+    LinuxMint(LinuxMint),
     OracleLinux(OracleLinux),
+    RaspberryPiOS(RaspberryPiOS),
     RedHatEnterpriseLinux(RedHatEnterpriseLinux),
     Ubuntu(Ubuntu),
 }
@@ -21,8 +53,16 @@ pub(crate) enum Linux {
 impl Linux {
     pub fn to_string(&self) -> Vec<String> {
         match self {
+            Linux::AlpineLinux(linux) => linux.to_string(),
+            Linux::AmazonLinux(linux) => linux.to_string(),
+            Linux::ArchLinux(linux) => linux.to_string(),
             Linux::Debian(linux) => linux.to_string(),
+            Linux::Devuan(linux) => linux.to_string(),
+            Linux::Gentoo(linux) => linux.to_string(),
+            Linux::KaliLinux(linux) => linux.to_string(),
+            Linux::LinuxMint(linux) => linux.to_string(),
             Linux::OracleLinux(linux) => linux.to_string(),
+            Linux::RaspberryPiOS(linux) => linux.to_string(),
             Linux::RedHatEnterpriseLinux(linux) => linux.to_string(),
             Linux::Ubuntu(linux) => linux.to_string(),
         }
@@ -30,8 +70,16 @@ impl Linux {
 
     pub fn vendor(&self) -> String {
         match self {
+            Linux::AlpineLinux(linux) => String::from(linux.vendor()),
+            Linux::AmazonLinux(linux) => String::from(linux.vendor()),
+            Linux::ArchLinux(linux) => String::from(linux.vendor()),
             Linux::Debian(linux) => String::from(linux.vendor()),
+            Linux::Devuan(linux) => String::from(linux.vendor()),
+            Linux::Gentoo(linux) => String::from(linux.vendor()),
+            Linux::KaliLinux(linux) => String::from(linux.vendor()),
+            Linux::LinuxMint(linux) => String::from(linux.vendor()),
             Linux::OracleLinux(linux) => String::from(linux.vendor()),
+            Linux::RaspberryPiOS(linux) => String::from(linux.vendor()),
             Linux::RedHatEnterpriseLinux(linux) => String::from(linux.vendor()),
             Linux::Ubuntu(linux) => String::from(linux.vendor()),
         }
@@ -39,8 +87,16 @@ impl Linux {
 
     pub fn product(&self) -> String {
         match self {
+            Linux::AlpineLinux(linux) => String::from(linux.product()),
+            Linux::AmazonLinux(linux) => String::from(linux.product()),
+            Linux::ArchLinux(linux) => String::from(linux.product()),
             Linux::Debian(linux) => String::from(linux.product()),
+            Linux::Devuan(linux) => String::from(linux.product()),
+            Linux::Gentoo(linux) => String::from(linux.product()),
+            Linux::KaliLinux(linux) => String::from(linux.product()),
+            Linux::LinuxMint(linux) => String::from(linux.product()),
             Linux::OracleLinux(linux) => String::from(linux.product()),
+            Linux::RaspberryPiOS(linux) => String::from(linux.product()),
             Linux::RedHatEnterpriseLinux(linux) => String::from(linux.product()),
             Linux::Ubuntu(linux) => String::from(linux.product()),
         }
@@ -48,17 +104,33 @@ impl Linux {
 
     pub fn release(&self) -> String {
         match self {
-            Linux::Debian(linux) => String::from(linux.release()),
-            Linux::OracleLinux(linux) => String::from(linux.release()),
-            Linux::RedHatEnterpriseLinux(linux) => String::from(linux.release()),
-            Linux::Ubuntu(linux) => String::from(linux.release()),
+            Linux::AlpineLinux(linux) => linux.release(),
+            Linux::AmazonLinux(linux) => linux.release(),
+            Linux::ArchLinux(linux) => linux.release(),
+            Linux::Debian(linux) => linux.release(),
+            Linux::Devuan(linux) => linux.release(),
+            Linux::Gentoo(linux) => linux.release(),
+            Linux::KaliLinux(linux) => linux.release(),
+            Linux::LinuxMint(linux) => linux.release(),
+            Linux::OracleLinux(linux) => linux.release(),
+            Linux::RaspberryPiOS(linux) => linux.release(),
+            Linux::RedHatEnterpriseLinux(linux) => linux.release(),
+            Linux::Ubuntu(linux) => linux.release(),
         }
     }
     
     pub fn is_enterprise(&self) -> bool {
         match self {
+            Linux::AlpineLinux(linux) => linux.is_enterprise(),
+            Linux::AmazonLinux(linux) => linux.is_enterprise(),
+            Linux::ArchLinux(linux) => linux.is_enterprise(),
             Linux::Debian(linux) => linux.is_enterprise(),
+            Linux::Devuan(linux) => linux.is_enterprise(),
+            Linux::Gentoo(linux) => linux.is_enterprise(),
+            Linux::KaliLinux(linux) => linux.is_enterprise(),
+            Linux::LinuxMint(linux) => linux.is_enterprise(),
             Linux::OracleLinux(linux) => linux.is_enterprise(),
+            Linux::RaspberryPiOS(linux) => linux.is_enterprise(),
             Linux::RedHatEnterpriseLinux(linux) => linux.is_enterprise(),
             Linux::Ubuntu(linux) => linux.is_enterprise(),
         }
@@ -66,20 +138,147 @@ impl Linux {
 
     pub fn is_lts(&self) -> bool {
         match self {
+            Linux::AlpineLinux(linux) => linux.is_lts(),
+            Linux::AmazonLinux(linux) => linux.is_lts(),
+            Linux::ArchLinux(linux) => linux.is_lts(),
             Linux::Debian(linux) => linux.is_lts(),
+            Linux::Devuan(linux) => linux.is_lts(),
+            Linux::Gentoo(linux) => linux.is_lts(),
+            Linux::KaliLinux(linux) => linux.is_lts(),
+            Linux::LinuxMint(linux) => linux.is_lts(),
             Linux::OracleLinux(linux) => linux.is_lts(),
+            Linux::RaspberryPiOS(linux) => linux.is_lts(),
             Linux::RedHatEnterpriseLinux(linux) => linux.is_lts(),
             Linux::Ubuntu(linux) => linux.is_lts(),
         }
     }
+
+    pub fn is_rolling(&self) -> bool {
+        match self {
+            Linux::AlpineLinux(linux) => linux.is_rolling(),
+            Linux::ArchLinux(linux) => linux.is_rolling(),
+            Linux::Gentoo(linux) => linux.is_rolling(),
+            Linux::KaliLinux(linux) => linux.is_rolling(),
+            _ => false,
+        }
+    }
+
+    pub fn is_pre_release(&self) -> bool {
+        match self {
+            Linux::Debian(linux) => linux.is_pre_release(),
+            Linux::Ubuntu(linux) => linux.is_pre_release(),
+            _ => false,
+        }
+    }
+
+    // Label of the release a derivative is built from
+    pub fn based_on(&self) -> Option<String> {
+        match self {
+            Linux::Devuan(linux) => linux.based_on(),
+            Linux::KaliLinux(linux) => linux.based_on(),
+            Linux::LinuxMint(linux) => linux.based_on(),
+            Linux::OracleLinux(linux) => linux.based_on(),
+            Linux::RaspberryPiOS(linux) => linux.based_on(),
+            Linux::Ubuntu(linux) => linux.based_on(),
+            _ => None,
+        }
+    }
+
+    // Labels of releases binary compatible with this one
+    pub fn compatible_with(&self) -> Vec<String> {
+        match self {
+            Linux::OracleLinux(linux) => linux.compatible_with(),
+            Linux::RedHatEnterpriseLinux(linux) => linux.compatible_with(),
+            _ => vec![],
+        }
+    }
+
+    // Major, minor and point components of releases numbered that way
+    pub fn release_components(&self) -> (Option<u32>, Option<u32>, Option<u32>) {
+        match self {
+            Linux::Debian(linux) => linux.release_components(),
+            Linux::OracleLinux(linux) => linux.release_components(),
+            Linux::RedHatEnterpriseLinux(linux) => linux.release_components(),
+            Linux::Ubuntu(linux) => linux.release_components(),
+            // Rolling releases are not numbered
+            Linux::ArchLinux(_) | Linux::Gentoo(_) => (None, None, None),
+            _ => crate::util::release_components(&self.release()),
+        }
+    }
+
+    // Release cycle endoflife.date tracks the product by
+    pub fn release_cycle(&self) -> String {
+        match self {
+            Linux::Debian(linux) => linux.release_cycle(),
+            Linux::OracleLinux(linux) => linux.release_cycle(),
+            Linux::RedHatEnterpriseLinux(linux) => linux.release_cycle(),
+            Linux::Ubuntu(linux) => linux.release_cycle(),
+            Linux::AlpineLinux(_) => crate::util::truncate_release(&self.release(), 2),
+            Linux::AmazonLinux(_) | Linux::Devuan(_) | Linux::RaspberryPiOS(_) => {
+                crate::util::truncate_release(&self.release(), 1)
+            },
+            // Linux Mint is tracked by point release, e.g. 21.3, Kali Linux by its quarterly release
+            _ => self.release(),
+        }
+    }
+
+    pub fn kernel_flavour(&self) -> Option<String> {
+        match self {
+            Linux::OracleLinux(linux) => linux.kernel_flavour(),
+            _ => None,
+        }
+    }
+
+    pub fn uek_release(&self) -> Option<String> {
+        match self {
+            Linux::OracleLinux(linux) => linux.uek_release(),
+            _ => None,
+        }
+    }
+
+    pub fn service_channel(&self) -> Option<String> {
+        match self {
+            Linux::Debian(linux) => linux.service_channel(),
+            Linux::RedHatEnterpriseLinux(linux) => linux.service_channel(),
+            Linux::Ubuntu(linux) => linux.service_channel(),
+            _ => None,
+        }
+    }
+
+    pub fn service_channel_at(&self, date: &str) -> Option<String> {
+        match self {
+            Linux::Debian(linux) => linux.service_channel_at(date),
+            Linux::Ubuntu(linux) => linux.service_channel_at(date),
+            _ => self.service_channel(),
+        }
+    }
 }
 
 impl TryFrom<&str> for Linux {
     type Error = String;
 
     fn try_from(value: &str) -> Result<Self, Self::Error> {
-        if let Ok(linux) = Debian::try_from(value) {
+        if let Ok(linux) = AlpineLinux::try_from(value) {
+            Ok(Linux::AlpineLinux(linux))
+        } else if let Ok(linux) = AmazonLinux::try_from(value) {
+            Ok(Linux::AmazonLinux(linux))
+        } else if let Ok(linux) = ArchLinux::try_from(value) {
+            Ok(Linux::ArchLinux(linux))
+        // Derivatives before Debian, their labels may name Debian, e.g. Linux Mint Debian Edition.
+        // They are identified by the names they report, e.g. in /etc/os-release, endoflife.date
+        // labels are not read for them.
+        } else if let Ok(linux) = Devuan::try_from(value) {
+            Ok(Linux::Devuan(linux))
+        } else if let Ok(linux) = KaliLinux::try_from(value) {
+            Ok(Linux::KaliLinux(linux))
+        } else if let Ok(linux) = LinuxMint::try_from(value) {
+            Ok(Linux::LinuxMint(linux))
+        } else if let Ok(linux) = RaspberryPiOS::try_from(value) {
+            Ok(Linux::RaspberryPiOS(linux))
+        } else if let Ok(linux) = Debian::try_from(value) {
             Ok(Linux::Debian(linux))
+        } else if let Ok(linux) = Gentoo::try_from(value) {
+            Ok(Linux::Gentoo(linux))
         } else if let Ok(linux) = OracleLinux::try_from(value) {
             Ok(Linux::OracleLinux(linux))
         } else if let Ok(linux) = RedHatEnterpriseLinux::try_from(value) {
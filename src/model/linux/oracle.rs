@@ -8,6 +8,7 @@ pub(crate) struct OracleLinux {
     release: Release,
     editions: Editions,
     service_channel: ServiceChannel,
+    kernel: Option<Kernel>,
 }
 
 impl OracleLinux {
@@ -18,9 +19,15 @@ impl OracleLinux {
             release,
             editions: Editions(vec![]),
             service_channel,
+            kernel: None,
         }
     }
 
+    pub(crate) fn kernel(mut self, kernel: Option<Kernel>) -> OracleLinux {
+        self.kernel = kernel;
+        self
+    }
+
     pub(super) fn vendor(&self) -> &str {
         self.vendor.as_str()
     }
@@ -46,6 +53,34 @@ impl OracleLinux {
         true
     }
 
+    // Oracle Linux is rebuilt from the sources of RHEL and binary compatible with the same release
+    pub(super) fn based_on(&self) -> Option<String> {
+        Some(format!("rhel-{}", self.release))
+    }
+
+    pub(super) fn compatible_with(&self) -> Vec<String> {
+        vec![format!("rhel-{}", self.release)]
+    }
+
+    pub(super) fn kernel_flavour(&self) -> Option<String> {
+        self.kernel.as_ref().map(|kernel| kernel.flavour().to_string())
+    }
+
+    pub(super) fn uek_release(&self) -> Option<String> {
+        match &self.kernel {
+            Some(Kernel::UEK(release)) => release.clone(),
+            _ => None,
+        }
+    }
+
+    pub(super) fn release_components(&self) -> (Option<u32>, Option<u32>, Option<u32>) {
+        self.release.components()
+    }
+
+    pub(super) fn release_cycle(&self) -> String {
+        self.release.cycle()
+    }
+
     pub(super) fn to_string(&self) -> Vec<String> {
         vec![format!(
             "{} {}",
@@ -70,6 +105,24 @@ impl TryFrom<&str> for OracleLinux {
 #[derive(Debug)]
 pub(crate) struct Release(String);
 
+impl Release {
+    pub(crate) fn components(&self) -> (Option<u32>, Option<u32>, Option<u32>) {
+        crate::util::release_components(&self.0)
+    }
+
+    pub(crate) fn major(&self) -> Option<u32> {
+        self.components().0
+    }
+
+    // endoflife.date tracks Oracle Linux by major release, e.g. 9
+    pub(crate) fn cycle(&self) -> String {
+        match self.major() {
+            Some(major) => major.to_string(),
+            None => self.0.clone(),
+        }
+    }
+}
+
 impl From<&str> for Release {
     fn from(value: &str) -> Self {
         Release(value.to_uppercase())
@@ -121,6 +174,23 @@ impl std::fmt::Display for Edition {
     }
 }
 
+// Oracle Linux ships the Unbreakable Enterprise Kernel next to the Red Hat Compatible Kernel
+#[derive(PartialEq, Debug)]
+pub(crate) enum Kernel {
+    RHCK,
+    // UEK release, e.g. R7, if known
+    UEK(Option<String>),
+}
+
+impl Kernel {
+    fn flavour(&self) -> &str {
+        match self {
+            Kernel::RHCK => "RHCK",
+            Kernel::UEK(_) => "UEK",
+        }
+    }
+}
+
 #[derive(PartialEq, Debug)]
 pub(crate) enum ServiceChannel {
     LTS,
@@ -221,4 +291,52 @@ mod tests {
         assert_eq!(label.editions.len(), 0);
         assert_eq!(label.service_channel, ServiceChannel::LTS);
     }
+
+    #[test]
+    fn test_from_string_arbitrary_uek() {
+        let label = OracleLinux::try_from("Oracle Linux Server 8.9 UEK R7").unwrap();
+
+        assert_eq!(label.release.to_string(), "8.9".to_string());
+        assert_eq!(label.kernel, Some(Kernel::UEK(Some("R7".to_string()))));
+        assert_eq!(label.kernel_flavour(), Some("UEK".to_string()));
+        assert_eq!(label.uek_release(), Some("R7".to_string()));
+    }
+
+    #[test]
+    fn test_from_string_arbitrary_uek_kernel() {
+        let label = OracleLinux::try_from("Oracle Linux 9.4 5.15.0-206.153.7.el9uek.x86_64").unwrap();
+
+        assert_eq!(label.release.to_string(), "9.4".to_string());
+        assert_eq!(label.uek_release(), Some("R7".to_string()));
+    }
+
+    #[test]
+    fn test_from_string_arbitrary_major_kernel() {
+        let label = OracleLinux::try_from("Oracle Linux 9 5.15.0-200.131.27.el9uek.x86_64").unwrap();
+
+        assert_eq!(label.release.to_string(), "9".to_string());
+        assert_eq!(label.uek_release(), Some("R7".to_string()));
+
+        // A kernel other than an Enterprise Linux one is not the Red Hat Compatible Kernel
+        let label = OracleLinux::try_from("Oracle Linux 9 6.1.0-18-amd64").unwrap();
+
+        assert_eq!(label.release.to_string(), "9".to_string());
+        assert_eq!(label.kernel, None);
+    }
+
+    #[test]
+    fn test_from_string_arbitrary_rhck_kernel() {
+        let label = OracleLinux::try_from("Oracle Linux Server release 8.10 4.18.0-553.el8_10.x86_64").unwrap();
+
+        assert_eq!(label.release.to_string(), "8.10".to_string());
+        assert_eq!(label.kernel, Some(Kernel::RHCK));
+        assert_eq!(label.uek_release(), None);
+    }
+
+    #[test]
+    fn test_from_string_no_kernel() {
+        let label = OracleLinux::try_from("oracle-linux-9").unwrap();
+
+        assert_eq!(label.kernel, None);
+    }
 }
@@ -0,0 +1,104 @@
+//
+// https://www.raspberrypi.com/software/operating-systems/
+//
+const VENDOR: &str = "Raspberry Pi";
+const PRODUCT: &str = "Raspberry Pi OS";
+
+#[derive(Debug)]
+pub(crate) struct RaspberryPiOS {
+    vendor: String,
+    product: String,
+    release: Release,
+}
+
+impl RaspberryPiOS {
+    pub(crate) fn build(release: Release) -> RaspberryPiOS {
+        RaspberryPiOS {
+            vendor: VENDOR.to_string(),
+            product: PRODUCT.to_string(),
+            release,
+        }
+    }
+
+    pub(super) fn vendor(&self) -> &str {
+        self.vendor.as_str()
+    }
+
+    pub(super) fn product(&self) -> &str {
+        self.product.as_str()
+    }
+
+    pub(super) fn release(&self) -> String {
+        self.release.to_string()
+    }
+
+    pub(crate) fn is_enterprise(&self) -> bool {
+        false
+    }
+
+    pub(crate) fn is_lts(&self) -> bool {
+        false
+    }
+
+    // Releases follow the numbering of the Debian release they are built from
+    pub(super) fn based_on(&self) -> Option<String> {
+        Some(format!("debian-{}", self.release))
+    }
+
+    pub(super) fn to_string(&self) -> Vec<String> {
+        vec![format!(
+            "{} {}",
+            self.product, self.release
+        )]
+    }
+}
+
+// Releases before 2020 report themselves as Raspbian
+impl TryFrom<&str> for RaspberryPiOS {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let label = crate::parser::generic::GenericLabel::from(value);
+        crate::parser::generic::linux::RaspberryPiOSParser::parse(&label)
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct Release(String);
+
+impl From<&str> for Release {
+    fn from(value: &str) -> Self {
+        Release(value.to_lowercase())
+    }
+}
+
+impl std::fmt::Display for Release {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_string_arbitrary_raspbian() {
+        let label = RaspberryPiOS::try_from("Raspbian GNU/Linux 11 (bullseye)").unwrap();
+
+        assert_eq!(label.vendor, "Raspberry Pi".to_string());
+        assert_eq!(label.product, "Raspberry Pi OS".to_string());
+        assert_eq!(label.release.to_string(), "11".to_string());
+        assert_eq!(label.based_on(), Some("debian-11".to_string()));
+        assert_eq!(label.to_string(), vec!["Raspberry Pi OS 11".to_string()]);
+    }
+
+    #[test]
+    fn test_from_string_arbitrary_codename_only() {
+        // 64 is the architecture, not the release
+        let label = RaspberryPiOS::try_from("Raspberry Pi OS (64-bit) bookworm").unwrap();
+
+        assert_eq!(label.release.to_string(), "12".to_string());
+        assert!(RaspberryPiOS::try_from("Raspberry Pi OS (64-bit)").is_err());
+    }
+}
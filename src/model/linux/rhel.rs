@@ -1,5 +1,6 @@
 const VENDOR: &str = "Red Hat";
 const PRODUCT: &str = "Red Hat Enterprise Linux";
+const PRODUCT_COREOS: &str = "Red Hat Enterprise Linux CoreOS";
 
 #[derive(Debug)]
 pub(crate) struct RedHatEnterpriseLinux {
@@ -33,11 +34,21 @@ impl RedHatEnterpriseLinux {
         self.release.to_string()
     }
 
+    // RHCOS is a product of its own, versioned with OpenShift, e.g. 4.14
     pub(crate) fn editions(mut self, editions: Editions) -> RedHatEnterpriseLinux {
+        self.product = if editions.contains(Edition::CoreOS) {
+            PRODUCT_COREOS.to_string()
+        } else {
+            PRODUCT.to_string()
+        };
         self.editions = editions;
         self
     }
 
+    fn is_coreos(&self) -> bool {
+        self.editions.contains(Edition::CoreOS)
+    }
+
     pub(crate) fn is_enterprise(&self) -> bool {
         true
     }
@@ -46,11 +57,54 @@ impl RedHatEnterpriseLinux {
         true
     }
 
+    pub(super) fn compatible_with(&self) -> Vec<String> {
+        if self.is_coreos() {
+            vec![]
+        } else {
+            vec![format!("oracle-linux-{}", self.release)]
+        }
+    }
+
+    pub(super) fn service_channel(&self) -> Option<String> {
+        if self.service_channel.is_default() {
+            None
+        } else {
+            Some(self.service_channel.to_string())
+        }
+    }
+
+    pub(super) fn release_components(&self) -> (Option<u32>, Option<u32>, Option<u32>) {
+        self.release.components()
+    }
+
+    // endoflife.date tracks OpenShift by minor release
+    pub(super) fn release_cycle(&self) -> String {
+        if self.is_coreos() {
+            self.release.to_string()
+        } else {
+            self.release.cycle()
+        }
+    }
+
     pub(super) fn to_string(&self) -> Vec<String> {
-        vec![format!(
-            "{} {}",
-            self.product, self.release
-        )]
+        let channel = if self.service_channel.is_default() {
+            String::new()
+        } else {
+            format!(" {}", self.service_channel)
+        };
+
+        if self.editions.0.is_empty() || self.is_coreos() {
+            vec![format!(
+                "{} {}{channel}",
+                self.product, self.release
+            )]
+        } else {
+            self.editions
+                .0
+                .iter()
+                .map(|edition| format!("{} {edition} {}{channel}", self.product, self.release))
+                .collect()
+        }
     }
 }
 
@@ -70,6 +124,24 @@ impl TryFrom<&str> for RedHatEnterpriseLinux {
 #[derive(Debug)]
 pub(crate) struct Release(String);
 
+impl Release {
+    pub(crate) fn components(&self) -> (Option<u32>, Option<u32>, Option<u32>) {
+        crate::util::release_components(&self.0)
+    }
+
+    pub(crate) fn major(&self) -> Option<u32> {
+        self.components().0
+    }
+
+    // endoflife.date tracks RHEL by major release, e.g. 9
+    pub(crate) fn cycle(&self) -> String {
+        match self.major() {
+            Some(major) => major.to_string(),
+            None => self.0.clone(),
+        }
+    }
+}
+
 impl From<&str> for Release {
     fn from(value: &str) -> Self {
         Release(value.to_uppercase())
@@ -102,30 +174,58 @@ impl Editions {
     }
 }
 
+// Variants of RHEL, releases up to 7 were split into Server, Workstation and Desktop
 #[derive(PartialEq, Debug)]
 pub(crate) enum Edition {
-    Core,
+    CoreOS,
     Desktop,
+    RealTime,
+    SAP,
     Server,
+    Workstation,
 }
 
 impl std::fmt::Display for Edition {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let out = match self {
-            Edition::Core => "Core",
+            Edition::CoreOS => "CoreOS",
             Edition::Desktop => "Desktop",
+            Edition::RealTime => "for Real Time",
+            Edition::SAP => "for SAP Solutions",
             Edition::Server => "Server",
+            Edition::Workstation => "Workstation",
         };
 
         write!(f, "{}", out.to_string())
     }
 }
 
+// Update streams of a minor release, support ends differ per stream
 #[derive(PartialEq, Debug)]
 pub(crate) enum ServiceChannel {
+    // Advanced Update Support
+    AUS,
+    // Update Services for SAP Solutions
+    E4S,
+    // Extended Life Cycle Support
+    ELS,
+    // Extended Update Support
+    EUS,
     LTS,
 }
 
+impl ServiceChannel {
+    fn is_default(&self) -> bool {
+        match self {
+            ServiceChannel::AUS => false,
+            ServiceChannel::E4S => false,
+            ServiceChannel::ELS => false,
+            ServiceChannel::EUS => false,
+            ServiceChannel::LTS => true,
+        }
+    }
+}
+
 impl From<&Release> for ServiceChannel {
     fn from(value: &Release) -> ServiceChannel {
         ServiceChannel::LTS
@@ -141,6 +241,10 @@ impl Default for ServiceChannel {
 impl std::fmt::Display for ServiceChannel {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let out = match self {
+            ServiceChannel::AUS => "AUS",
+            ServiceChannel::E4S => "E4S",
+            ServiceChannel::ELS => "ELS",
+            ServiceChannel::EUS => "EUS",
             ServiceChannel::LTS => "LTS",
         };
 
@@ -148,7 +252,19 @@ impl std::fmt::Display for ServiceChannel {
     }
 }
 
+impl TryFrom<&str> for ServiceChannel {
+    type Error = String;
 
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "aus" => Ok(ServiceChannel::AUS),
+            "e4s" => Ok(ServiceChannel::E4S),
+            "els" => Ok(ServiceChannel::ELS),
+            "eus" => Ok(ServiceChannel::EUS),
+            _ => Err(String::from("Not an RHEL update stream.")),
+        }
+    }
+}
 
 #[cfg(test)]
 mod tests {
@@ -234,7 +350,59 @@ mod tests {
         assert_eq!(label.product, "Red Hat Enterprise Linux".to_string());
         assert_eq!(label.release.to_string(), "5.11".to_string());
 
-        assert_eq!(label.editions.len(), 0);
+        assert_eq!(label.editions.len(), 1);
+        assert!(label.editions.contains(Edition::Server));
         assert_eq!(label.service_channel, ServiceChannel::LTS);
     }
+
+    #[test]
+    fn test_from_string_eus() {
+        let label = RedHatEnterpriseLinux::try_from("rhel-8.6-eus").unwrap();
+
+        assert_eq!(label.release.to_string(), "8.6".to_string());
+        assert_eq!(label.service_channel, ServiceChannel::EUS);
+        assert_eq!(label.to_string(), vec!["Red Hat Enterprise Linux 8.6 EUS".to_string()]);
+    }
+
+    #[test]
+    fn test_from_string_arbitrary_server() {
+        let label = RedHatEnterpriseLinux::try_from("Red Hat Enterprise Linux Server release 7.9 (Maipo)").unwrap();
+
+        assert_eq!(label.release.to_string(), "7.9".to_string());
+        assert_eq!(label.to_string(), vec!["Red Hat Enterprise Linux Server 7.9".to_string()]);
+    }
+
+    #[test]
+    fn test_from_string_arbitrary_eus() {
+        let label = RedHatEnterpriseLinux::try_from("RHEL 8.6 EUS").unwrap();
+
+        assert_eq!(label.release.to_string(), "8.6".to_string());
+        assert_eq!(label.service_channel, ServiceChannel::EUS);
+        assert_eq!(label.editions.len(), 0);
+    }
+
+    #[test]
+    fn test_from_string_arbitrary_sap() {
+        let label = RedHatEnterpriseLinux::try_from("Red Hat Enterprise Linux for SAP Solutions 8.8 Update Services for SAP Solutions").unwrap();
+
+        assert!(label.editions.contains(Edition::SAP));
+        assert_eq!(label.service_channel, ServiceChannel::E4S);
+        assert_eq!(label.to_string(), vec!["Red Hat Enterprise Linux for SAP Solutions 8.8 E4S".to_string()]);
+    }
+
+    #[test]
+    fn test_from_string_arbitrary_coreos() {
+        let label = RedHatEnterpriseLinux::try_from("Red Hat Enterprise Linux CoreOS 4.14").unwrap();
+
+        assert_eq!(label.product, "Red Hat Enterprise Linux CoreOS".to_string());
+        assert_eq!(label.release.to_string(), "4.14".to_string());
+        assert_eq!(label.release_cycle(), "4.14".to_string());
+        assert!(label.compatible_with().is_empty());
+        assert_eq!(label.to_string(), vec!["Red Hat Enterprise Linux CoreOS 4.14".to_string()]);
+
+        // The build carries the OpenShift release, 414, ahead of the RHEL one, 92
+        let label = RedHatEnterpriseLinux::try_from("RHCOS 414.92.202310170514-0").unwrap();
+
+        assert_eq!(label.release.to_string(), "4.14".to_string());
+    }
 }
@@ -1,6 +1,25 @@
 const VENDOR: &str = "Canonical";
 const PRODUCT: &str = "Ubuntu Linux";
 
+// LTS release and the Debian release it was imported from while that was still testing,
+// e.g. trixie (13) for 24.04
+const DEBIAN_RELEASES: [(&str, &str); 4] = [
+    ("18.04", "10"),
+    ("20.04", "11"),
+    ("22.04", "12"),
+    ("24.04", "13"),
+];
+
+// End of standard support of LTS releases, Ubuntu Pro continues with ESM
+const STANDARD_SUPPORT: [(&str, &str); 6] = [
+    ("14.04", "2019-04-30"),
+    ("16.04", "2021-04-30"),
+    ("18.04", "2023-05-31"),
+    ("20.04", "2025-05-31"),
+    ("22.04", "2027-06-30"),
+    ("24.04", "2029-05-31"),
+];
+
 #[derive(Debug)]
 pub(crate) struct Ubuntu {
     vendor: String,
@@ -8,6 +27,7 @@ pub(crate) struct Ubuntu {
     release: Release,
     editions: Editions,
     service_channel: ServiceChannel,
+    development: bool,
 }
 
 impl Ubuntu {
@@ -18,9 +38,15 @@ impl Ubuntu {
             release,
             editions: Editions(vec![]),
             service_channel,
+            development: false,
         }
     }
 
+    pub(crate) fn development(mut self, development: bool) -> Ubuntu {
+        self.development = development;
+        self
+    }
+
     pub(super) fn vendor(&self) -> &str {
         self.vendor.as_str()
     }
@@ -33,7 +59,12 @@ impl Ubuntu {
         self.release.to_string()
     }
 
+    // Official flavours carry a name of their own, e.g. Kubuntu
     pub(crate) fn editions(mut self, editions: Editions) -> Ubuntu {
+        self.product = match editions.0.as_slice() {
+            [edition] if edition.is_flavour() => edition.to_string(),
+            _ => PRODUCT.to_string(),
+        };
         self.editions = editions;
         self
     }
@@ -46,20 +77,71 @@ impl Ubuntu {
         match self.service_channel {
             ServiceChannel::Interim => false,
             ServiceChannel::LTS => true,
+            ServiceChannel::ESM => true,
+            ServiceChannel::Legacy => true,
+        }
+    }
+
+    // Development branch of the upcoming release, or the devel series itself
+    pub(crate) fn is_pre_release(&self) -> bool {
+        self.development || self.release.0 == "devel"
+    }
+
+    pub(super) fn based_on(&self) -> Option<String> {
+        let cycle = self.release.cycle();
+
+        DEBIAN_RELEASES
+            .iter()
+            .find(|(release, _)| *release == cycle)
+            .map(|(_, debian)| format!("debian-{debian}"))
+    }
+
+    // LTS is the default of LTS releases, as interim is of the others
+    pub(super) fn service_channel(&self) -> Option<String> {
+        if self.service_channel == ServiceChannel::from(&self.release) {
+            None
+        } else {
+            Some(self.service_channel.to_string())
+        }
+    }
+
+    // Service channel on a date formatted as "YYYY-MM-DD" unless the label names one
+    pub(super) fn service_channel_at(&self, date: &str) -> Option<String> {
+        if !crate::util::is_iso_date(date) {
+            return None;
+        }
+
+        let cycle = self.release.cycle();
+        let past_standard_support = STANDARD_SUPPORT
+            .iter()
+            .any(|(release, end)| *release == cycle && date > *end);
+
+        match self.service_channel {
+            ServiceChannel::LTS if past_standard_support => Some(ServiceChannel::ESM.to_string()),
+            _ => self.service_channel(),
         }
     }
 
+    pub(super) fn release_components(&self) -> (Option<u32>, Option<u32>, Option<u32>) {
+        self.release.components()
+    }
+
+    pub(super) fn release_cycle(&self) -> String {
+        self.release.cycle()
+    }
+
     pub(super) fn to_string(&self) -> Vec<String> {
-        let out = if self.service_channel.is_default() {
-            format!(
-                "{} {}",
-                self.product, self.release
-            )
+        let channel = if self.service_channel.is_default() {
+            String::new()
         } else {
-            format!(
-                "{} {} {}",
-                self.product, self.release, self.service_channel
-            )
+            format!(" {}", self.service_channel)
+        };
+
+        // Name the edition only if the label did, the product of a flavour already does
+        let out = match self.editions.0.as_slice() {
+            [edition] if edition.is_flavour() => format!("{} {}{channel}", self.product, self.release),
+            [edition] => format!("{} {edition} {}{channel}", self.product, self.release),
+            _ => format!("{} {}{channel}", self.product, self.release),
         };
 
         vec![out]
@@ -83,24 +165,46 @@ impl TryFrom<&str> for Ubuntu {
 pub(crate) struct Release(String);
 
 impl Release {
-    fn major_is_even(&self) -> bool {
-        let release: Vec<&str> = self.0.split('.').collect();
-        let major = release[0];
-        if major.parse::<i32>().unwrap() % 2 == 0 {
-            true
-        } else {
-            false
+    pub(crate) fn components(&self) -> (Option<u32>, Option<u32>, Option<u32>) {
+        crate::util::release_components(&self.0)
+    }
+
+    pub(crate) fn major(&self) -> Option<u32> {
+        self.components().0
+    }
+
+    pub(crate) fn minor(&self) -> Option<u32> {
+        self.components().1
+    }
+
+    // endoflife.date tracks Ubuntu by year and month, e.g. 22.04
+    pub(crate) fn cycle(&self) -> String {
+        match self.components() {
+            (Some(major), Some(minor), _) => format!("{major}.{minor:02}"),
+            _ => self.0.clone(),
+        }
+    }
+
+    // Ubuntu Core is versioned by the year of the LTS release it is built from, e.g. 24 for 24.04
+    pub(crate) fn core(self) -> Release {
+        match self.minor() {
+            None if self.major().is_some() => Release(format!("{}.04", self.0)),
+            _ => self,
         }
     }
 
+    fn major_is_even(&self) -> bool {
+        self.major().is_some_and(|major| major % 2 == 0)
+    }
+
     fn ends_with_04(&self) -> bool {
-        self.0.ends_with(".04")
+        self.minor() == Some(4)
     }
 }
 
 impl From<&str> for Release {
     fn from(value: &str) -> Self {
-        Release(value.to_uppercase())
+        Release(value.to_lowercase())
     }
 }
 
@@ -139,6 +243,27 @@ pub(crate) enum Edition {
     Core,
     Desktop,
     Server,
+    // Official flavours
+    Kubuntu,
+    Lubuntu,
+    MATE,
+    Studio,
+    Xubuntu,
+}
+
+impl Edition {
+    fn is_flavour(&self) -> bool {
+        match self {
+            Edition::Core => false,
+            Edition::Desktop => false,
+            Edition::Server => false,
+            Edition::Kubuntu => true,
+            Edition::Lubuntu => true,
+            Edition::MATE => true,
+            Edition::Studio => true,
+            Edition::Xubuntu => true,
+        }
+    }
 }
 
 impl std::fmt::Display for Edition {
@@ -147,6 +272,11 @@ impl std::fmt::Display for Edition {
             Edition::Core => "Core",
             Edition::Desktop => "Desktop",
             Edition::Server => "Server",
+            Edition::Kubuntu => "Kubuntu",
+            Edition::Lubuntu => "Lubuntu",
+            Edition::MATE => "Ubuntu MATE",
+            Edition::Studio => "Ubuntu Studio",
+            Edition::Xubuntu => "Xubuntu",
         };
 
         write!(f, "{}", out.to_string())
@@ -157,6 +287,10 @@ impl std::fmt::Display for Edition {
 pub(crate) enum ServiceChannel {
     Interim,
     LTS,
+    // Expanded Security Maintenance of Ubuntu Pro, after standard support of an LTS release ended
+    ESM,
+    // Legacy Support add-on of Ubuntu Pro, after ESM ended
+    Legacy,
 }
 
 impl ServiceChannel {
@@ -164,6 +298,8 @@ impl ServiceChannel {
         match self {
             ServiceChannel::Interim => true,
             ServiceChannel::LTS => false,
+            ServiceChannel::ESM => false,
+            ServiceChannel::Legacy => false,
         }
     }
 }
@@ -172,6 +308,8 @@ impl From<&str> for ServiceChannel {
     fn from(value: &str) -> Self {
         match value {
             "lts" => ServiceChannel::LTS,
+            "esm" => ServiceChannel::ESM,
+            "legacy" => ServiceChannel::Legacy,
             _ => ServiceChannel::Interim,
         }
     }
@@ -198,6 +336,8 @@ impl std::fmt::Display for ServiceChannel {
         let out = match self {
             ServiceChannel::Interim => "",
             ServiceChannel::LTS => "LTS",
+            ServiceChannel::ESM => "ESM",
+            ServiceChannel::Legacy => "Legacy",
         };
 
         write!(f, "{}", out)
@@ -297,7 +437,8 @@ mod tests {
 
         assert_eq!(label.vendor, "Canonical".to_string());
         assert_eq!(label.product, "Ubuntu Linux".to_string());
-        assert_eq!(label.release.to_string(), "16.04".to_string());
+        assert_eq!(label.release.to_string(), "16.04.7".to_string());
+        assert_eq!(label.release.cycle(), "16.04".to_string());
 
         assert_eq!(label.editions.len(), Editions::all().len());
         assert_eq!(label.service_channel, ServiceChannel::LTS);
@@ -314,4 +455,157 @@ mod tests {
         assert_eq!(label.editions.len(), Editions::all().len());
         assert_eq!(label.service_channel, ServiceChannel::LTS);
     }
+
+    #[test]
+    fn test_from_string_esm() {
+        let label = Ubuntu::try_from("ubuntu-18.04-esm").unwrap();
+
+        assert_eq!(label.release.to_string(), "18.04".to_string());
+        assert_eq!(label.service_channel, ServiceChannel::ESM);
+        assert_eq!(label.to_string(), vec!["Ubuntu Linux 18.04 ESM".to_string()]);
+    }
+
+    #[test]
+    fn test_from_string_pro() {
+        let label = Ubuntu::try_from("ubuntu-pro-16.04").unwrap();
+
+        assert_eq!(label.release.to_string(), "16.04".to_string());
+        assert_eq!(label.service_channel, ServiceChannel::LTS);
+        assert_eq!(label.service_channel_at("2021-04-30"), None);
+        assert_eq!(label.service_channel_at("2021-05-01"), Some("ESM".to_string()));
+    }
+
+    #[test]
+    fn test_from_string_arbitrary_esm() {
+        let label = Ubuntu::try_from("Ubuntu 18.04.6 LTS ESM").unwrap();
+
+        assert_eq!(label.release.to_string(), "18.04.6".to_string());
+        assert_eq!(label.service_channel, ServiceChannel::ESM);
+        assert!(label.is_lts());
+        assert_eq!(label.service_channel(), Some("ESM".to_string()));
+    }
+
+    #[test]
+    fn test_from_string_arbitrary_pro() {
+        let label = Ubuntu::try_from("Ubuntu Pro 22.04 LTS").unwrap();
+
+        assert_eq!(label.release.to_string(), "22.04".to_string());
+        assert_eq!(label.service_channel, ServiceChannel::LTS);
+        assert_eq!(label.service_channel(), None);
+        assert_eq!(label.service_channel_at("2026-10-19"), None);
+        assert_eq!(label.service_channel_at("2027-07-01"), Some("ESM".to_string()));
+
+        // Word order does not matter
+        let label = Ubuntu::try_from("Ubuntu 22.04 Pro").unwrap();
+
+        assert_eq!(label.service_channel, ServiceChannel::LTS);
+    }
+
+    #[test]
+    fn test_from_string_arbitrary_pro_invalid_date() {
+        let label = Ubuntu::try_from("Ubuntu Pro 22.04 LTS").unwrap();
+
+        assert_eq!(label.service_channel_at("2027/07/01"), None);
+        assert_eq!(label.service_channel_at("July 2027"), None);
+    }
+
+    #[test]
+    fn test_from_string_arbitrary_pro_20_04() {
+        let label = Ubuntu::try_from("Ubuntu Pro 20.04").unwrap();
+
+        assert_eq!(label.service_channel(), None);
+        assert_eq!(label.service_channel_at("2025-06-01"), Some("ESM".to_string()));
+    }
+
+    #[test]
+    fn test_from_string_arbitrary_esm_at() {
+        let label = Ubuntu::try_from("Ubuntu Pro 20.04 ESM").unwrap();
+
+        assert_eq!(label.service_channel_at("2020-04-23"), Some("ESM".to_string()));
+    }
+
+    #[test]
+    fn test_from_string_arbitrary_legacy() {
+        let label = Ubuntu::try_from("Ubuntu 14.04 LTS Legacy Support").unwrap();
+
+        assert_eq!(label.service_channel, ServiceChannel::Legacy);
+        assert_eq!(label.to_string(), vec!["Ubuntu Linux 14.04 Legacy".to_string()]);
+    }
+
+    #[test]
+    fn test_from_string_arbitrary_pro_interim() {
+        assert!(Ubuntu::try_from("Ubuntu Pro 23.10").is_err());
+        assert!(Ubuntu::try_from("Ubuntu 22.10 ESM").is_err());
+        assert!(Ubuntu::try_from("ubuntu-22.10-esm").is_err());
+        assert!(Ubuntu::try_from("ubuntu-pro-23.10").is_err());
+    }
+
+    #[test]
+    fn test_from_string_arbitrary_server() {
+        let label = Ubuntu::try_from("Ubuntu Server 22.04").unwrap();
+
+        assert_eq!(label.editions.len(), 1);
+        assert!(label.editions.contains(Edition::Server));
+        assert_eq!(label.to_string(), vec!["Ubuntu Linux Server 22.04 LTS".to_string()]);
+    }
+
+    #[test]
+    fn test_from_string_arbitrary_core() {
+        let label = Ubuntu::try_from("Ubuntu Core 24").unwrap();
+
+        assert_eq!(label.release.to_string(), "24.04".to_string());
+        assert!(label.editions.contains(Edition::Core));
+        assert_eq!(label.service_channel, ServiceChannel::LTS);
+        assert_eq!(label.to_string(), vec!["Ubuntu Linux Core 24.04 LTS".to_string()]);
+
+        let label = Ubuntu::try_from("ubuntu-core-24").unwrap();
+
+        assert_eq!(label.release.to_string(), "24.04".to_string());
+        assert!(label.editions.contains(Edition::Core));
+        assert_eq!(label.to_string(), vec!["Ubuntu Linux Core 24.04 LTS".to_string()]);
+    }
+
+    #[test]
+    fn test_from_string_arbitrary_desktop() {
+        let label = Ubuntu::try_from("Ubuntu Desktop 25.10").unwrap();
+
+        assert!(label.editions.contains(Edition::Desktop));
+        assert_eq!(label.to_string(), vec!["Ubuntu Linux Desktop 25.10".to_string()]);
+    }
+
+    #[test]
+    fn test_from_string_arbitrary_flavours() {
+        let label = Ubuntu::try_from("Kubuntu 24.04.1 LTS").unwrap();
+
+        assert!(label.editions.contains(Edition::Kubuntu));
+        assert_eq!(label.product, "Kubuntu".to_string());
+        assert_eq!(label.to_string(), vec!["Kubuntu 24.04.1 LTS".to_string()]);
+
+        let label = Ubuntu::try_from("Ubuntu MATE 22.04").unwrap();
+
+        assert!(label.editions.contains(Edition::MATE));
+        assert_eq!(label.to_string(), vec!["Ubuntu MATE 22.04 LTS".to_string()]);
+
+        let label = Ubuntu::try_from("Ubuntu Studio 24.10").unwrap();
+
+        assert!(label.editions.contains(Edition::Studio));
+        assert_eq!(label.to_string(), vec!["Ubuntu Studio 24.10".to_string()]);
+
+        assert!(Ubuntu::try_from("Xubuntu 22.04").unwrap().editions.contains(Edition::Xubuntu));
+        assert!(Ubuntu::try_from("Lubuntu 20.04 LTS").unwrap().editions.contains(Edition::Lubuntu));
+    }
+
+    #[test]
+    fn test_from_string_arbitrary_development() {
+        let label = Ubuntu::try_from("Ubuntu 25.04 (development branch)").unwrap();
+
+        assert_eq!(label.release.to_string(), "25.04".to_string());
+        assert!(label.is_pre_release());
+
+        let label = Ubuntu::try_from("Ubuntu devel").unwrap();
+
+        assert_eq!(label.release.to_string(), "devel".to_string());
+        assert!(label.is_pre_release());
+        assert!(!label.is_lts());
+    }
 }
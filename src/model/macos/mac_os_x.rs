@@ -0,0 +1,190 @@
+//
+// https://support.apple.com/en-us/109033
+//
+const VENDOR: &str = "Apple";
+
+/// Marketing names of Mac OS X, OS X and macOS 10.x releases.
+pub(crate) const CODENAMES: [(&str, &str); 16] = [
+    ("10.0", "Cheetah"),
+    ("10.1", "Puma"),
+    ("10.2", "Jaguar"),
+    ("10.3", "Panther"),
+    ("10.4", "Tiger"),
+    ("10.5", "Leopard"),
+    ("10.6", "Snow Leopard"),
+    ("10.7", "Lion"),
+    ("10.8", "Mountain Lion"),
+    ("10.9", "Mavericks"),
+    ("10.10", "Yosemite"),
+    ("10.11", "El Capitan"),
+    ("10.12", "Sierra"),
+    ("10.13", "High Sierra"),
+    ("10.14", "Mojave"),
+    ("10.15", "Catalina"),
+];
+
+/// MacOSX covers the 10.x releases, marketed as Mac OS X (10.0 to 10.7), OS X (10.8 to 10.11)
+/// and macOS (10.12 to 10.15).
+#[derive(Debug)]
+pub(crate) struct MacOSX {
+    vendor: String,
+    product: String,
+    release: Release,
+}
+
+impl MacOSX {
+    pub(crate) fn build(release: Release) -> MacOSX {
+        MacOSX {
+            vendor: VENDOR.to_string(),
+            product: release.product().to_string(),
+            release,
+        }
+    }
+
+    pub(super) fn vendor(&self) -> &str {
+        self.vendor.as_str()
+    }
+
+    pub(super) fn product(&self) -> &str {
+        self.product.as_str()
+    }
+
+    pub(super) fn release(&self) -> String {
+        self.release.to_string()
+    }
+
+    pub(super) fn is_enterprise(&self) -> bool {
+        false
+    }
+
+    pub(super) fn is_lts(&self) -> bool {
+        false
+    }
+
+    pub(super) fn to_string(&self) -> Vec<String> {
+        let out = match self.release.codename() {
+            Some(codename) => format!(
+                "{} {} {} {codename}",
+                self.vendor, self.product, self.release.major()
+            ),
+            None => format!(
+                "{} {} {}",
+                self.vendor, self.product, self.release.major()
+            ),
+        };
+
+        vec![out]
+    }
+}
+
+impl TryFrom<&str> for MacOSX {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        if let Ok(label) = crate::parser::endoflife::EndOfLifeLabel::try_from(value) {
+            crate::parser::endoflife::macos::MacOSXParser::parse(&label)
+        } else {
+            let label = crate::parser::generic::GenericLabel::from(value);
+            crate::parser::generic::macos::MacOSXParser::parse(&label)
+        }
+    }
+}
+
+/// Release of Mac OS X, e.g. 10.15 or 10.15.7.
+#[derive(Debug)]
+pub(crate) struct Release(String);
+
+impl Release {
+    // 10.x releases are identified by their first two components, e.g. 10.15
+    fn major(&self) -> String {
+        self.0.split('.').take(2).collect::<Vec<&str>>().join(".")
+    }
+
+    fn minor(&self) -> u32 {
+        self.0.split('.').nth(1).and_then(|minor| minor.parse().ok()).unwrap_or_default()
+    }
+
+    fn codename(&self) -> Option<&str> {
+        let major = self.major();
+
+        CODENAMES.iter()
+            .find(|(release, _)| *release == major)
+            .map(|(_, codename)| *codename)
+    }
+
+    fn product(&self) -> &str {
+        match self.minor() {
+            0..=7 => "Mac OS X",
+            8..=11 => "OS X",
+            _ => "macOS",
+        }
+    }
+}
+
+impl From<&str> for Release {
+    fn from(value: &str) -> Self {
+        Release(value.to_string())
+    }
+}
+
+impl std::fmt::Display for Release {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_string_1() {
+        let label = MacOSX::try_from("macos-10.15").unwrap();
+
+        assert_eq!(label.vendor, "Apple".to_string());
+        assert_eq!(label.product, "macOS".to_string());
+        assert_eq!(label.release.to_string(), "10.15".to_string());
+        assert_eq!(label.to_string(), vec!["Apple macOS 10.15 Catalina".to_string()]);
+    }
+
+    #[test]
+    fn test_from_string_2() {
+        let label = MacOSX::try_from("macos-10.6").unwrap();
+
+        assert_eq!(label.product, "Mac OS X".to_string());
+        assert_eq!(label.to_string(), vec!["Apple Mac OS X 10.6 Snow Leopard".to_string()]);
+    }
+
+    #[test]
+    fn test_from_string_arbitrary1() {
+        let label = MacOSX::try_from("Mac OS X 10.15.7").unwrap();
+
+        assert_eq!(label.vendor, "Apple".to_string());
+        assert_eq!(label.product, "macOS".to_string());
+        assert_eq!(label.release.to_string(), "10.15.7".to_string());
+        assert_eq!(label.to_string(), vec!["Apple macOS 10.15 Catalina".to_string()]);
+    }
+
+    #[test]
+    fn test_from_string_arbitrary2() {
+        let label = MacOSX::try_from("Mac OS X El Capitan").unwrap();
+
+        assert_eq!(label.product, "OS X".to_string());
+        assert_eq!(label.release.to_string(), "10.11".to_string());
+        assert_eq!(label.to_string(), vec!["Apple OS X 10.11 El Capitan".to_string()]);
+    }
+
+    #[test]
+    fn test_from_string_arbitrary3() {
+        let label = MacOSX::try_from("macOS High Sierra").unwrap();
+
+        assert_eq!(label.release.to_string(), "10.13".to_string());
+    }
+
+    #[test]
+    fn test_from_string_arbitrary4() {
+        let label = MacOSX::try_from("Darwin 19.6.0").unwrap();
+
+        assert_eq!(label.release.to_string(), "10.15".to_string());
+    }
+}
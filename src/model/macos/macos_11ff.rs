@@ -0,0 +1,165 @@
+//
+// https://support.apple.com/en-us/109033
+//
+const VENDOR: &str = "Apple";
+const PRODUCT: &str = "macOS";
+
+/// Marketing names of macOS releases, starting with macOS 11.
+pub(crate) const CODENAMES: [(&str, &str); 6] = [
+    ("11", "Big Sur"),
+    ("12", "Monterey"),
+    ("13", "Ventura"),
+    ("14", "Sonoma"),
+    ("15", "Sequoia"),
+    ("26", "Tahoe"),
+];
+
+/// MacOS11ff covers macOS 11 Big Sur and later, which are versioned by their major release.
+#[derive(Debug)]
+pub(crate) struct MacOS11ff {
+    vendor: String,
+    product: String,
+    release: Release,
+}
+
+impl MacOS11ff {
+    pub(crate) fn build(release: Release) -> MacOS11ff {
+        MacOS11ff {
+            vendor: VENDOR.to_string(),
+            product: PRODUCT.to_string(),
+            release,
+        }
+    }
+
+    pub(super) fn vendor(&self) -> &str {
+        self.vendor.as_str()
+    }
+
+    pub(super) fn product(&self) -> &str {
+        self.product.as_str()
+    }
+
+    pub(super) fn release(&self) -> String {
+        self.release.to_string()
+    }
+
+    pub(super) fn is_enterprise(&self) -> bool {
+        false
+    }
+
+    pub(super) fn is_lts(&self) -> bool {
+        false
+    }
+
+    pub(super) fn to_string(&self) -> Vec<String> {
+        let out = match self.release.codename() {
+            Some(codename) => format!(
+                "{} {} {} {codename}",
+                self.vendor, self.product, self.release.major()
+            ),
+            None => format!(
+                "{} {} {}",
+                self.vendor, self.product, self.release.major()
+            ),
+        };
+
+        vec![out]
+    }
+}
+
+impl TryFrom<&str> for MacOS11ff {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        if let Ok(label) = crate::parser::endoflife::EndOfLifeLabel::try_from(value) {
+            crate::parser::endoflife::macos::MacOS11ffParser::parse(&label)
+        } else {
+            let label = crate::parser::generic::GenericLabel::from(value);
+            crate::parser::generic::macos::MacOS11ffParser::parse(&label)
+        }
+    }
+}
+
+/// Release of macOS, e.g. 14 or 14.6.1.
+#[derive(Debug)]
+pub(crate) struct Release(String);
+
+impl Release {
+    fn major(&self) -> &str {
+        self.0.split('.').next().unwrap_or_default()
+    }
+
+    fn codename(&self) -> Option<&str> {
+        CODENAMES.iter()
+            .find(|(release, _)| *release == self.major())
+            .map(|(_, codename)| *codename)
+    }
+}
+
+impl From<&str> for Release {
+    fn from(value: &str) -> Self {
+        Release(value.to_string())
+    }
+}
+
+impl std::fmt::Display for Release {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_string_1() {
+        let label = MacOS11ff::try_from("macos-14").unwrap();
+
+        assert_eq!(label.vendor, "Apple".to_string());
+        assert_eq!(label.product, "macOS".to_string());
+        assert_eq!(label.release.to_string(), "14".to_string());
+        assert_eq!(label.to_string(), vec!["Apple macOS 14 Sonoma".to_string()]);
+    }
+
+    #[test]
+    fn test_from_string_2() {
+        let label = MacOS11ff::try_from("macos-26").unwrap();
+
+        assert_eq!(label.release.to_string(), "26".to_string());
+        assert_eq!(label.to_string(), vec!["Apple macOS 26 Tahoe".to_string()]);
+    }
+
+    #[test]
+    fn test_from_string_arbitrary1() {
+        let label = MacOS11ff::try_from("macOS 14.6.1").unwrap();
+
+        assert_eq!(label.vendor, "Apple".to_string());
+        assert_eq!(label.product, "macOS".to_string());
+        assert_eq!(label.release.to_string(), "14.6.1".to_string());
+        assert_eq!(label.to_string(), vec!["Apple macOS 14 Sonoma".to_string()]);
+    }
+
+    #[test]
+    fn test_from_string_arbitrary2() {
+        let label = MacOS11ff::try_from("macOS Sequoia").unwrap();
+
+        assert_eq!(label.release.to_string(), "15".to_string());
+        assert_eq!(label.to_string(), vec!["Apple macOS 15 Sequoia".to_string()]);
+    }
+
+    #[test]
+    fn test_from_string_arbitrary3() {
+        let label = MacOS11ff::try_from("Darwin Kernel Version 23.6.0: Mon Jul 29 21:14:30 PDT 2024").unwrap();
+
+        assert_eq!(label.release.to_string(), "14".to_string());
+        assert_eq!(label.to_string(), vec!["Apple macOS 14 Sonoma".to_string()]);
+    }
+
+    #[test]
+    fn test_from_string_arbitrary4() {
+        let label = MacOS11ff::try_from("Mac OS X 10.15.7");
+
+        assert!(label.is_err());
+    }
+}
@@ -0,0 +1,81 @@
+pub(crate) mod mac_os_x;
+pub(crate) use mac_os_x::MacOSX;
+
+pub(crate) mod macos_11ff;
+pub(crate) use macos_11ff::MacOS11ff;
+
+#[derive(Debug)]
+pub(crate) enum MacOS {
+    MacOSX(MacOSX),
+    MacOS11ff(MacOS11ff),
+}
+
+impl MacOS {
+    pub fn to_string(&self) -> Vec<String> {
+        match self {
+            MacOS::MacOSX(macos) => macos.to_string(),
+            MacOS::MacOS11ff(macos) => macos.to_string(),
+        }
+    }
+
+    pub fn vendor(&self) -> String {
+        match self {
+            MacOS::MacOSX(macos) => String::from(macos.vendor()),
+            MacOS::MacOS11ff(macos) => String::from(macos.vendor()),
+        }
+    }
+
+    pub fn product(&self) -> String {
+        match self {
+            MacOS::MacOSX(macos) => String::from(macos.product()),
+            MacOS::MacOS11ff(macos) => String::from(macos.product()),
+        }
+    }
+
+    pub fn release(&self) -> String {
+        match self {
+            MacOS::MacOSX(macos) => macos.release(),
+            MacOS::MacOS11ff(macos) => macos.release(),
+        }
+    }
+
+    pub fn release_components(&self) -> (Option<u32>, Option<u32>, Option<u32>) {
+        crate::util::release_components(&self.release())
+    }
+
+    // Mac OS X is tracked by minor release, e.g. 10.15, macOS 11 and later by major release
+    pub fn release_cycle(&self) -> String {
+        match self {
+            MacOS::MacOSX(macos) => crate::util::truncate_release(&macos.release(), 2),
+            MacOS::MacOS11ff(macos) => crate::util::truncate_release(&macos.release(), 1),
+        }
+    }
+
+    pub fn is_enterprise(&self) -> bool {
+        match self {
+            MacOS::MacOSX(macos) => macos.is_enterprise(),
+            MacOS::MacOS11ff(macos) => macos.is_enterprise(),
+        }
+    }
+
+    pub fn is_lts(&self) -> bool {
+        match self {
+            MacOS::MacOSX(macos) => macos.is_lts(),
+            MacOS::MacOS11ff(macos) => macos.is_lts(),
+        }
+    }
+}
+
+impl TryFrom<&str> for MacOS {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        if let Ok(macos) = MacOS11ff::try_from(value) {
+            Ok(MacOS::MacOS11ff(macos))
+        } else if let Ok(macos) = MacOSX::try_from(value) {
+            Ok(MacOS::MacOSX(macos))
+        } else {
+            Err(format!("Not a macOS: {}", value))
+        }
+    }
+}
@@ -0,0 +1,159 @@
+//
+// https://developer.android.com/tools/releases/platforms
+//
+const VENDOR: &str = "Google";
+const PRODUCT: &str = "Android";
+
+#[derive(Debug)]
+pub(crate) struct Android {
+    vendor: String,
+    product: String,
+    release: Release,
+    api_level: Option<u32>,
+}
+
+impl Android {
+    pub(crate) fn build(release: Release) -> Android {
+        Android {
+            vendor: VENDOR.to_string(),
+            product: PRODUCT.to_string(),
+            release,
+            api_level: None,
+        }
+    }
+
+    pub(crate) fn api(mut self, api_level: Option<u32>) -> Self {
+        self.api_level = api_level;
+        self
+    }
+
+    pub(super) fn vendor(&self) -> &str {
+        self.vendor.as_str()
+    }
+
+    pub(super) fn product(&self) -> &str {
+        self.product.as_str()
+    }
+
+    pub(super) fn release(&self) -> String {
+        self.release.to_string()
+    }
+
+    pub(super) fn api_level(&self) -> Option<u32> {
+        self.api_level
+    }
+
+    pub(super) fn is_enterprise(&self) -> bool {
+        false
+    }
+
+    pub(super) fn is_lts(&self) -> bool {
+        false
+    }
+
+    pub(super) fn to_string(&self) -> Vec<String> {
+        let out = match self.api_level {
+            Some(api) => format!("{} {} (API {api})", self.product, self.release),
+            None => format!("{} {}", self.product, self.release),
+        };
+
+        vec![out]
+    }
+}
+
+impl TryFrom<&str> for Android {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        if let Ok(label) = crate::parser::endoflife::EndOfLifeLabel::try_from(value) {
+            crate::parser::endoflife::mobile::AndroidParser::parse(&label)
+        } else {
+            let label = crate::parser::generic::GenericLabel::from(value);
+            crate::parser::generic::mobile::AndroidParser::parse(&label)
+        }
+    }
+}
+
+/// Release of Android, e.g. 14, 12L or 8.1.
+#[derive(Debug)]
+pub(crate) struct Release(String);
+
+impl From<&str> for Release {
+    fn from(value: &str) -> Self {
+        Release(value.to_string())
+    }
+}
+
+impl std::fmt::Display for Release {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_string_1() {
+        let label = Android::try_from("android-14").unwrap();
+
+        assert_eq!(label.vendor, "Google".to_string());
+        assert_eq!(label.product, "Android".to_string());
+        assert_eq!(label.release.to_string(), "14".to_string());
+        assert_eq!(label.api_level, Some(34));
+        assert_eq!(label.to_string(), vec!["Android 14 (API 34)".to_string()]);
+    }
+
+    #[test]
+    fn test_from_string_arbitrary1() {
+        let label = Android::try_from("Android 14 (API 34)").unwrap();
+
+        assert_eq!(label.release.to_string(), "14".to_string());
+        assert_eq!(label.api_level, Some(34));
+    }
+
+    #[test]
+    fn test_from_string_arbitrary2() {
+        let label = Android::try_from("Android API level 32").unwrap();
+
+        assert_eq!(label.release.to_string(), "12L".to_string());
+        assert_eq!(label.api_level, Some(32));
+    }
+
+    #[test]
+    fn test_from_string_arbitrary3() {
+        let label = Android::try_from("Android 8.1.0").unwrap();
+
+        assert_eq!(label.release.to_string(), "8.1".to_string());
+        assert_eq!(label.api_level, Some(27));
+    }
+
+    #[test]
+    fn test_from_string_arbitrary4() {
+        let label = Android::try_from("Android 4.4.2").unwrap();
+
+        assert_eq!(label.release.to_string(), "4.4".to_string());
+        assert_eq!(label.api_level, Some(19));
+
+        let label = Android::try_from("android-6").unwrap();
+
+        assert_eq!(label.release.to_string(), "6.0".to_string());
+        assert_eq!(label.api_level, Some(23));
+
+        // Releases not known to the map are rejected
+        assert!(Android::try_from("Android 17").is_err());
+        assert!(Android::try_from("android-17").is_err());
+    }
+
+    #[test]
+    fn test_from_string_arbitrary_other_product() {
+        assert!(Android::try_from("Windows 10 Pro 22H2 on Android 14").is_err());
+    }
+
+    #[test]
+    fn test_from_string_arbitrary_api_mismatch() {
+        assert!(Android::try_from("Android 14 (API 30)").is_err());
+        assert!(Android::try_from("Android 14 (API 34)").is_ok());
+    }
+}
@@ -0,0 +1,163 @@
+//
+// https://support.apple.com/en-us/100100
+//
+const VENDOR: &str = "Apple";
+
+/// IOS covers iOS and iPadOS, which share releases and build numbers.
+#[derive(Debug)]
+pub(crate) struct IOS {
+    vendor: String,
+    product: Product,
+    release: Release,
+    build: Option<String>,
+}
+
+impl IOS {
+    pub(crate) fn build(product: Product, release: Release) -> IOS {
+        IOS {
+            vendor: VENDOR.to_string(),
+            product,
+            release,
+            build: None,
+        }
+    }
+
+    pub(crate) fn installed_build(mut self, build: &str) -> Self {
+        self.build = Some(build.to_string());
+        self
+    }
+
+    pub(super) fn vendor(&self) -> &str {
+        self.vendor.as_str()
+    }
+
+    pub(super) fn product(&self) -> &str {
+        self.product.as_str()
+    }
+
+    pub(super) fn release(&self) -> String {
+        self.release.to_string()
+    }
+
+    pub(super) fn build_number(&self) -> Option<String> {
+        self.build.clone()
+    }
+
+    pub(super) fn is_enterprise(&self) -> bool {
+        false
+    }
+
+    pub(super) fn is_lts(&self) -> bool {
+        false
+    }
+
+    pub(super) fn to_string(&self) -> Vec<String> {
+        vec![format!("{} {} {}", self.vendor, self.product.as_str(), self.release)]
+    }
+}
+
+impl TryFrom<&str> for IOS {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        if let Ok(label) = crate::parser::endoflife::EndOfLifeLabel::try_from(value) {
+            crate::parser::endoflife::mobile::IOSParser::parse(&label)
+        } else {
+            let label = crate::parser::generic::GenericLabel::from(value);
+            crate::parser::generic::mobile::IOSParser::parse(&label)
+        }
+    }
+}
+
+#[derive(PartialEq, Debug)]
+pub(crate) enum Product {
+    IOS,
+    IPadOS,
+}
+
+impl Product {
+    fn as_str(&self) -> &str {
+        match self {
+            Product::IOS => "iOS",
+            Product::IPadOS => "iPadOS",
+        }
+    }
+}
+
+/// Release of iOS or iPadOS, e.g. 17 or 17.6.1.
+#[derive(Debug)]
+pub(crate) struct Release(String);
+
+impl From<&str> for Release {
+    fn from(value: &str) -> Self {
+        Release(value.to_string())
+    }
+}
+
+impl std::fmt::Display for Release {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_string_1() {
+        let label = IOS::try_from("ios-17").unwrap();
+
+        assert_eq!(label.vendor, "Apple".to_string());
+        assert_eq!(label.product, Product::IOS);
+        assert_eq!(label.release.to_string(), "17".to_string());
+        assert_eq!(label.to_string(), vec!["Apple iOS 17".to_string()]);
+    }
+
+    #[test]
+    fn test_from_string_2() {
+        let label = IOS::try_from("ipados-17").unwrap();
+
+        assert_eq!(label.product, Product::IPadOS);
+        assert_eq!(label.to_string(), vec!["Apple iPadOS 17".to_string()]);
+    }
+
+    #[test]
+    fn test_from_string_arbitrary1() {
+        let label = IOS::try_from("iOS 17.6.1 (21G93)").unwrap();
+
+        assert_eq!(label.release.to_string(), "17.6.1".to_string());
+        assert_eq!(label.build, Some("21G93".to_string()));
+    }
+
+    #[test]
+    fn test_from_string_arbitrary2() {
+        let label = IOS::try_from("iPadOS 21G93").unwrap();
+
+        assert_eq!(label.product, Product::IPadOS);
+        assert_eq!(label.release.to_string(), "17.6.1".to_string());
+    }
+
+    #[test]
+    fn test_from_string_arbitrary3() {
+        assert!(IOS::try_from("iOS 17.6.1 (21G80)").is_err());
+    }
+
+    #[test]
+    fn test_from_string_arbitrary4() {
+        let label = IOS::try_from("iOS 21C66").unwrap();
+
+        assert_eq!(label.release.to_string(), "17.2.1".to_string());
+    }
+
+    #[test]
+    fn test_from_string_arbitrary_unknown_release() {
+        let label = IOS::try_from("iPadOS 26.1").unwrap();
+
+        assert_eq!(label.release.to_string(), "26.1".to_string());
+
+        // Releases not known to the map are rejected
+        assert!(IOS::try_from("iOS 12.5.7").is_err());
+        assert!(IOS::try_from("ios-12").is_err());
+    }
+}
@@ -0,0 +1,94 @@
+pub(crate) mod android;
+pub(crate) use android::Android;
+
+pub(crate) mod ios;
+pub(crate) use ios::IOS;
+
+#[derive(Debug)]
+pub(crate) enum Mobile {
+    Android(Android),
+    IOS(IOS),
+}
+
+impl Mobile {
+    pub fn to_string(&self) -> Vec<String> {
+        match self {
+            Mobile::Android(m) => m.to_string(),
+            Mobile::IOS(m) => m.to_string(),
+        }
+    }
+
+    pub fn vendor(&self) -> String {
+        match self {
+            Mobile::Android(m) => String::from(m.vendor()),
+            Mobile::IOS(m) => String::from(m.vendor()),
+        }
+    }
+
+    pub fn product(&self) -> String {
+        match self {
+            Mobile::Android(m) => String::from(m.product()),
+            Mobile::IOS(m) => String::from(m.product()),
+        }
+    }
+
+    pub fn release(&self) -> String {
+        match self {
+            Mobile::Android(m) => m.release(),
+            Mobile::IOS(m) => m.release(),
+        }
+    }
+
+    pub fn release_components(&self) -> (Option<u32>, Option<u32>, Option<u32>) {
+        crate::util::release_components(&self.release())
+    }
+
+    // Both are tracked by major release, e.g. 17 for iOS 17.6.1
+    pub fn release_cycle(&self) -> String {
+        crate::util::truncate_release(&self.release(), 1)
+    }
+
+    // API level of an Android release, e.g. 34 for Android 14
+    pub fn api_level(&self) -> Option<u32> {
+        match self {
+            Mobile::Android(m) => m.api_level(),
+            _ => None,
+        }
+    }
+
+    // Build number of an iOS or iPadOS release, e.g. 21G93 for 17.6.1
+    pub fn build(&self) -> Option<String> {
+        match self {
+            Mobile::IOS(m) => m.build_number(),
+            _ => None,
+        }
+    }
+
+    pub fn is_enterprise(&self) -> bool {
+        match self {
+            Mobile::Android(m) => m.is_enterprise(),
+            Mobile::IOS(m) => m.is_enterprise(),
+        }
+    }
+
+    pub fn is_lts(&self) -> bool {
+        match self {
+            Mobile::Android(m) => m.is_lts(),
+            Mobile::IOS(m) => m.is_lts(),
+        }
+    }
+}
+
+impl TryFrom<&str> for Mobile {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        if let Ok(android) = Android::try_from(value) {
+            Ok(Mobile::Android(android))
+        } else if let Ok(ios) = IOS::try_from(value) {
+            Ok(Mobile::IOS(ios))
+        } else {
+            Err(format!("Not a mobile operating system: {}", value))
+        }
+    }
+}
@@ -1,6 +1,18 @@
+mod bsd;
+pub(crate) use bsd::*;
+
+mod hypervisor;
+pub(crate) use hypervisor::*;
+
 mod linux;
 pub(crate) use linux::*;
 
+mod macos;
+pub(crate) use macos::*;
+
+mod mobile;
+pub(crate) use mobile::*;
+
 mod windows;
 pub(crate) use windows::*;
 
@@ -12,14 +24,34 @@ pub struct OS(OperatingSystem);
 
 #[derive(Debug)]
 enum OperatingSystem {
+    BSD(BSD),
+    Hypervisor(Hypervisor),
     Linux(Linux),
+    MacOS(MacOS),
+    Mobile(Mobile),
     Windows(Windows),
 }
 
+// Public interface
+#[derive(Debug)]
+pub struct BSD(bsd::BSD);
+
+// Public interface
+#[derive(Debug)]
+pub struct Hypervisor(hypervisor::Hypervisor);
+
 // Public interface
 #[derive(Debug)]
 pub struct Linux(linux::Linux);
 
+// Public interface
+#[derive(Debug)]
+pub struct MacOS(macos::MacOS);
+
+// Public interface
+#[derive(Debug)]
+pub struct Mobile(mobile::Mobile);
+
 // Public interface
 #[derive(Debug)]
 pub struct Windows(windows::Windows);
@@ -33,44 +65,211 @@ impl OS {
 
     pub fn vendor(&self) -> String {
         match &self.0 {
+            OperatingSystem::BSD(b) => b.vendor(),
+            OperatingSystem::Hypervisor(h) => h.vendor(),
             OperatingSystem::Linux(l) => l.vendor(),
+            OperatingSystem::MacOS(m) => m.vendor(),
+            OperatingSystem::Mobile(m) => m.vendor(),
             OperatingSystem::Windows(w) => w.vendor(),
         }
     }
 
     pub fn product(&self) -> String {
         match &self.0 {
+            OperatingSystem::BSD(b) => b.product(),
+            OperatingSystem::Hypervisor(h) => h.product(),
             OperatingSystem::Linux(l) => l.product(),
+            OperatingSystem::MacOS(m) => m.product(),
+            OperatingSystem::Mobile(m) => m.product(),
             OperatingSystem::Windows(w) => w.product(),
         }
     }
 
     pub fn release(&self) -> String {
         match &self.0 {
+            OperatingSystem::BSD(b) => b.release(),
+            OperatingSystem::Hypervisor(h) => h.release(),
             OperatingSystem::Linux(l) => l.release(),
+            OperatingSystem::MacOS(m) => m.release(),
+            OperatingSystem::Mobile(m) => m.release(),
             OperatingSystem::Windows(w) => w.release(),
         }
     }
     
+    /// Major release, e.g. 22 for Ubuntu 22.04.4. `None` if releases are not numbered that way,
+    /// e.g. Windows 11 24H2, Hyper-V Server 2019 or rolling releases.
+    pub fn release_major(&self) -> Option<u32> {
+        self.release_components().0
+    }
+
+    /// Minor release, e.g. 4 for Ubuntu 22.04.4.
+    pub fn release_minor(&self) -> Option<u32> {
+        self.release_components().1
+    }
+
+    /// Point release, e.g. 4 for Ubuntu 22.04.4, 10 for Debian 6.0.10 or 3 for VMware ESXi 7.0 U3.
+    pub fn release_point(&self) -> Option<u32> {
+        self.release_components().2
+    }
+
+    /// Release truncated to the release cycle endoflife.date tracks the product by,
+    /// e.g. "22.04" for Ubuntu 22.04.4, "12" for Debian 12.7 or "17" for iOS 17.6.1.
+    /// Windows Server is tracked by the year of the product, e.g. "2012 R2", other Windows releases are cycles of their own.
+    pub fn release_cycle(&self) -> String {
+        match &self.0 {
+            OperatingSystem::BSD(b) => b.0.release_cycle(),
+            OperatingSystem::Hypervisor(h) => h.0.release_cycle(),
+            OperatingSystem::Linux(l) => l.release_cycle(),
+            OperatingSystem::MacOS(m) => m.0.release_cycle(),
+            OperatingSystem::Mobile(m) => m.0.release_cycle(),
+            OperatingSystem::Windows(w) => w.0.release_cycle(),
+        }
+    }
+
+    fn release_components(&self) -> (Option<u32>, Option<u32>, Option<u32>) {
+        match &self.0 {
+            OperatingSystem::BSD(b) => b.0.release_components(),
+            OperatingSystem::Hypervisor(h) => h.0.release_components(),
+            OperatingSystem::Linux(l) => l.0.release_components(),
+            OperatingSystem::MacOS(m) => m.0.release_components(),
+            OperatingSystem::Mobile(m) => m.0.release_components(),
+            OperatingSystem::Windows(_) => (None, None, None),
+        }
+    }
+
     pub fn is_enterprise(&self) -> bool {
         match &self.0 {
+            OperatingSystem::BSD(b) => b.is_enterprise(),
+            OperatingSystem::Hypervisor(h) => h.is_enterprise(),
             OperatingSystem::Linux(l) => l.is_enterprise(),
+            OperatingSystem::MacOS(m) => m.is_enterprise(),
+            OperatingSystem::Mobile(m) => m.is_enterprise(),
             OperatingSystem::Windows(w) => w.is_enterprise(),
         }
     }
 
     pub fn is_lts(&self) -> bool {
         match &self.0 {
+            OperatingSystem::BSD(b) => b.is_lts(),
+            OperatingSystem::Hypervisor(h) => h.is_lts(),
             OperatingSystem::Linux(l) => l.is_lts(),
+            OperatingSystem::MacOS(m) => m.is_lts(),
+            OperatingSystem::Mobile(m) => m.is_lts(),
             OperatingSystem::Windows(w) => w.is_lts(),
         }
     }
+
+    /// Release a derivative is built from, e.g. Ubuntu 24.04 for Linux Mint 22, RHEL 9.4 for Oracle Linux 9.4
+    /// or Windows 10 1809 for Windows Server 2019.
+    pub fn based_on(&self) -> Option<OS> {
+        match &self.0 {
+            OperatingSystem::Linux(l) => l.based_on().map(|linux| OS(OperatingSystem::Linux(linux))),
+            OperatingSystem::Windows(w) => w.based_on().map(|windows| OS(OperatingSystem::Windows(windows))),
+            _ => None,
+        }
+    }
+
+    /// Releases vulnerability data can be shared with, e.g. RHEL 9.4 for Oracle Linux 9.4
+    /// or Windows 10 1809 for Windows Server 2019.
+    pub fn compatible_with(&self) -> Vec<OS> {
+        match &self.0 {
+            OperatingSystem::Linux(l) => l.compatible_with().into_iter().map(|linux| OS(OperatingSystem::Linux(linux))).collect(),
+            OperatingSystem::Windows(w) => w.compatible_with().into_iter().map(|windows| OS(OperatingSystem::Windows(windows))).collect(),
+            _ => vec![],
+        }
+    }
+
+    /// Whether the product is a rolling release without fixed releases, e.g. Arch Linux or Alpine Linux edge.
+    pub fn is_rolling(&self) -> bool {
+        match &self.0 {
+            OperatingSystem::Linux(l) => l.is_rolling(),
+            _ => false,
+        }
+    }
+
+    /// Whether the release is still in development, e.g. Debian testing or an Ubuntu development branch.
+    pub fn is_pre_release(&self) -> bool {
+        match &self.0 {
+            OperatingSystem::Linux(l) => l.is_pre_release(),
+            _ => false,
+        }
+    }
+
+    /// Whether the label named an edition, `false` if all editions of the release are listed instead.
+    pub fn is_edition_known(&self) -> bool {
+        match &self.0 {
+            OperatingSystem::Windows(w) => w.is_edition_known(),
+            _ => true,
+        }
+    }
+
+    /// Service channel beyond the default one, e.g. "ESM" for Ubuntu Pro or "EUS" for RHEL.
+    pub fn service_channel(&self) -> Option<String> {
+        match &self.0 {
+            OperatingSystem::Linux(l) => l.service_channel(),
+            _ => None,
+        }
+    }
+
+    /// Service channel on a date formatted as "YYYY-MM-DD", e.g. "ELTS" for Debian 10 on 2025-01-01.
+    /// Derived from the published support phases unless the label names a channel.
+    /// None for a date in any other format.
+    pub fn service_channel_at(&self, date: &str) -> Option<String> {
+        match &self.0 {
+            OperatingSystem::Linux(l) => l.service_channel_at(date),
+            _ => None,
+        }
+    }
+
+    /// Kernel flavour of Oracle Linux, "UEK" or "RHCK", if the label names it.
+    pub fn kernel_flavour(&self) -> Option<String> {
+        match &self.0 {
+            OperatingSystem::Linux(l) => l.kernel_flavour(),
+            _ => None,
+        }
+    }
+
+    /// Release of the Unbreakable Enterprise Kernel, e.g. "R7".
+    pub fn uek_release(&self) -> Option<String> {
+        match &self.0 {
+            OperatingSystem::Linux(l) => l.uek_release(),
+            _ => None,
+        }
+    }
+
+    /// Whether security updates can be installed without a reboot, e.g. Windows Server 2022 Datacenter: Azure Edition.
+    pub fn supports_hotpatch(&self) -> bool {
+        match &self.0 {
+            OperatingSystem::Windows(w) => w.supports_hotpatch(),
+            _ => false,
+        }
+    }
+
+    /// Installation option of Windows Server, e.g. "Server Core" or "Nano Server".
+    pub fn installation_option(&self) -> Option<String> {
+        match &self.0 {
+            OperatingSystem::Windows(w) => w.installation_option(),
+            _ => None,
+        }
+    }
     
     pub fn to_string(&self) -> Vec<String> {
         match &self.0 {
+            OperatingSystem::BSD(os) => {
+                os.to_string()
+            },
+            OperatingSystem::Hypervisor(os) => {
+                os.to_string()
+            },
             OperatingSystem::Linux(os) => {
                 os.to_string()
             },
+            OperatingSystem::MacOS(os) => {
+                os.to_string()
+            },
+            OperatingSystem::Mobile(os) => {
+                os.to_string()
+            },
             OperatingSystem::Windows(os) => {
                 os.to_string()
             },
@@ -78,6 +277,75 @@ impl OS {
     }
 }
 
+impl BSD {
+    pub fn parse(label: &str) -> Result<BSD, String> {
+        let bsd = bsd::BSD::try_from(label)?;
+
+        Ok(BSD(bsd))
+    }
+
+    pub fn vendor(&self) -> String {
+        self.0.vendor()
+    }
+
+    pub fn product(&self) -> String {
+        self.0.product()
+    }
+
+    pub fn release(&self) -> String {
+        self.0.release()
+    }
+    
+    pub fn is_enterprise(&self) -> bool {
+        self.0.is_enterprise()
+    }
+
+    pub fn is_lts(&self) -> bool {
+        self.0.is_lts()
+    }
+    
+    pub fn to_string(&self) -> Vec<String> {
+        self.0.to_string()
+    }
+}
+
+impl Hypervisor {
+    pub fn parse(label: &str) -> Result<Hypervisor, String> {
+        let hypervisor = hypervisor::Hypervisor::try_from(label)?;
+
+        Ok(Hypervisor(hypervisor))
+    }
+
+    pub fn vendor(&self) -> String {
+        self.0.vendor()
+    }
+
+    pub fn product(&self) -> String {
+        self.0.product()
+    }
+
+    pub fn release(&self) -> String {
+        self.0.release()
+    }
+
+    /// Build number the release was resolved from, e.g. "24022510" for VMware ESXi 8.0 U3.
+    pub fn build(&self) -> Option<String> {
+        self.0.build()
+    }
+
+    pub fn is_enterprise(&self) -> bool {
+        self.0.is_enterprise()
+    }
+
+    pub fn is_lts(&self) -> bool {
+        self.0.is_lts()
+    }
+
+    pub fn to_string(&self) -> Vec<String> {
+        self.0.to_string()
+    }
+}
+
 impl Linux {
     pub fn parse(label: &str) -> Result<Linux, String> {
         let linux = linux::Linux::try_from(label)?;
@@ -97,6 +365,98 @@ impl Linux {
         self.0.release()
     }
     
+    /// Major release, e.g. 22 for Ubuntu 22.04.4. `None` if releases are not numbered that way.
+    pub fn release_major(&self) -> Option<u32> {
+        self.0.release_components().0
+    }
+
+    /// Minor release, e.g. 4 for Ubuntu 22.04.4.
+    pub fn release_minor(&self) -> Option<u32> {
+        self.0.release_components().1
+    }
+
+    /// Point release, e.g. 4 for Ubuntu 22.04.4 or 10 for Debian 6.0.10.
+    pub fn release_point(&self) -> Option<u32> {
+        self.0.release_components().2
+    }
+
+    /// Release truncated to the release cycle endoflife.date tracks the product by.
+    pub fn release_cycle(&self) -> String {
+        self.0.release_cycle()
+    }
+
+    pub fn is_enterprise(&self) -> bool {
+        self.0.is_enterprise()
+    }
+
+    pub fn is_lts(&self) -> bool {
+        self.0.is_lts()
+    }
+
+    /// Release a derivative is built from, e.g. Debian 12 for Devuan 5.
+    pub fn based_on(&self) -> Option<Linux> {
+        self.0.based_on().and_then(|label| Linux::parse(&label).ok())
+    }
+
+    /// Releases binary compatible with this one, e.g. RHEL 9.4 for Oracle Linux 9.4.
+    pub fn compatible_with(&self) -> Vec<Linux> {
+        self.0.compatible_with().iter().filter_map(|label| Linux::parse(label).ok()).collect()
+    }
+
+    /// Whether the product is a rolling release without fixed releases, e.g. Arch Linux or Alpine Linux edge.
+    pub fn is_rolling(&self) -> bool {
+        self.0.is_rolling()
+    }
+
+    /// Whether the release is still in development, e.g. Debian testing or an Ubuntu development branch.
+    pub fn is_pre_release(&self) -> bool {
+        self.0.is_pre_release()
+    }
+
+    /// Kernel flavour of Oracle Linux, "UEK" or "RHCK", if the label names it.
+    pub fn kernel_flavour(&self) -> Option<String> {
+        self.0.kernel_flavour()
+    }
+
+    /// Release of the Unbreakable Enterprise Kernel, e.g. "R7".
+    pub fn uek_release(&self) -> Option<String> {
+        self.0.uek_release()
+    }
+
+    /// Service channel beyond the default one, e.g. "ESM" for Ubuntu Pro or "EUS" for RHEL.
+    pub fn service_channel(&self) -> Option<String> {
+        self.0.service_channel()
+    }
+
+    /// Service channel on a date formatted as "YYYY-MM-DD", e.g. "ELTS" for Debian 10 on 2025-01-01.
+    pub fn service_channel_at(&self, date: &str) -> Option<String> {
+        self.0.service_channel_at(date)
+    }
+    
+    pub fn to_string(&self) -> Vec<String> {
+        self.0.to_string()
+    }
+}
+
+impl MacOS {
+    pub fn parse(label: &str) -> Result<MacOS, String> {
+        let macos = macos::MacOS::try_from(label)?;
+
+        Ok(MacOS(macos))
+    }
+
+    pub fn vendor(&self) -> String {
+        self.0.vendor()
+    }
+
+    pub fn product(&self) -> String {
+        self.0.product()
+    }
+
+    pub fn release(&self) -> String {
+        self.0.release()
+    }
+    
     pub fn is_enterprise(&self) -> bool {
         self.0.is_enterprise()
     }
@@ -110,6 +470,48 @@ impl Linux {
     }
 }
 
+impl Mobile {
+    pub fn parse(label: &str) -> Result<Mobile, String> {
+        let mobile = mobile::Mobile::try_from(label)?;
+
+        Ok(Mobile(mobile))
+    }
+
+    pub fn vendor(&self) -> String {
+        self.0.vendor()
+    }
+
+    pub fn product(&self) -> String {
+        self.0.product()
+    }
+
+    pub fn release(&self) -> String {
+        self.0.release()
+    }
+
+    /// API level of an Android release, e.g. 34 for Android 14.
+    pub fn api_level(&self) -> Option<u32> {
+        self.0.api_level()
+    }
+
+    /// Build number of an iOS or iPadOS release, e.g. "21G93" for iOS 17.6.1.
+    pub fn build(&self) -> Option<String> {
+        self.0.build()
+    }
+
+    pub fn is_enterprise(&self) -> bool {
+        self.0.is_enterprise()
+    }
+
+    pub fn is_lts(&self) -> bool {
+        self.0.is_lts()
+    }
+
+    pub fn to_string(&self) -> Vec<String> {
+        self.0.to_string()
+    }
+}
+
 impl Windows {
     pub fn parse(label: &str) -> Result<Windows, String> {
         let windows = windows::Windows::try_from(label)?;
@@ -128,6 +530,11 @@ impl Windows {
     pub fn release(&self) -> String {
         self.0.release()
     }
+
+    /// Release cycle endoflife.date tracks the product by, e.g. "2022" for Windows Server 2022 or "24H2" for Windows 11 24H2.
+    pub fn release_cycle(&self) -> String {
+        self.0.release_cycle()
+    }
     
     pub fn is_enterprise(&self) -> bool {
         self.0.is_enterprise()
@@ -137,6 +544,30 @@ impl Windows {
         self.0.is_lts()
     }
     
+    /// Client release a Windows Server release was branched from, e.g. Windows 10 1809 for Windows Server 2019.
+    pub fn based_on(&self) -> Option<Windows> {
+        self.0.based_on().and_then(|label| Windows::parse(&label).ok())
+    }
+
+    /// Releases sharing their code base with this one, e.g. Windows 10 1809 for Windows Server 2019.
+    pub fn compatible_with(&self) -> Vec<Windows> {
+        self.0.compatible_with().iter().filter_map(|label| Windows::parse(label).ok()).collect()
+    }
+
+    /// Whether the label named an edition, `false` if all editions of Windows 10 or 11 are listed instead.
+    pub fn is_edition_known(&self) -> bool {
+        self.0.is_edition_known()
+    }
+
+    /// Whether security updates can be installed without a reboot, e.g. Windows Server 2022 Datacenter: Azure Edition.
+    pub fn supports_hotpatch(&self) -> bool {
+        self.0.supports_hotpatch()
+    }
+
+    pub fn installation_option(&self) -> Option<String> {
+        self.0.installation_option()
+    }
+
     pub fn to_string(&self) -> Vec<String> {
         self.0.to_string()
     }
@@ -146,10 +577,18 @@ impl TryFrom<&str> for OperatingSystem {
     type Error = String;
 
     fn try_from(value: &str) -> Result<Self, Self::Error> {
-        if let Ok(windows) = windows::Windows::try_from(value) {
+        if let Ok(hypervisor) = hypervisor::Hypervisor::try_from(value) {
+            Ok(OperatingSystem::Hypervisor(Hypervisor(hypervisor)))
+        } else if let Ok(mobile) = mobile::Mobile::try_from(value) {
+            Ok(OperatingSystem::Mobile(Mobile(mobile)))
+        } else if let Ok(windows) = windows::Windows::try_from(value) {
             Ok(OperatingSystem::Windows(Windows(windows)))
         } else if let Ok(linux) = linux::Linux::try_from(value) {
             Ok(OperatingSystem::Linux(Linux(linux)))
+        } else if let Ok(macos) = macos::MacOS::try_from(value) {
+            Ok(OperatingSystem::MacOS(MacOS(macos)))
+        } else if let Ok(bsd) = bsd::BSD::try_from(value) {
+            Ok(OperatingSystem::BSD(BSD(bsd)))
         } else {
             Err(format!("{} \"{}\"", ERR_UNKNOWN_OS, value))
         }
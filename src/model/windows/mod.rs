@@ -1,5 +1,8 @@
-mod windows_7;
-mod windows_8;
+pub(crate) mod windows_7;
+pub(crate) use windows_7::Windows7;
+
+pub(crate) mod windows_8;
+pub(crate) use windows_8::Windows8;
 
 pub(crate) mod windows_10;
 pub(crate) use windows_10::Windows10;
@@ -7,40 +10,51 @@ pub(crate) use windows_10::Windows10;
 pub(crate) mod windows_11;
 pub(crate) use windows_11::Windows11;
 
-mod windows_vista;
-mod windows_xp;
+pub(crate) mod windows_embedded_8;
+pub(crate) use windows_embedded_8::WindowsEmbedded8;
+
+pub(crate) mod windows_embedded_compact;
+pub(crate) use windows_embedded_compact::WindowsEmbeddedCompact;
+
+pub(crate) mod windows_embedded_posready;
+pub(crate) use windows_embedded_posready::WindowsEmbeddedPOSReady;
+
+pub(crate) mod windows_embedded_standard_7;
+pub(crate) use windows_embedded_standard_7::WindowsEmbeddedStandard7;
+
+pub(crate) mod windows_vista;
+pub(crate) use windows_vista::WindowsVista;
+
+pub(crate) mod windows_xp;
+pub(crate) use windows_xp::WindowsXP;
 
-mod windows_server_1709ff;
+pub(crate) mod windows_server_1709ff;
+pub(crate) use windows_server_1709ff::WindowsServer1709ff;
 
 mod windows_2000;
 
-mod windows_server_2003;
+pub(crate) mod windows_server_2003;
+pub(crate) use windows_server_2003::WindowsServer2003;
 
-mod windows_server_2008;
+pub(crate) mod windows_server_2008;
+pub(crate) use windows_server_2008::WindowsServer2008;
 
-mod windows_server_2008_r2;
+pub(crate) mod windows_server_2008_r2;
+pub(crate) use windows_server_2008_r2::WindowsServer2008R2;
 
-mod windows_server_2012;
+pub(crate) mod windows_server_2012;
+pub(crate) use windows_server_2012::WindowsServer2012;
 
-mod windows_server_2012_r2;
+pub(crate) mod windows_server_2012_r2;
+pub(crate) use windows_server_2012_r2::WindowsServer2012R2;
 
-mod windows_server_2016;
+pub(crate) mod windows_server_2016;
+pub(crate) use windows_server_2016::WindowsServer2016;
 
 pub(crate) mod windows_server_2019ff;
 pub(crate) use windows_server_2019ff::WindowsServer2019ff;
 
-use windows_7::Windows7;
-use windows_8::Windows8;
 use windows_2000::Windows2000;
-use windows_server_1709ff::WindowsServer1709ff;
-use windows_server_2003::WindowsServer2003;
-use windows_server_2008::WindowsServer2008;
-use windows_server_2008_r2::WindowsServer2008R2;
-use windows_server_2012::WindowsServer2012;
-use windows_server_2012_r2::WindowsServer2012R2;
-use windows_server_2016::WindowsServer2016;
-use windows_vista::WindowsVista;
-use windows_xp::WindowsXP;
 
 #[derive(Debug)]
 pub(crate) enum Windows {
@@ -49,6 +63,10 @@ pub(crate) enum Windows {
     Windows10(Windows10),
     Windows11(Windows11),
     Windows2000(Windows2000),
+    WindowsEmbedded8(WindowsEmbedded8),
+    WindowsEmbeddedCompact(WindowsEmbeddedCompact),
+    WindowsEmbeddedPOSReady(WindowsEmbeddedPOSReady),
+    WindowsEmbeddedStandard7(WindowsEmbeddedStandard7),
     WindowsServer1709ff(WindowsServer1709ff),
     WindowsServer2003(WindowsServer2003),
     WindowsServer2008(WindowsServer2008),
@@ -69,6 +87,10 @@ impl Windows {
             Windows::Windows10(windows) => windows.to_string(),
             Windows::Windows11(windows) => windows.to_string(),
             Windows::Windows2000(windows) => windows.to_string(),
+            Windows::WindowsEmbedded8(windows) => windows.to_string(),
+            Windows::WindowsEmbeddedCompact(windows) => windows.to_string(),
+            Windows::WindowsEmbeddedPOSReady(windows) => windows.to_string(),
+            Windows::WindowsEmbeddedStandard7(windows) => windows.to_string(),
             Windows::WindowsServer1709ff(windows) => windows.to_string(),
             Windows::WindowsServer2003(windows) => windows.to_string(),
             Windows::WindowsServer2008(windows) => windows.to_string(),
@@ -89,6 +111,10 @@ impl Windows {
             Windows::Windows10(w) => String::from(w.vendor()),
             Windows::Windows11(w) => String::from(w.vendor()),
             Windows::Windows2000(w) => String::from(w.vendor()),
+            Windows::WindowsEmbedded8(w) => String::from(w.vendor()),
+            Windows::WindowsEmbeddedCompact(w) => String::from(w.vendor()),
+            Windows::WindowsEmbeddedPOSReady(w) => String::from(w.vendor()),
+            Windows::WindowsEmbeddedStandard7(w) => String::from(w.vendor()),
             Windows::WindowsServer1709ff(w) => String::from(w.vendor()),
             Windows::WindowsServer2003(w) => String::from(w.vendor()),
             Windows::WindowsServer2008(w) => String::from(w.vendor()),
@@ -109,6 +135,10 @@ impl Windows {
             Windows::Windows10(w) => String::from(w.product()),
             Windows::Windows11(w) => String::from(w.product()),
             Windows::Windows2000(w) => String::from(w.product()),
+            Windows::WindowsEmbedded8(w) => String::from(w.product()),
+            Windows::WindowsEmbeddedCompact(w) => String::from(w.product()),
+            Windows::WindowsEmbeddedPOSReady(w) => String::from(w.product()),
+            Windows::WindowsEmbeddedStandard7(w) => String::from(w.product()),
             Windows::WindowsServer1709ff(w) => String::from(w.product()),
             Windows::WindowsServer2003(w) => String::from(w.product()),
             Windows::WindowsServer2008(w) => String::from(w.product()),
@@ -124,24 +154,43 @@ impl Windows {
 
     pub fn release(&self) -> String {
         match self {
-            Windows::Windows7(w) => String::from(w.release()),
-            Windows::Windows8(w) => String::from(w.release()),
-            Windows::Windows10(w) => String::from(w.release()),
-            Windows::Windows11(w) => String::from(w.release()),
-            Windows::Windows2000(w) => String::from(w.release()),
-            Windows::WindowsServer1709ff(w) => String::from(w.release()),
-            Windows::WindowsServer2003(w) => String::from(w.release()),
-            Windows::WindowsServer2008(w) => String::from(w.release()),
-            Windows::WindowsServer2008R2(w) => String::from(w.release()),
-            Windows::WindowsServer2012(w) => String::from(w.release()),
-            Windows::WindowsServer2012R2(w) => String::from(w.release()),
-            Windows::WindowsServer2016(w) => String::from(w.release()),
-            Windows::WindowsServer2019ff(w) => String::from(w.release()),
-            Windows::WindowsVista(w) => String::from(w.release()),
-            Windows::WindowsXP(w) => String::from(w.release()),
+            Windows::Windows7(w) => w.release(),
+            Windows::Windows8(w) => w.release(),
+            Windows::Windows10(w) => w.release(),
+            Windows::Windows11(w) => w.release(),
+            Windows::Windows2000(w) => w.release(),
+            Windows::WindowsEmbedded8(w) => w.release(),
+            Windows::WindowsEmbeddedCompact(w) => w.release(),
+            Windows::WindowsEmbeddedPOSReady(w) => w.release(),
+            Windows::WindowsEmbeddedStandard7(w) => w.release(),
+            Windows::WindowsServer1709ff(w) => w.release(),
+            Windows::WindowsServer2003(w) => w.release(),
+            Windows::WindowsServer2008(w) => w.release(),
+            Windows::WindowsServer2008R2(w) => w.release(),
+            Windows::WindowsServer2012(w) => w.release(),
+            Windows::WindowsServer2012R2(w) => w.release(),
+            Windows::WindowsServer2016(w) => w.release(),
+            Windows::WindowsServer2019ff(w) => w.release(),
+            Windows::WindowsVista(w) => w.release(),
+            Windows::WindowsXP(w) => w.release(),
         }
     }
     
+    // Releases named after a year are tracked by it, e.g. 2012 R2 for Windows Server 2012 R2 SP1,
+    // the others by their release, e.g. 1809 for the semi-annual channel or 24H2 for Windows 11
+    pub fn release_cycle(&self) -> String {
+        match self {
+            Windows::WindowsServer2003(_)
+            | Windows::WindowsServer2008(_)
+            | Windows::WindowsServer2008R2(_)
+            | Windows::WindowsServer2012(_)
+            | Windows::WindowsServer2012R2(_)
+            | Windows::WindowsServer2016(_)
+            | Windows::WindowsServer2019ff(_) => self.product().trim_start_matches("Windows Server ").to_string(),
+            _ => self.release(),
+        }
+    }
+
     pub fn is_enterprise(&self) -> bool {
         match self {
             Windows::Windows7(w) => w.is_enterprise(),
@@ -149,6 +198,10 @@ impl Windows {
             Windows::Windows10(w) => w.is_enterprise(),
             Windows::Windows11(w) => w.is_enterprise(),
             Windows::Windows2000(w) => w.is_enterprise(),
+            Windows::WindowsEmbedded8(w) => w.is_enterprise(),
+            Windows::WindowsEmbeddedCompact(w) => w.is_enterprise(),
+            Windows::WindowsEmbeddedPOSReady(w) => w.is_enterprise(),
+            Windows::WindowsEmbeddedStandard7(w) => w.is_enterprise(),
             Windows::WindowsServer1709ff(w) => w.is_enterprise(),
             Windows::WindowsServer2003(w) => w.is_enterprise(),
             Windows::WindowsServer2008(w) => w.is_enterprise(),
@@ -169,6 +222,10 @@ impl Windows {
             Windows::Windows10(w) => w.is_lts(),
             Windows::Windows11(w) => w.is_lts(),
             Windows::Windows2000(w) => w.is_lts(),
+            Windows::WindowsEmbedded8(w) => w.is_lts(),
+            Windows::WindowsEmbeddedCompact(w) => w.is_lts(),
+            Windows::WindowsEmbeddedPOSReady(w) => w.is_lts(),
+            Windows::WindowsEmbeddedStandard7(w) => w.is_lts(),
             Windows::WindowsServer1709ff(w) => w.is_lts(),
             Windows::WindowsServer2003(w) => w.is_lts(),
             Windows::WindowsServer2008(w) => w.is_lts(),
@@ -181,13 +238,68 @@ impl Windows {
             Windows::WindowsXP(w) => w.is_lts(),
         }
     }
+
+    pub fn based_on(&self) -> Option<String> {
+        match self {
+            Windows::WindowsServer2016(w) => w.based_on(),
+            Windows::WindowsServer2019ff(w) => w.based_on(),
+            _ => None,
+        }
+    }
+
+    pub fn compatible_with(&self) -> Vec<String> {
+        match self {
+            Windows::Windows10(w) => w.compatible_with(),
+            Windows::Windows11(w) => w.compatible_with(),
+            Windows::WindowsServer2016(w) => w.compatible_with(),
+            Windows::WindowsServer2019ff(w) => w.compatible_with(),
+            _ => vec![],
+        }
+    }
+
+    pub fn supports_hotpatch(&self) -> bool {
+        match self {
+            Windows::WindowsServer2019ff(w) => w.supports_hotpatch(),
+            _ => false,
+        }
+    }
+
+    pub fn is_edition_known(&self) -> bool {
+        match self {
+            Windows::Windows10(w) => w.is_edition_known(),
+            Windows::Windows11(w) => w.is_edition_known(),
+            _ => true,
+        }
+    }
+
+    pub fn installation_option(&self) -> Option<String> {
+        let option = match self {
+            Windows::WindowsServer1709ff(w) => w.installation(),
+            Windows::WindowsServer2016(w) => w.installation(),
+            Windows::WindowsServer2019ff(w) => w.installation(),
+            _ => None,
+        };
+
+        option.map(|option| option.to_string())
+    }
 }
 
 impl TryFrom<&str> for Windows {
     type Error = String;
 
     fn try_from(value: &str) -> Result<Self, Self::Error> {
-        if let Ok(windows) = Windows11::try_from(value) {
+        // The standalone Hyper-V Server is modelled by the hypervisor family
+        if crate::util::contains_any_word(value, &["Hyper-V Server"]) {
+            Err(format!("Not a windows: {}", value))
+        } else if let Ok(windows) = WindowsEmbeddedStandard7::try_from(value) {
+            Ok(Windows::WindowsEmbeddedStandard7(windows))
+        } else if let Ok(windows) = WindowsEmbedded8::try_from(value) {
+            Ok(Windows::WindowsEmbedded8(windows))
+        } else if let Ok(windows) = WindowsEmbeddedPOSReady::try_from(value) {
+            Ok(Windows::WindowsEmbeddedPOSReady(windows))
+        } else if let Ok(windows) = WindowsEmbeddedCompact::try_from(value) {
+            Ok(Windows::WindowsEmbeddedCompact(windows))
+        } else if let Ok(windows) = Windows11::try_from(value) {
             Ok(Windows::Windows11(windows))
         } else if let Ok(windows) = Windows10::try_from(value) {
             Ok(Windows::Windows10(windows))
@@ -222,3 +334,55 @@ impl TryFrom<&str> for Windows {
         }
     }
 }
+
+/// Installation option of Windows Server 2016 and later.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub(crate) enum InstallationOption {
+    #[default]
+    DesktopExperience,
+    ServerCore,
+    NanoServer,
+}
+
+impl InstallationOption {
+    pub(crate) fn is_default(&self) -> bool {
+        match self {
+            InstallationOption::DesktopExperience => true,
+            InstallationOption::ServerCore => false,
+            InstallationOption::NanoServer => false,
+        }
+    }
+
+    // endoflife.date publishes Server Core and Nano Server as products of their own,
+    // e.g. windows-server-core-1709 or windows-nano-server-2016. Returns the option
+    // and the label rewritten to the corresponding windows-server-* label.
+    pub(crate) fn split_label(value: &str) -> (Option<InstallationOption>, String) {
+        if let Some(rest) = value.strip_prefix("windows-server-core-") {
+            (Some(InstallationOption::ServerCore), format!("windows-server-{}", rest))
+        } else if let Some(rest) = value.strip_prefix("windows-nano-server-") {
+            (Some(InstallationOption::NanoServer), format!("windows-server-{}", rest))
+        } else {
+            (None, value.to_string())
+        }
+    }
+
+    // Suffix of the canonical name, the default installation option is not rendered
+    pub(crate) fn suffix(value: &Option<InstallationOption>) -> String {
+        match value {
+            Some(option) if !option.is_default() => format!(" {}", option),
+            _ => String::new(),
+        }
+    }
+}
+
+impl std::fmt::Display for InstallationOption {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let out = match self {
+            InstallationOption::DesktopExperience => "Desktop Experience",
+            InstallationOption::ServerCore => "Server Core",
+            InstallationOption::NanoServer => "Nano Server",
+        };
+
+        write!(f, "{}", out)
+    }
+}
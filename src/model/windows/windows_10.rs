@@ -16,6 +16,7 @@ pub(crate) struct Windows10 {
     release: Release,
     editions: Option<Editions>,
     service_channel: ServiceChannel,
+    edition_known: bool,
 }
 
 impl Windows10 {
@@ -26,6 +27,7 @@ impl Windows10 {
             release,
             editions: None,
             service_channel,
+            edition_known: true,
         }
     }
 
@@ -41,12 +43,24 @@ impl Windows10 {
         self.release.to_string()
     }
 
+    // Windows Server releases sharing the code base of this release
+    pub(super) fn compatible_with(&self) -> Vec<String> {
+        match self.release.0.as_str() {
+            "1607" => vec!["windows-server-2016".to_string()],
+            "1809" => vec!["windows-server-2019".to_string()],
+            _ => vec![],
+        }
+    }
+
     pub(super) fn is_enterprise(&self) -> bool {
         match &self.editions {
             None => false,
             Some(editions) => {
                 editions.contains(Edition::Enterprise) ||
-                    editions.contains(Edition::EnterpriseIoT)
+                    editions.contains(Edition::EnterpriseIoT) ||
+                    editions.contains(Edition::EnterpriseKN) ||
+                    editions.contains(Edition::EnterpriseMultiSession) ||
+                    editions.contains(Edition::EnterpriseN)
             }
         }
     }
@@ -60,44 +74,51 @@ impl Windows10 {
         self
     }
 
-    pub(crate) fn editions(mut self, editions: Editions) -> Windows10 {
+    pub(crate) fn editions(mut self, mut editions: Editions) -> Windows10 {
+        editions.0.retain(|edition| edition.supports(&self.service_channel));
         self.editions = Some(editions);
         self
     }
 
+    // Whether the label named an edition, otherwise all editions of the release are listed
+    pub(crate) fn edition_known(mut self, edition_known: bool) -> Windows10 {
+        self.edition_known = edition_known;
+        self
+    }
+
+    pub(super) fn is_edition_known(&self) -> bool {
+        self.edition_known
+    }
+
+    // Long-term servicing releases are named after their year, e.g. "2016 LTSB" or "LTSC 2019"
+    fn release_name(&self) -> String {
+        match (&self.service_channel, self.release.lts_year()) {
+            (ServiceChannel::LTSB, Some(year)) => format!("{year} LTSB"),
+            (ServiceChannel::LTSC, Some(year)) => format!("LTSC {year}"),
+            (service_channel, _) if service_channel.is_default() => self.release.to_string(),
+            (service_channel, _) => format!("{} {service_channel}", self.release),
+        }
+    }
+
     pub(super) fn to_string(&self) -> Vec<String> {
         if let Some(editions) = &self.editions {
             let out = editions
                 .0
                 .iter()
                 .map(|edition| {
-                    if self.service_channel.is_default() {
-                        format!(
-                            "{} {} {edition} {}",
-                            self.vendor, self.product, self.release
-                        )
-                    } else {
-                        format!(
-                            "{} {} {edition} {} {}",
-                            self.vendor, self.product, self.release, self.service_channel
-                        )
-                    }
+                    format!(
+                        "{} {} {edition} {}",
+                        self.vendor, self.product, self.release_name()
+                    )
                 })
                 .collect();
 
             out
         } else {
-            let out = if self.service_channel.is_default() {
-                format!(
-                    "{} {} {}",
-                    self.vendor, self.product, self.release
-                )
-            } else {
-                format!(
-                    "{} {} {} {}",
-                    self.vendor, self.product, self.release, self.service_channel
-                )
-            };
+            let out = format!(
+                "{} {} {}",
+                self.vendor, self.product, self.release_name()
+            );
 
             let out = vec![out];
             out
@@ -118,16 +139,42 @@ impl TryFrom<&str> for Windows10 {
     }
 }
 
+// Long-term servicing releases and the year they are named after
+const LTS_YEARS: [(&str, &str); 4] = [
+    ("1507", "2015"),
+    ("1607", "2016"),
+    ("1809", "2019"),
+    ("21H2", "2021"),
+];
+
 #[derive(Debug)]
 pub(crate) struct Release(String);
 
 impl Release {
+    pub(crate) fn from_lts_year(year: &str) -> Option<Release> {
+        LTS_YEARS
+            .iter()
+            .find(|(_, lts_year)| *lts_year == year)
+            .map(|(release, _)| Release::from(*release))
+    }
+
+    fn lts_year(&self) -> Option<&str> {
+        LTS_YEARS
+            .iter()
+            .find(|(release, _)| *release == self.0)
+            .map(|(_, year)| *year)
+    }
+
     fn is_semi_annual(&self) -> bool {
         self.0.ends_with("H1")
     }
 
-    fn up_to_1607(&self) -> bool {
-        self.0 <= String::from("1607")
+    pub(crate) fn up_to_1607(&self) -> bool {
+        self.0.as_str() <= "1607"
+    }
+
+    pub(crate) fn up_to_1809(&self) -> bool {
+        self.0.as_str() <= "1809"
     }
 }
 
@@ -185,24 +232,70 @@ impl Editions {
 #[derive(PartialEq, Debug)]
 pub(crate) enum Edition {
     Education,
+    EducationInSMode,
+    EducationKN,
+    EducationN,
     Enterprise,
     EnterpriseIoT,
+    EnterpriseKN,
+    EnterpriseMultiSession,
+    EnterpriseN,
+    Holographic,
     Home,
+    HomeInSMode,
+    HomeKN,
+    HomeN,
+    HomeSingleLanguage,
     Pro,
     ProEducation,
+    ProEducationN,
     ProForWorkstations,
+    ProForWorkstationsN,
+    ProInSMode,
+    ProKN,
+    ProN,
+    S,
+    Team,
+}
+
+impl Edition {
+    // Windows 10 Home followed the Current Branch only
+    pub(crate) fn supports(&self, service_channel: &ServiceChannel) -> bool {
+        match self {
+            Edition::Home | Edition::HomeInSMode | Edition::HomeKN | Edition::HomeN => *service_channel != ServiceChannel::CBB,
+            _ => true,
+        }
+    }
 }
 
 impl std::fmt::Display for Edition {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let out = match self {
             Edition::Education => "Education",
+            Edition::EducationInSMode => "Education in S mode",
+            Edition::EducationKN => "Education KN",
+            Edition::EducationN => "Education N",
             Edition::Enterprise => "Enterprise",
             Edition::EnterpriseIoT => "Enterprise IoT",
+            Edition::EnterpriseKN => "Enterprise KN",
+            Edition::EnterpriseMultiSession => "Enterprise multi-session",
+            Edition::EnterpriseN => "Enterprise N",
+            Edition::Holographic => "Holographic",
             Edition::Home => "Home",
+            Edition::HomeInSMode => "Home in S mode",
+            Edition::HomeKN => "Home KN",
+            Edition::HomeN => "Home N",
+            Edition::HomeSingleLanguage => "Home Single Language",
             Edition::Pro => "Pro",
             Edition::ProEducation => "Pro Education",
+            Edition::ProEducationN => "Pro Education N",
             Edition::ProForWorkstations => "Pro for Workstations",
+            Edition::ProForWorkstationsN => "Pro N for Workstations",
+            Edition::ProInSMode => "Pro in S mode",
+            Edition::ProKN => "Pro KN",
+            Edition::ProN => "Pro N",
+            Edition::S => "S",
+            Edition::Team => "Team",
         };
 
         write!(f, "{}", out.to_string())
@@ -218,32 +311,40 @@ The primary difference between Current Branch (CB) and Current Branch for Busine
  */
 #[derive(PartialEq, Debug)]
 pub(crate) enum ServiceChannel {
-    // Current Branch
-    // CB: only used by Windows 10 1507, 1511
-    // Current Branch for Business
-    // CBB: only used by Windows 10 1507, 1511
+    // Current Branch, Windows 10 1507 to 1607
+    CB,
+    // Current Branch for Business, Windows 10 1507 to 1607
+    CBB,
     GAC,
     LTSB,
     LTSC,
     SAC,
+    // Semi-Annual Channel (Targeted), Windows 10 1703 to 1809
+    SACT,
 }
 
 impl ServiceChannel {
     fn is_default(&self) -> bool {
         match self {
+            ServiceChannel::CB => false,
+            ServiceChannel::CBB => false,
             ServiceChannel::GAC => true,
             ServiceChannel::LTSB => false,
             ServiceChannel::LTSC => false,
             ServiceChannel::SAC => true,
+            ServiceChannel::SACT => false,
         }
     }
 
-    fn is_lts(&self) -> bool {
+    pub(crate) fn is_lts(&self) -> bool {
         match self {
+            ServiceChannel::CB => false,
+            ServiceChannel::CBB => false,
             ServiceChannel::GAC => false,
             ServiceChannel::LTSB => true,
             ServiceChannel::LTSC => true,
             ServiceChannel::SAC => false,
+            ServiceChannel::SACT => false,
         }
     }
 }
@@ -257,10 +358,13 @@ impl Default for ServiceChannel {
 impl std::fmt::Display for ServiceChannel {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let out = match self {
+            ServiceChannel::CB => "CB",
+            ServiceChannel::CBB => "CBB",
             ServiceChannel::GAC => "GAC",
             ServiceChannel::LTSB => "LTSB",
             ServiceChannel::LTSC => "LTSC",
             ServiceChannel::SAC => "SAC",
+            ServiceChannel::SACT => "SAC-T",
         };
 
         write!(f, "{}", out)
@@ -280,7 +384,9 @@ impl TryFrom<&Release> for ServiceChannel {
     type Error = String;
 
     fn try_from(value: &Release) -> Result<Self, Self::Error> {
-        if value.is_semi_annual() {
+        if value.up_to_1607() {
+            Ok(ServiceChannel::CB)
+        } else if value.up_to_1809() || value.is_semi_annual() {
             Ok(ServiceChannel::SAC)
         } else {
             // todo: improve error handling
@@ -342,7 +448,7 @@ mod tests {
         assert_eq!(label.release.to_string(), "1809".to_string());
 
         assert_eq!(label.editions.unwrap().len(), Editions::all_e().len());
-        assert_eq!(label.service_channel, ServiceChannel::GAC);
+        assert_eq!(label.service_channel, ServiceChannel::SAC);
     }
 
     #[test]
@@ -354,7 +460,7 @@ mod tests {
         assert_eq!(label.release.to_string(), "1809".to_string());
 
         assert_eq!(label.editions.unwrap().len(), Editions::all_w().len());
-        assert_eq!(label.service_channel, ServiceChannel::GAC);
+        assert_eq!(label.service_channel, ServiceChannel::SAC);
     }
 
     #[test]
@@ -390,7 +496,7 @@ mod tests {
         assert_eq!(label.release.to_string(), "1507".to_string());
 
         assert_eq!(label.editions.unwrap().len(), Editions::all().len());
-        assert_eq!(label.service_channel, ServiceChannel::GAC);
+        assert_eq!(label.service_channel, ServiceChannel::CB);
     }
 
     #[test]
@@ -426,7 +532,7 @@ mod tests {
         assert_eq!(label.release.to_string(), "1809".to_string());
 
         assert!(label.editions.unwrap().contains(Edition::Pro));
-        assert_eq!(label.service_channel, ServiceChannel::GAC);
+        assert_eq!(label.service_channel, ServiceChannel::SAC);
     }
 
     #[test]
@@ -438,6 +544,113 @@ mod tests {
         assert_eq!(label.release.to_string(), "21H1".to_string());
 
         assert!(label.editions.unwrap().contains(Edition::Enterprise));
-        assert_eq!(label.service_channel, ServiceChannel::GAC);
+        assert_eq!(label.service_channel, ServiceChannel::SAC);
+    }
+
+    #[test]
+    fn test_from_string_arbitrary_wo_edition() {
+        let label = Windows10::try_from("Windows 10 22H2").unwrap();
+
+        assert_eq!(label.release.to_string(), "22H2".to_string());
+        assert_eq!(label.editions.as_ref().unwrap().len(), Editions::all().len());
+        assert!(!label.is_edition_known());
+    }
+
+    #[test]
+    fn test_from_string_arbitrary_pro_for_workstations() {
+        let label = Windows10::try_from("Windows 10 Pro for Workstations 22H2").unwrap();
+
+        assert_eq!(label.editions.as_ref().unwrap().len(), 1);
+        assert!(label.editions.as_ref().unwrap().contains(Edition::ProForWorkstations));
+        assert_eq!(label.to_string(), vec!["Microsoft Windows 10 Pro for Workstations 22H2".to_string()]);
+    }
+
+    #[test]
+    fn test_from_string_arbitrary_n() {
+        let label = Windows10::try_from("Windows 10 Enterprise N (Build 19045)").unwrap();
+
+        assert!(label.editions.as_ref().unwrap().contains(Edition::EnterpriseN));
+        assert!(label.is_enterprise());
+    }
+
+    #[test]
+    fn test_from_string_arbitrary_team() {
+        let label = Windows10::try_from("Windows 10 Team 20H2").unwrap();
+
+        assert!(label.editions.as_ref().unwrap().contains(Edition::Team));
+        assert!(!label.is_enterprise());
+    }
+
+    #[test]
+    fn test_from_string_arbitrary_ltsc_year() {
+        let label = Windows10::try_from("Windows 10 Enterprise LTSC 2019").unwrap();
+
+        assert_eq!(label.release.to_string(), "1809".to_string());
+        assert_eq!(label.service_channel, ServiceChannel::LTSC);
+        assert_eq!(label.to_string(), vec!["Microsoft Windows 10 Enterprise LTSC 2019".to_string()]);
+    }
+
+    #[test]
+    fn test_from_string_arbitrary_ltsb_year() {
+        let label = Windows10::try_from("Windows 10 Enterprise 2016 LTSB").unwrap();
+
+        assert_eq!(label.release.to_string(), "1607".to_string());
+        assert_eq!(label.service_channel, ServiceChannel::LTSB);
+        assert_eq!(label.to_string(), vec!["Microsoft Windows 10 Enterprise 2016 LTSB".to_string()]);
+    }
+
+    #[test]
+    fn test_from_string_cbb() {
+        let label = Windows10::try_from("windows-10-1511-e").unwrap();
+
+        assert_eq!(label.service_channel, ServiceChannel::CBB);
+        assert!(label.to_string().contains(&"Microsoft Windows 10 Enterprise 1511 CBB".to_string()));
+    }
+
+    #[test]
+    fn test_from_string_service_channel_suffix() {
+        let label = Windows10::try_from("windows-10-1511-cbb").unwrap();
+
+        assert_eq!(label.release.to_string(), "1511".to_string());
+        assert_eq!(label.service_channel, ServiceChannel::CBB);
+        assert!(!label.editions.unwrap().contains(Edition::Home));
+
+        let label = Windows10::try_from("windows-10-1709-sac-t").unwrap();
+
+        assert_eq!(label.service_channel, ServiceChannel::SACT);
+        assert!(label.to_string().contains(&"Microsoft Windows 10 Pro 1709 SAC-T".to_string()));
+
+        assert!(Windows10::try_from("windows-10-1809-cb").is_err());
+        assert!(Windows10::try_from("Windows 10 Home 1511 CBB").is_err());
+
+        let label = Windows10::try_from("Windows 10 1511 Current Branch for Business").unwrap();
+
+        assert!(!label.editions.unwrap().contains(Edition::Home));
+        assert!(Windows10::try_from("windows-10-21h2-sac-t").is_err());
+        assert!(Windows10::try_from("windows-10-21h2-sac").is_err());
+    }
+
+    #[test]
+    fn test_from_string_arbitrary_cb() {
+        let label = Windows10::try_from("Windows 10 Home 1511 (Build 10586)").unwrap();
+
+        assert_eq!(label.service_channel, ServiceChannel::CB);
+        assert_eq!(label.to_string(), vec!["Microsoft Windows 10 Home 1511 CB".to_string()]);
+    }
+
+    #[test]
+    fn test_from_string_arbitrary_sac_t() {
+        let label = Windows10::try_from("Windows 10 Pro 1709 Semi-Annual Channel (Targeted)").unwrap();
+
+        assert_eq!(label.service_channel, ServiceChannel::SACT);
+        assert_eq!(label.to_string(), vec!["Microsoft Windows 10 Pro 1709 SAC-T".to_string()]);
+    }
+
+    #[test]
+    fn test_from_string_arbitrary_pro_n_for_workstations() {
+        let label = Windows10::try_from("Windows 10 Pro N for Workstations 22H2").unwrap();
+
+        assert!(label.editions.as_ref().unwrap().contains(Edition::ProForWorkstationsN));
+        assert_eq!(label.to_string(), vec!["Microsoft Windows 10 Pro N for Workstations 22H2".to_string()]);
     }
 }
@@ -8,6 +8,7 @@ pub(crate) struct Windows11 {
     release: Release,
     editions: Editions,
     service_channel: ServiceChannel,
+    edition_known: bool,
 }
 
 impl Windows11 {
@@ -18,6 +19,7 @@ impl Windows11 {
             release,
             editions: Editions(vec![]),
             service_channel,
+            edition_known: true,
         }
     }
     
@@ -33,9 +35,19 @@ impl Windows11 {
         self.release.to_string()
     }
     
+    // Windows Server releases sharing the code base of this release
+    pub(super) fn compatible_with(&self) -> Vec<String> {
+        match self.release.0.as_str() {
+            "24H2" => vec!["windows-server-2025".to_string()],
+            _ => vec![],
+        }
+    }
+
     pub(super) fn is_enterprise(&self) -> bool {
         self.editions.contains(Edition::Enterprise) ||
+            self.editions.contains(Edition::EnterpriseKN) ||
             self.editions.contains(Edition::EnterpriseMultiSession) ||
+            self.editions.contains(Edition::EnterpriseN) ||
             self.editions.contains(Edition::IoTEnterprise)
     }
 
@@ -48,23 +60,35 @@ impl Windows11 {
         self
     }
 
+    // Whether the label named an edition, otherwise all editions of the release are listed
+    pub(crate) fn edition_known(mut self, edition_known: bool) -> Windows11 {
+        self.edition_known = edition_known;
+        self
+    }
+
+    pub(super) fn is_edition_known(&self) -> bool {
+        self.edition_known
+    }
+
+    // Long-term servicing releases are named after their year, e.g. "LTSC 2024"
+    fn release_name(&self) -> String {
+        match (&self.service_channel, self.release.lts_year()) {
+            (ServiceChannel::LTSC, Some(year)) => format!("LTSC {year}"),
+            (service_channel, _) if service_channel.is_default() => self.release.to_string(),
+            (service_channel, _) => format!("{} {service_channel}", self.release),
+        }
+    }
+
     pub(super) fn to_string(&self) -> Vec<String> {
         let out = self
             .editions
             .0
             .iter()
             .map(|edition| {
-                if self.service_channel.is_default() {
-                    format!(
-                        "{} {} {edition} {}",
-                        self.vendor, self.product, self.release
-                    )
-                } else {
-                    format!(
-                        "{} {} {edition} {} {}",
-                        self.vendor, self.product, self.release, self.service_channel
-                    )
-                }
+                format!(
+                    "{} {} {edition} {}",
+                    self.vendor, self.product, self.release_name()
+                )
             })
             .collect();
 
@@ -85,9 +109,30 @@ impl TryFrom<&str> for Windows11 {
     }
 }
 
+// Long-term servicing releases and the year they are named after
+const LTS_YEARS: [(&str, &str); 1] = [
+    ("24H2", "2024"),
+];
+
 #[derive(Debug)]
 pub(crate) struct Release(String);
 
+impl Release {
+    pub(crate) fn from_lts_year(year: &str) -> Option<Release> {
+        LTS_YEARS
+            .iter()
+            .find(|(_, lts_year)| *lts_year == year)
+            .map(|(release, _)| Release::from(*release))
+    }
+
+    fn lts_year(&self) -> Option<&str> {
+        LTS_YEARS
+            .iter()
+            .find(|(release, _)| *release == self.0)
+            .map(|(_, year)| *year)
+    }
+}
+
 impl From<&str> for Release {
     fn from(value: &str) -> Self {
         Release(value.to_uppercase())
@@ -148,26 +193,56 @@ impl Editions {
 #[derive(PartialEq, Debug)]
 pub(crate) enum Edition {
     Education,
+    EducationInSMode,
+    EducationKN,
+    EducationN,
     Enterprise,
+    EnterpriseKN,
     EnterpriseMultiSession,
+    EnterpriseN,
     Home,
+    HomeInSMode,
+    HomeKN,
+    HomeN,
+    HomeSingleLanguage,
     IoTEnterprise,
     Pro,
     ProEducation,
+    ProEducationN,
     ProForWorkstations,
+    ProForWorkstationsN,
+    ProInSMode,
+    ProKN,
+    ProN,
+    SE,
 }
 
 impl std::fmt::Display for Edition {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let out = match self {
             Edition::Education => "Education",
+            Edition::EducationInSMode => "Education in S mode",
+            Edition::EducationKN => "Education KN",
+            Edition::EducationN => "Education N",
             Edition::Enterprise => "Enterprise",
+            Edition::EnterpriseKN => "Enterprise KN",
             Edition::EnterpriseMultiSession => "Enterprise multi-session",
+            Edition::EnterpriseN => "Enterprise N",
             Edition::Home => "Home",
+            Edition::HomeInSMode => "Home in S mode",
+            Edition::HomeKN => "Home KN",
+            Edition::HomeN => "Home N",
+            Edition::HomeSingleLanguage => "Home Single Language",
             Edition::IoTEnterprise => "IoT Enterprise",
             Edition::Pro => "Pro",
             Edition::ProEducation => "Pro Education",
+            Edition::ProEducationN => "Pro Education N",
             Edition::ProForWorkstations => "Pro for Workstations",
+            Edition::ProForWorkstationsN => "Pro N for Workstations",
+            Edition::ProInSMode => "Pro in S mode",
+            Edition::ProKN => "Pro KN",
+            Edition::ProN => "Pro N",
+            Edition::SE => "SE",
         };
 
         write!(f, "{}", out.to_string())
@@ -321,4 +396,64 @@ mod tests {
         assert!(label.editions.contains(Edition::Enterprise));
         assert_eq!(label.service_channel, ServiceChannel::GAC);
     }
+
+    #[test]
+    fn test_from_string_arbitrary_wo_edition() {
+        let label = Windows11::try_from("Microsoft Windows 11 (Build 22631)").unwrap();
+
+        assert_eq!(label.release.to_string(), "23H2".to_string());
+        assert_eq!(label.editions.len(), Editions::all().len());
+        assert!(!label.is_edition_known());
+    }
+
+    #[test]
+    fn test_from_string_arbitrary_se() {
+        let label = Windows11::try_from("Windows 11 SE 23H2").unwrap();
+
+        assert!(label.editions.contains(Edition::SE));
+        assert_eq!(label.to_string(), vec!["Microsoft Windows 11 SE 23H2".to_string()]);
+    }
+
+    #[test]
+    fn test_from_string_arbitrary_single_language() {
+        let label = Windows11::try_from("Windows 11 Home Single Language (Build 22631)").unwrap();
+
+        assert!(label.editions.contains(Edition::HomeSingleLanguage));
+    }
+
+    #[test]
+    fn test_from_string_arbitrary_iot_enterprise() {
+        let label = Windows11::try_from("Windows 11 IoT Enterprise 24H2").unwrap();
+
+        assert!(label.editions.contains(Edition::IoTEnterprise));
+        assert!(label.is_enterprise());
+    }
+
+    #[test]
+    fn test_from_string_arbitrary_ltsc_year() {
+        let label = Windows11::try_from("Windows 11 IoT Enterprise LTSC 2024").unwrap();
+
+        assert_eq!(label.release.to_string(), "24H2".to_string());
+        assert_eq!(label.service_channel, ServiceChannel::LTSC);
+        assert_eq!(label.to_string(), vec!["Microsoft Windows 11 IoT Enterprise LTSC 2024".to_string()]);
+    }
+
+    #[test]
+    fn test_from_string_arbitrary_s_mode() {
+        let label = Windows11::try_from("Windows 11 Pro in S mode 23H2").unwrap();
+
+        assert!(label.editions.contains(Edition::ProInSMode));
+        assert_eq!(label.to_string(), vec!["Microsoft Windows 11 Pro in S mode 23H2".to_string()]);
+
+        let label = Windows11::try_from("Windows 11 Home in S mode 23H2").unwrap();
+
+        assert!(label.editions.contains(Edition::HomeInSMode));
+    }
+
+    #[test]
+    fn test_from_string_arbitrary_pro_n_for_workstations() {
+        let label = Windows11::try_from("Windows 11 Pro N for Workstations 23H2").unwrap();
+
+        assert_eq!(label.to_string(), vec!["Microsoft Windows 11 Pro N for Workstations 23H2".to_string()]);
+    }
 }
@@ -10,6 +10,20 @@ pub(crate) struct Windows7 {
 }
 
 impl Windows7 {
+    pub(crate) fn build(release: Release) -> Windows7 {
+        Windows7 {
+            vendor: "Microsoft".to_string(),
+            product: "Windows 7".to_string(),
+            release,
+            editions: Editions(vec![]),
+        }
+    }
+
+    pub(crate) fn editions(mut self, editions: Editions) -> Windows7 {
+        self.editions = editions;
+        self
+    }
+
     pub(super) fn vendor(&self) -> &str {
         self.vendor.as_str()
     }
@@ -37,10 +51,17 @@ impl Windows7 {
             .0
             .iter()
             .map(|edition| {
-                format!(
-                    "{} {} {edition} {}",
-                    self.vendor, self.product, self.release
-                )
+                if self.release == Release::GA {
+                    format!(
+                        "{} {} {edition}",
+                        self.vendor, self.product
+                    )
+                } else {
+                    format!(
+                        "{} {} {edition} {}",
+                        self.vendor, self.product, self.release
+                    )
+                }
             })
             .collect();
 
@@ -52,6 +73,11 @@ impl TryFrom<&str> for Windows7 {
     type Error = String;
 
     fn try_from(value: &str) -> Result<Self, Self::Error> {
+        if crate::parser::endoflife::EndOfLifeLabel::try_from(value).is_err() {
+            let label = crate::parser::generic::GenericLabel::from(value);
+            return crate::parser::generic::windows::Windows7Parser::parse(&label);
+        }
+
         let parts: Vec<&str> = value.split('-').collect();
         let parts: Vec<&str> = parts.iter().skip(1).map(|&s| s).collect();
 
@@ -83,10 +109,9 @@ impl TryFrom<&str> for Windows7 {
     }
 }
 
-#[derive(Debug)]
-enum Release {
+#[derive(PartialEq, Debug)]
+pub(crate) enum Release {
     // Original Release
-    #[allow(dead_code)]
     GA,
     // Service Pack 1
     SP1,
@@ -103,6 +128,7 @@ impl TryFrom<&str> for Release {
 
     fn try_from(value: &str) -> Result<Self, Self::Error> {
         match value.to_uppercase().as_str() {
+            "GA" => Ok(Release::GA),
             "SP1" => Ok(Release::SP1),
             "ESU1" => Ok(Release::ESU1),
             "ESU2" => Ok(Release::ESU2),
@@ -127,10 +153,10 @@ impl std::fmt::Display for Release {
 }
 
 #[derive(Debug)]
-struct Editions(Vec<Edition>);
+pub(crate) struct Editions(pub(crate) Vec<Edition>);
 
 impl Editions {
-    fn all() -> Self {
+    pub(crate) fn all() -> Self {
         Editions(vec![
             Edition::Enterprise,
             Edition::EnterpriseN,
@@ -160,7 +186,7 @@ impl Editions {
 }
 
 #[derive(PartialEq, Debug)]
-enum Edition {
+pub(crate) enum Edition {
     Enterprise,
     EnterpriseN,
     HomeBasic,
@@ -212,4 +238,37 @@ mod tests {
 
         assert_eq!(label.editions.len(), Editions::all().len());
     }
+
+    #[test]
+    fn test_from_string_arbitrary1() {
+        let label = Windows7::try_from("Microsoft Windows 7 Professional Service Pack 1").unwrap();
+
+        assert_eq!(label.release, Release::SP1);
+        assert_eq!(label.editions.len(), 1);
+        assert!(label.editions.contains(Edition::Professional));
+        assert_eq!(label.to_string(), vec!["Microsoft Windows 7 Professional SP1".to_string()]);
+    }
+
+    #[test]
+    fn test_from_string_arbitrary2() {
+        let label = Windows7::try_from("Windows 7 Enterprise N 6.1.7601").unwrap();
+
+        assert_eq!(label.release, Release::SP1);
+        assert!(label.editions.contains(Edition::EnterpriseN));
+        assert!(label.is_enterprise());
+    }
+
+    #[test]
+    fn test_from_string_arbitrary3() {
+        let label = Windows7::try_from("Windows 7 Home Premium (Build 7600)").unwrap();
+
+        assert_eq!(label.release, Release::GA);
+        assert_eq!(label.to_string(), vec!["Microsoft Windows 7 Home Premium".to_string()]);
+    }
+
+    #[test]
+    fn test_from_string_arbitrary_foreign_build() {
+        assert!(Windows7::try_from("Windows 7 Professional Build 9600").is_err());
+        assert!(Windows7::try_from("Windows 7 Professional Service Pack 1 (Build 7600)").is_err());
+    }
 }
@@ -10,6 +10,20 @@ pub(crate) struct Windows8 {
 }
 
 impl Windows8 {
+    // Windows 8 and 8.1 are distinct products, the release is part of the product name
+    pub(crate) fn build(release: &str) -> Windows8 {
+        Windows8 {
+            vendor: "Microsoft".to_string(),
+            product: format!("Windows {}", release),
+            editions: Editions(vec![]),
+        }
+    }
+
+    pub(crate) fn editions(mut self, editions: Editions) -> Windows8 {
+        self.editions = editions;
+        self
+    }
+
     pub(super) fn vendor(&self) -> &str {
         self.vendor.as_str()
     }
@@ -47,6 +61,11 @@ impl TryFrom<&str> for Windows8 {
     type Error = String;
 
     fn try_from(value: &str) -> Result<Self, Self::Error> {
+        if crate::parser::endoflife::EndOfLifeLabel::try_from(value).is_err() {
+            let label = crate::parser::generic::GenericLabel::from(value);
+            return crate::parser::generic::windows::Windows8Parser::parse(&label);
+        }
+
         match value {
             "windows-8" => Ok(Windows8 {
                 vendor: "Microsoft".to_string(),
@@ -64,10 +83,10 @@ impl TryFrom<&str> for Windows8 {
 }
 
 #[derive(Debug)]
-struct Editions(Vec<Edition>);
+pub(crate) struct Editions(pub(crate) Vec<Edition>);
 
 impl Editions {
-    fn all() -> Self {
+    pub(crate) fn all() -> Self {
         Editions(vec![
             Edition::Enterprise,
             Edition::EnterpriseN,
@@ -91,7 +110,7 @@ impl Editions {
 }
 
 #[derive(PartialEq, Debug)]
-enum Edition {
+pub(crate) enum Edition {
     Enterprise,
     EnterpriseN,
     N,
@@ -140,4 +159,35 @@ mod tests {
 
         assert_eq!(label.editions.len(), Editions::all().len());
     }
+
+    #[test]
+    fn test_from_string_arbitrary1() {
+        let label = Windows8::try_from("Windows 8.1 Pro (Build 9600)").unwrap();
+
+        assert_eq!(label.product, "Windows 8.1".to_string());
+        assert_eq!(label.editions.len(), 1);
+        assert!(label.editions.contains(Edition::Professional));
+    }
+
+    #[test]
+    fn test_from_string_arbitrary2() {
+        let label = Windows8::try_from("Windows 8 Enterprise (Build 9600)").unwrap();
+
+        assert_eq!(label.product, "Windows 8.1".to_string());
+        assert!(label.is_enterprise());
+    }
+
+    #[test]
+    fn test_from_string_arbitrary3() {
+        let label = Windows8::try_from("Windows 8.1 Single Language").unwrap();
+
+        assert_eq!(label.product, "Windows 8.1".to_string());
+        assert!(label.editions.contains(Edition::SL));
+    }
+
+    #[test]
+    fn test_from_string_arbitrary_foreign_build() {
+        assert!(Windows8::try_from("Windows 8.1 Pro (Build 9200)").is_err());
+        assert!(Windows8::try_from("Windows 8 Pro (Build 7601)").is_err());
+    }
 }
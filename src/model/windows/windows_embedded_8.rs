@@ -0,0 +1,169 @@
+//
+// https://learn.microsoft.com/lifecycle/products/windows-embedded-8-industry
+// https://learn.microsoft.com/lifecycle/products/windows-embedded-81-industry
+//
+const PRODUCT: &str = "Windows Embedded Industry";
+const VENDOR: &str = "Microsoft";
+
+/// WindowsEmbedded8 covers Windows Embedded 8 Industry and Windows Embedded 8.1 Industry.
+#[derive(Debug)]
+pub(crate) struct WindowsEmbedded8 {
+    vendor: String,
+    product: String,
+    release: Release,
+    editions: Editions,
+}
+
+impl WindowsEmbedded8 {
+    pub(crate) fn build(release: Release) -> WindowsEmbedded8 {
+        WindowsEmbedded8 {
+            vendor: VENDOR.to_string(),
+            product: PRODUCT.to_string(),
+            release,
+            editions: Editions(vec![]),
+        }
+    }
+
+    pub(crate) fn editions(mut self, editions: Editions) -> WindowsEmbedded8 {
+        self.editions = editions;
+        self
+    }
+
+    pub(super) fn vendor(&self) -> &str {
+        self.vendor.as_str()
+    }
+
+    pub(super) fn product(&self) -> &str {
+        self.product.as_str()
+    }
+
+    pub(super) fn release(&self) -> String {
+        self.release.to_string()
+    }
+
+    pub(super) fn is_enterprise(&self) -> bool {
+        self.editions.contains(Edition::Enterprise)
+    }
+
+    // Embedded products are serviced under the Fixed Lifecycle Policy
+    pub(super) fn is_lts(&self) -> bool {
+        true
+    }
+
+    pub(super) fn to_string(&self) -> Vec<String> {
+        self.editions
+            .0
+            .iter()
+            .map(|edition| {
+                format!(
+                    "{} Windows Embedded {} Industry {edition}",
+                    self.vendor, self.release
+                )
+            })
+            .collect()
+    }
+}
+
+impl TryFrom<&str> for WindowsEmbedded8 {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        if let Ok(label) = crate::parser::endoflife::EndOfLifeLabel::try_from(value) {
+            crate::parser::endoflife::windows::WindowsEmbedded8Parser::parse(&label)
+        } else {
+            let label = crate::parser::generic::GenericLabel::from(value);
+            crate::parser::generic::windows::WindowsEmbedded8Parser::parse(&label)
+        }
+    }
+}
+
+/// Release of Windows Embedded Industry, either 8 or 8.1.
+#[derive(Debug)]
+pub(crate) struct Release(String);
+
+impl From<&str> for Release {
+    fn from(value: &str) -> Self {
+        Release(value.to_string())
+    }
+}
+
+impl std::fmt::Display for Release {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct Editions(pub(crate) Vec<Edition>);
+
+impl Editions {
+    pub(crate) fn all() -> Self {
+        Editions(vec![
+            Edition::Enterprise,
+            Edition::Pro,
+            Edition::ProRetail,
+        ])
+    }
+
+    #[allow(dead_code)]
+    fn contains(&self, edition: Edition) -> bool {
+        self.0.contains(&edition)
+    }
+
+    #[allow(dead_code)]
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+}
+
+#[derive(PartialEq, Debug)]
+pub(crate) enum Edition {
+    Enterprise,
+    Pro,
+    ProRetail,
+}
+
+impl std::fmt::Display for Edition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let out = match self {
+            Edition::Enterprise => "Enterprise",
+            Edition::Pro => "Pro",
+            Edition::ProRetail => "Pro Retail",
+        };
+
+        write!(f, "{}", out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_string_1() {
+        let label = WindowsEmbedded8::try_from("windows-embedded-8.1-industry").unwrap();
+
+        assert_eq!(label.vendor, "Microsoft".to_string());
+        assert_eq!(label.product, "Windows Embedded Industry".to_string());
+        assert_eq!(label.release.to_string(), "8.1".to_string());
+        assert_eq!(label.editions.len(), Editions::all().len());
+    }
+
+    #[test]
+    fn test_from_string_arbitrary1() {
+        let label = WindowsEmbedded8::try_from("Windows Embedded 8.1 Industry Pro Retail").unwrap();
+
+        assert_eq!(label.release.to_string(), "8.1".to_string());
+        assert_eq!(label.editions.len(), 1);
+        assert!(label.editions.contains(Edition::ProRetail));
+        assert_eq!(label.to_string(), vec!["Microsoft Windows Embedded 8.1 Industry Pro Retail".to_string()]);
+    }
+
+    #[test]
+    fn test_from_string_arbitrary2() {
+        let label = WindowsEmbedded8::try_from("Windows Embedded 8 Industry Enterprise").unwrap();
+
+        assert_eq!(label.release.to_string(), "8".to_string());
+        assert!(label.is_enterprise());
+    }
+}
@@ -0,0 +1,82 @@
+//
+// https://learn.microsoft.com/lifecycle/products/windows-embedded-compact-7
+// https://learn.microsoft.com/lifecycle/products/windows-embedded-compact-2013
+//
+const PRODUCT: &str = "Windows Embedded Compact";
+const VENDOR: &str = "Microsoft";
+
+/// WindowsEmbeddedCompact covers Windows Embedded Compact 7 and 2013.
+#[derive(Debug)]
+pub(crate) struct WindowsEmbeddedCompact {
+    vendor: String,
+    product: String,
+}
+
+impl WindowsEmbeddedCompact {
+    // Like Windows 8 and 8.1, the release is part of the product name
+    pub(crate) fn build(release: &str) -> WindowsEmbeddedCompact {
+        WindowsEmbeddedCompact {
+            vendor: VENDOR.to_string(),
+            product: format!("{PRODUCT} {release}"),
+        }
+    }
+
+    pub(super) fn vendor(&self) -> &str {
+        self.vendor.as_str()
+    }
+
+    pub(super) fn product(&self) -> &str {
+        self.product.as_str()
+    }
+
+    pub(super) fn release(&self) -> String {
+        "".to_string()
+    }
+
+    pub(super) fn is_enterprise(&self) -> bool {
+        false
+    }
+
+    pub(super) fn is_lts(&self) -> bool {
+        true
+    }
+
+    pub(super) fn to_string(&self) -> Vec<String> {
+        vec![format!("{} {}", self.vendor, self.product)]
+    }
+}
+
+impl TryFrom<&str> for WindowsEmbeddedCompact {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        if let Ok(label) = crate::parser::endoflife::EndOfLifeLabel::try_from(value) {
+            crate::parser::endoflife::windows::WindowsEmbeddedCompactParser::parse(&label)
+        } else {
+            let label = crate::parser::generic::GenericLabel::from(value);
+            crate::parser::generic::windows::WindowsEmbeddedCompactParser::parse(&label)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_string_1() {
+        let label = WindowsEmbeddedCompact::try_from("windows-embedded-compact-2013").unwrap();
+
+        assert_eq!(label.vendor, "Microsoft".to_string());
+        assert_eq!(label.product, "Windows Embedded Compact 2013".to_string());
+        assert!(label.release().is_empty());
+        assert_eq!(label.to_string(), vec!["Microsoft Windows Embedded Compact 2013".to_string()]);
+    }
+
+    #[test]
+    fn test_from_string_arbitrary1() {
+        let label = WindowsEmbeddedCompact::try_from("Windows Embedded Compact 7").unwrap();
+
+        assert_eq!(label.product, "Windows Embedded Compact 7".to_string());
+    }
+}
@@ -0,0 +1,112 @@
+//
+// https://learn.microsoft.com/lifecycle/products/windows-embedded-standard-7
+//
+const PRODUCT: &str = "Windows Embedded Standard 7";
+const VENDOR: &str = "Microsoft";
+
+#[derive(Debug)]
+pub(crate) struct WindowsEmbeddedStandard7 {
+    vendor: String,
+    product: String,
+    release: Option<Release>,
+}
+
+impl WindowsEmbeddedStandard7 {
+    pub(crate) fn build(release: Option<Release>) -> WindowsEmbeddedStandard7 {
+        WindowsEmbeddedStandard7 {
+            vendor: VENDOR.to_string(),
+            product: PRODUCT.to_string(),
+            release,
+        }
+    }
+
+    pub(super) fn vendor(&self) -> &str {
+        self.vendor.as_str()
+    }
+
+    pub(super) fn product(&self) -> &str {
+        self.product.as_str()
+    }
+
+    pub(super) fn release(&self) -> String {
+        match &self.release {
+            Some(release) => release.to_string(),
+            None => "".to_string(),
+        }
+    }
+
+    pub(super) fn is_enterprise(&self) -> bool {
+        false
+    }
+
+    pub(super) fn is_lts(&self) -> bool {
+        true
+    }
+
+    pub(super) fn to_string(&self) -> Vec<String> {
+        let out = match &self.release {
+            Some(release) => format!("{} {} {release}", self.vendor, self.product),
+            None => format!("{} {}", self.vendor, self.product),
+        };
+
+        vec![out]
+    }
+}
+
+impl TryFrom<&str> for WindowsEmbeddedStandard7 {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        if let Ok(label) = crate::parser::endoflife::EndOfLifeLabel::try_from(value) {
+            crate::parser::endoflife::windows::WindowsEmbeddedStandard7Parser::parse(&label)
+        } else {
+            let label = crate::parser::generic::GenericLabel::from(value);
+            crate::parser::generic::windows::WindowsEmbeddedStandard7Parser::parse(&label)
+        }
+    }
+}
+
+/// Service pack of Windows Embedded Standard 7, e.g. SP1.
+#[derive(Clone, Debug)]
+pub(crate) struct Release(String);
+
+impl From<&str> for Release {
+    fn from(value: &str) -> Self {
+        Release(value.to_uppercase())
+    }
+}
+
+impl std::fmt::Display for Release {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_string_1() {
+        let label = WindowsEmbeddedStandard7::try_from("windows-embedded-standard-7").unwrap();
+
+        assert_eq!(label.vendor, "Microsoft".to_string());
+        assert_eq!(label.product, "Windows Embedded Standard 7".to_string());
+        assert!(label.release.is_none());
+        assert_eq!(label.to_string(), vec!["Microsoft Windows Embedded Standard 7".to_string()]);
+    }
+
+    #[test]
+    fn test_from_string_arbitrary1() {
+        let label = WindowsEmbeddedStandard7::try_from("Windows Embedded Standard 7 Service Pack 1").unwrap();
+
+        assert_eq!(label.release.unwrap().to_string(), "SP1".to_string());
+    }
+
+    #[test]
+    fn test_from_string_arbitrary2() {
+        let label = WindowsEmbeddedStandard7::try_from("Windows 7 Professional for Embedded Systems");
+
+        assert!(label.is_err());
+    }
+}
@@ -3,6 +3,8 @@
 const PRODUCT: &str = "Windows Server";
 const VENDOR: &str = "Microsoft";
 
+use super::InstallationOption;
+
 #[derive(Debug)]
 pub(crate) struct WindowsServer1709ff {
     vendor: String,
@@ -11,9 +13,31 @@ pub(crate) struct WindowsServer1709ff {
     release: Option<Release>,
     #[allow(dead_code)]
     service_channel: ServiceChannel,
+    installation_option: Option<InstallationOption>,
 }
 
 impl WindowsServer1709ff {
+    pub(crate) fn build(release: Release, service_channel: ServiceChannel) -> WindowsServer1709ff {
+        WindowsServer1709ff {
+            vendor: VENDOR.to_string(),
+            product: PRODUCT.to_string(),
+            editions: Editions(vec![]),
+            release: Some(release),
+            service_channel,
+            installation_option: None,
+        }
+    }
+
+    pub(crate) fn editions(mut self, editions: Editions) -> WindowsServer1709ff {
+        self.editions = editions;
+        self
+    }
+
+    pub(crate) fn installation_option(mut self, installation_option: Option<InstallationOption>) -> WindowsServer1709ff {
+        self.installation_option = installation_option;
+        self
+    }
+
     pub(super) fn vendor(&self) -> &str {
         self.vendor.as_str()
     }
@@ -29,6 +53,10 @@ impl WindowsServer1709ff {
         }
     }
 
+    pub(super) fn installation(&self) -> Option<InstallationOption> {
+        self.installation_option.clone()
+    }
+
     pub(super) fn is_enterprise(&self) -> bool {
         false
     }
@@ -38,6 +66,8 @@ impl WindowsServer1709ff {
     }
     
     pub(super) fn to_string(&self) -> Vec<String> {
+        let option = InstallationOption::suffix(&self.installation_option);
+
         let out = self
             .editions
             .0
@@ -45,13 +75,13 @@ impl WindowsServer1709ff {
             .map(|edition| {
                 if self.release.is_none() {
                     format!(
-                        "{} {} {edition}",
+                        "{} {} {edition}{option}",
                         self.vendor, self.product
                     )
                 } else {
                     let release = self.release.clone().unwrap();
                     format!(
-                        "{} {} {edition} {release}",
+                        "{} {} {edition} {release}{option}",
                         self.vendor, self.product
                     )
                 }
@@ -66,8 +96,19 @@ impl TryFrom<&str> for WindowsServer1709ff {
     type Error = String;
 
     fn try_from(value: &str) -> Result<Self, Self::Error> {
+        if crate::parser::endoflife::EndOfLifeLabel::try_from(value).is_err() {
+            let label = crate::parser::generic::GenericLabel::from(value);
+            return crate::parser::generic::windows::WindowsServer1709ffParser::parse(&label);
+        }
+
+        let (installation_option, value) = InstallationOption::split_label(value);
         let parts: Vec<&str> = value.split('-').collect();
-        let parts: Vec<&str> = parts.iter().skip(2).map(|&s| s).collect();
+        let mut parts: Vec<&str> = parts.iter().skip(2).copied().collect();
+
+        // Server Core and Nano Server labels carry no channel, their releases were SAC
+        if installation_option.is_some() && parts.len() == 1 {
+            parts.push("sac");
+        }
 
         if let Some(first) = parts.get(0) && let Some(second) = parts.get(1) {
             if ! (*first >= "1709" && ( *second == "ac" || *second == "sac" )) {
@@ -86,6 +127,7 @@ impl TryFrom<&str> for WindowsServer1709ff {
                         editions,
                         release: Some(Release::from(*first)),
                         service_channel,
+                        installation_option,
                     })
                 } else {
                     Err(String::from("This is not a Windows Server."))
@@ -98,7 +140,7 @@ impl TryFrom<&str> for WindowsServer1709ff {
 }
 
 #[derive(Clone, Debug)]
-struct Release(String);
+pub(crate) struct Release(String);
 
 impl Release {}
 
@@ -115,10 +157,10 @@ impl std::fmt::Display for Release {
 }
 
 #[derive(Debug)]
-struct Editions(Vec<Edition>);
+pub(crate) struct Editions(pub(crate) Vec<Edition>);
 
 impl Editions {
-    fn all() -> Self {
+    pub(crate) fn all() -> Self {
         Editions(vec![
             Edition::Datacenter,
             Edition::Standard,
@@ -149,7 +191,7 @@ impl From<&ServiceChannel> for Editions {
 }
 
 #[derive(PartialEq, Debug)]
-enum Edition {
+pub(crate) enum Edition {
     Datacenter,
     Standard,
 }
@@ -215,6 +257,16 @@ impl From<&str> for ServiceChannel {
     }
 }
 
+// The Annual Channel succeeded the Semi-Annual Channel with 23H2
+impl From<&Release> for ServiceChannel {
+    fn from(value: &Release) -> Self {
+        match value.0.as_str() {
+            "23H2" => ServiceChannel::AC,
+            _ => ServiceChannel::SAC,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -254,4 +306,38 @@ mod tests {
         assert_eq!(label.release.unwrap().0, "23H2");
         assert_eq!(label.service_channel, ServiceChannel::AC);
     }
+
+    #[test]
+    fn test_from_string_core_1809() {
+        let label = WindowsServer1709ff::try_from("windows-server-core-1809").unwrap();
+
+        assert_eq!(label.release.as_ref().unwrap().0, "1809");
+        assert_eq!(label.service_channel, ServiceChannel::SAC);
+        assert_eq!(label.installation_option, Some(InstallationOption::ServerCore));
+        assert!(label.to_string().contains(&"Microsoft Windows Server Datacenter 1809 Server Core".to_string()));
+    }
+
+    #[test]
+    fn test_from_string_arbitrary1() {
+        let label = WindowsServer1709ff::try_from("Windows Server Datacenter Version 20H2 (Build 19042)").unwrap();
+
+        assert_eq!(label.release.as_ref().unwrap().0, "20H2");
+        assert_eq!(label.service_channel, ServiceChannel::SAC);
+        assert_eq!(label.to_string(), vec!["Microsoft Windows Server Datacenter 20H2".to_string()]);
+    }
+
+    #[test]
+    fn test_from_string_arbitrary2() {
+        let label = WindowsServer1709ff::try_from("Windows Server 23H2 Annual Channel").unwrap();
+
+        assert_eq!(label.release.as_ref().unwrap().0, "23H2");
+        assert_eq!(label.service_channel, ServiceChannel::AC);
+        assert_eq!(label.editions.len(), 1);
+        assert!(label.editions.contains(Edition::Datacenter));
+    }
+
+    #[test]
+    fn test_from_string_arbitrary_ltsc() {
+        assert!(WindowsServer1709ff::try_from("Windows Server 2019 Standard (Build 17763)").is_err());
+    }
 }
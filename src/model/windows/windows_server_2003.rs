@@ -14,6 +14,29 @@ pub(crate) struct WindowsServer2003 {
 }
 
 impl WindowsServer2003 {
+    pub(crate) fn build(release: Release) -> WindowsServer2003 {
+        WindowsServer2003 {
+            vendor: VENDOR.to_string(),
+            product: PRODUCT.to_string(),
+            editions: Editions(vec![]),
+            release: crate::util::without_original_release(release),
+            service_channel: ServiceChannel::default(),
+        }
+    }
+
+    pub(crate) fn editions(mut self, editions: Editions) -> WindowsServer2003 {
+        self.editions = editions;
+        self
+    }
+
+    // Windows Server 2003 R2 shares the lifecycle of Windows Server 2003
+    pub(crate) fn r2(mut self, r2: bool) -> WindowsServer2003 {
+        if r2 {
+            self.product = format!("{} R2", PRODUCT);
+        }
+        self
+    }
+
     pub(super) fn vendor(&self) -> &str {
         self.vendor.as_str()
     }
@@ -66,6 +89,11 @@ impl TryFrom<&str> for WindowsServer2003 {
     type Error = String;
 
     fn try_from(value: &str) -> Result<Self, Self::Error> {
+        if crate::parser::endoflife::EndOfLifeLabel::try_from(value).is_err() {
+            let label = crate::parser::generic::GenericLabel::from(value);
+            return crate::parser::generic::windows::WindowsServer2003Parser::parse(&label);
+        }
+
         let parts: Vec<&str> = value.split('-').collect();
         let parts: Vec<&str> = parts.iter().skip(2).map(|&s| s).collect();
 
@@ -103,7 +131,7 @@ impl TryFrom<&str> for WindowsServer2003 {
 }
 
 #[derive(Clone, Debug)]
-struct Release(String);
+pub(crate) struct Release(String);
 
 impl Release {}
 
@@ -120,10 +148,10 @@ impl std::fmt::Display for Release {
 }
 
 #[derive(Debug)]
-struct Editions(Vec<Edition>);
+pub(crate) struct Editions(pub(crate) Vec<Edition>);
 
 impl Editions {
-    fn all() -> Self {
+    pub(crate) fn all() -> Self {
         Editions(vec![
             Edition::Datacenter,
             Edition::Enterprise,
@@ -144,7 +172,7 @@ impl Editions {
 }
 
 #[derive(PartialEq, Debug)]
-enum Edition {
+pub(crate) enum Edition {
     Datacenter,
     Enterprise,
     Standard,
@@ -232,4 +260,30 @@ mod tests {
         assert!(label.release.is_some());
         assert_eq!(label.release.unwrap().to_string(), "SP2".to_string());
     }
+
+    #[test]
+    fn test_from_string_arbitrary1() {
+        let label = WindowsServer2003::try_from("Microsoft Windows Server 2003, Enterprise Edition Service Pack 2").unwrap();
+
+        assert_eq!(label.release.as_ref().unwrap().to_string(), "SP2".to_string());
+        assert_eq!(label.editions.len(), 1);
+        assert!(label.is_enterprise());
+        assert_eq!(label.to_string(), vec!["Microsoft Windows Server 2003 Enterprise SP2".to_string()]);
+    }
+
+    #[test]
+    fn test_from_string_arbitrary_r2() {
+        let label = WindowsServer2003::try_from("Windows Server 2003 R2 Standard").unwrap();
+
+        assert_eq!(label.product, "Windows Server 2003 R2".to_string());
+        assert_eq!(label.to_string(), vec!["Microsoft Windows Server 2003 R2 Standard".to_string()]);
+    }
+
+    #[test]
+    fn test_from_string_arbitrary_build_revision() {
+        let label = WindowsServer2003::try_from("Windows Server 2003 Standard 5.2.3790.3959").unwrap();
+
+        assert_eq!(label.release.as_ref().unwrap().to_string(), "SP2".to_string());
+        assert!(WindowsServer2003::try_from("Windows Server 2003 Standard SP1 5.2.3790.3959").is_err());
+    }
 }
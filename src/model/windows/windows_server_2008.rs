@@ -14,6 +14,21 @@ pub(crate) struct WindowsServer2008 {
 }
 
 impl WindowsServer2008 {
+    pub(crate) fn build(release: Release) -> WindowsServer2008 {
+        WindowsServer2008 {
+            vendor: VENDOR.to_string(),
+            product: PRODUCT.to_string(),
+            editions: Editions(vec![]),
+            release: crate::util::without_original_release(release),
+            service_channel: ServiceChannel::default(),
+        }
+    }
+
+    pub(crate) fn editions(mut self, editions: Editions) -> WindowsServer2008 {
+        self.editions = editions;
+        self
+    }
+
     pub(super) fn vendor(&self) -> &str {
         self.vendor.as_str()
     }
@@ -66,6 +81,11 @@ impl TryFrom<&str> for WindowsServer2008 {
     type Error = String;
 
     fn try_from(value: &str) -> Result<Self, Self::Error> {
+        if crate::parser::endoflife::EndOfLifeLabel::try_from(value).is_err() {
+            let label = crate::parser::generic::GenericLabel::from(value);
+            return crate::parser::generic::windows::WindowsServer2008Parser::parse(&label);
+        }
+
         let parts: Vec<&str> = value.split('-').collect();
         let parts: Vec<&str> = parts.iter().skip(2).map(|&s| s).collect();
 
@@ -103,7 +123,7 @@ impl TryFrom<&str> for WindowsServer2008 {
 }
 
 #[derive(Clone, Debug)]
-struct Release(String);
+pub(crate) struct Release(String);
 
 impl Release {}
 
@@ -120,10 +140,10 @@ impl std::fmt::Display for Release {
 }
 
 #[derive(Debug)]
-struct Editions(Vec<Edition>);
+pub(crate) struct Editions(pub(crate) Vec<Edition>);
 
 impl Editions {
-    fn all() -> Self {
+    pub(crate) fn all() -> Self {
         Editions(vec![
             Edition::Datacenter,
             Edition::Enterprise,
@@ -145,7 +165,7 @@ impl Editions {
 }
 
 #[derive(PartialEq, Debug)]
-enum Edition {
+pub(crate) enum Edition {
     Datacenter,
     Enterprise,
     Foundation,
@@ -235,4 +255,18 @@ mod tests {
         assert!(label.release.is_some());
         assert_eq!(label.release.unwrap().to_string(), "SP2".to_string());
     }
+
+    #[test]
+    fn test_from_string_arbitrary1() {
+        let label = WindowsServer2008::try_from("Windows Server 2008 Standard (Build 6003)").unwrap();
+
+        assert_eq!(label.release.as_ref().unwrap().to_string(), "SP2".to_string());
+        assert_eq!(label.to_string(), vec!["Microsoft Windows Server 2008 Standard SP2".to_string()]);
+    }
+
+    #[test]
+    fn test_from_string_arbitrary_r2() {
+        assert!(WindowsServer2008::try_from("Windows Server 2008 R2 Enterprise SP1").is_err());
+        assert!(WindowsServer2008::try_from("Windows Server 2008 Standard 6.1.7601").is_err());
+    }
 }
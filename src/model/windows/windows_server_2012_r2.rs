@@ -14,6 +14,21 @@ pub(crate) struct WindowsServer2012R2 {
 }
 
 impl WindowsServer2012R2 {
+    pub(crate) fn build(release: Release) -> WindowsServer2012R2 {
+        WindowsServer2012R2 {
+            vendor: VENDOR.to_string(),
+            product: PRODUCT.to_string(),
+            editions: Editions(vec![]),
+            release: crate::util::without_original_release(release),
+            service_channel: ServiceChannel::default(),
+        }
+    }
+
+    pub(crate) fn editions(mut self, editions: Editions) -> WindowsServer2012R2 {
+        self.editions = editions;
+        self
+    }
+
     pub(super) fn vendor(&self) -> &str {
         self.vendor.as_str()
     }
@@ -66,6 +81,11 @@ impl TryFrom<&str> for WindowsServer2012R2 {
     type Error = String;
 
     fn try_from(value: &str) -> Result<Self, Self::Error> {
+        if crate::parser::endoflife::EndOfLifeLabel::try_from(value).is_err() {
+            let label = crate::parser::generic::GenericLabel::from(value);
+            return crate::parser::generic::windows::WindowsServer2012R2Parser::parse(&label);
+        }
+
         let parts: Vec<&str> = value.split('-').collect();
         let parts: Vec<&str> = parts.iter().skip(2).map(|&s| s).collect();
 
@@ -103,7 +123,7 @@ impl TryFrom<&str> for WindowsServer2012R2 {
 }
 
 #[derive(Clone, Debug)]
-struct Release(String);
+pub(crate) struct Release(String);
 
 impl Release {}
 
@@ -120,10 +140,10 @@ impl std::fmt::Display for Release {
 }
 
 #[derive(Debug)]
-struct Editions(Vec<Edition>);
+pub(crate) struct Editions(pub(crate) Vec<Edition>);
 
 impl Editions {
-    fn all() -> Self {
+    pub(crate) fn all() -> Self {
         Editions(vec![
             Edition::Datacenter,
             Edition::Essentials,
@@ -144,7 +164,7 @@ impl Editions {
 }
 
 #[derive(PartialEq, Debug)]
-enum Edition {
+pub(crate) enum Edition {
     Datacenter,
     Essentials,
     Foundation,
@@ -221,4 +241,13 @@ mod tests {
         assert_eq!(label.editions.len(), Editions::all().len());
         assert!(label.release.is_none());
     }
+
+    #[test]
+    fn test_from_string_arbitrary1() {
+        let label = WindowsServer2012R2::try_from("Windows Server 2012 R2 Standard (Build 9600)").unwrap();
+
+        assert!(label.release.is_none());
+        assert_eq!(label.editions.len(), 1);
+        assert_eq!(label.to_string(), vec!["Microsoft Windows Server 2012 R2 Standard".to_string()]);
+    }
 }
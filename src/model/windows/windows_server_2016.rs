@@ -3,6 +3,8 @@
 const PRODUCT: &str = "Windows Server 2016";
 const VENDOR: &str = "Microsoft";
 
+use super::InstallationOption;
+
 #[derive(Debug)]
 pub(crate) struct WindowsServer2016 {
     vendor: String,
@@ -11,9 +13,34 @@ pub(crate) struct WindowsServer2016 {
     release: Option<Release>,
     #[allow(dead_code)]
     service_channel: ServiceChannel,
+    installation_option: Option<InstallationOption>,
 }
 
 impl WindowsServer2016 {
+    pub(crate) fn build(release: Release) -> WindowsServer2016 {
+        WindowsServer2016 {
+            vendor: VENDOR.to_string(),
+            product: PRODUCT.to_string(),
+            editions: Editions(vec![]),
+            release: crate::util::without_original_release(release),
+            service_channel: ServiceChannel::default(),
+            installation_option: None,
+        }
+    }
+
+    pub(crate) fn editions(mut self, editions: Editions) -> WindowsServer2016 {
+        self.editions = editions;
+        self
+    }
+
+    pub(crate) fn installation_option(mut self, installation_option: Option<InstallationOption>) -> WindowsServer2016 {
+        if let Some(option) = &installation_option {
+            self.editions.0.retain(|edition| edition.supports(option));
+        }
+        self.installation_option = installation_option;
+        self
+    }
+
     pub(super) fn vendor(&self) -> &str {
         self.vendor.as_str()
     }
@@ -22,6 +49,16 @@ impl WindowsServer2016 {
         self.product.as_str()
     }
 
+    // Client release the server release was branched from
+    pub(super) fn based_on(&self) -> Option<String> {
+        self.compatible_with().into_iter().next()
+    }
+
+    // Client release sharing the code base of Windows Server 2016
+    pub(super) fn compatible_with(&self) -> Vec<String> {
+        vec!["windows-10-1607-e-lts".to_string()]
+    }
+
     pub(super) fn release(&self) -> String {
         match &self.release {
             Some(release) => release.to_string(),
@@ -29,6 +66,10 @@ impl WindowsServer2016 {
         }
     }
 
+    pub(super) fn installation(&self) -> Option<InstallationOption> {
+        self.installation_option.clone()
+    }
+
     pub(super) fn is_enterprise(&self) -> bool {
         false
     }
@@ -38,6 +79,8 @@ impl WindowsServer2016 {
     }
     
     pub(super) fn to_string(&self) -> Vec<String> {
+        let option = InstallationOption::suffix(&self.installation_option);
+
         let out = self
             .editions
             .0
@@ -45,13 +88,13 @@ impl WindowsServer2016 {
             .map(|edition| {
                 if self.release.is_none() {
                     format!(
-                        "{} {} {edition}",
+                        "{} {} {edition}{option}",
                         self.vendor, self.product
                     )
                 } else {
                     let release = self.release.clone().unwrap();
                     format!(
-                        "{} {} {edition} {release}",
+                        "{} {} {edition} {release}{option}",
                         self.vendor, self.product
                     )
                 }
@@ -66,6 +109,12 @@ impl TryFrom<&str> for WindowsServer2016 {
     type Error = String;
 
     fn try_from(value: &str) -> Result<Self, Self::Error> {
+        if crate::parser::endoflife::EndOfLifeLabel::try_from(value).is_err() {
+            let label = crate::parser::generic::GenericLabel::from(value);
+            return crate::parser::generic::windows::WindowsServer2016Parser::parse(&label);
+        }
+
+        let (installation_option, value) = InstallationOption::split_label(value);
         let parts: Vec<&str> = value.split('-').collect();
         let parts: Vec<&str> = parts.iter().skip(2).map(|&s| s).collect();
 
@@ -83,7 +132,8 @@ impl TryFrom<&str> for WindowsServer2016 {
                         editions: Editions::all(),
                         release: None,
                         service_channel: ServiceChannel::default(),
-                    })
+                        installation_option: None,
+                    }.installation_option(installation_option))
                 } else if parts.len() == 2 {
                     Ok(WindowsServer2016 {
                         vendor,
@@ -91,7 +141,8 @@ impl TryFrom<&str> for WindowsServer2016 {
                         editions: Editions::all(),
                         release: Some(Release::from(parts[1])),
                         service_channel: ServiceChannel::default(),
-                    })
+                        installation_option: None,
+                    }.installation_option(installation_option))
                 } else {
                     Err(String::from("This is not a Windows Server."))
                 }
@@ -103,7 +154,7 @@ impl TryFrom<&str> for WindowsServer2016 {
 }
 
 #[derive(Clone, Debug)]
-struct Release(String);
+pub(crate) struct Release(String);
 
 impl Release {}
 
@@ -120,10 +171,10 @@ impl std::fmt::Display for Release {
 }
 
 #[derive(Debug)]
-struct Editions(Vec<Edition>);
+pub(crate) struct Editions(pub(crate) Vec<Edition>);
 
 impl Editions {
-    fn all() -> Self {
+    pub(crate) fn all() -> Self {
         Editions(vec![
             Edition::Datacenter,
             Edition::Essentials,
@@ -131,6 +182,13 @@ impl Editions {
         ])
     }
 
+    pub(crate) fn storage_server() -> Self {
+        Editions(vec![
+            Edition::StorageServerStandard,
+            Edition::StorageServerWorkgroup,
+        ])
+    }
+
     #[allow(dead_code)]
     fn contains(&self, edition: Edition) -> bool {
         self.0.contains(&edition)
@@ -143,10 +201,22 @@ impl Editions {
 }
 
 #[derive(PartialEq, Debug)]
-enum Edition {
+pub(crate) enum Edition {
     Datacenter,
     Essentials,
     Standard,
+    StorageServerStandard,
+    StorageServerWorkgroup,
+}
+
+impl Edition {
+    // Essentials and Storage Server come with the Desktop Experience only
+    pub(crate) fn supports(&self, option: &InstallationOption) -> bool {
+        match self {
+            Edition::Essentials | Edition::StorageServerStandard | Edition::StorageServerWorkgroup => option.is_default(),
+            Edition::Datacenter | Edition::Standard => true,
+        }
+    }
 }
 
 impl std::fmt::Display for Edition {
@@ -155,6 +225,8 @@ impl std::fmt::Display for Edition {
             Edition::Datacenter => "Datacenter",
             Edition::Essentials => "Essentials",
             Edition::Standard => "Standard",
+            Edition::StorageServerStandard => "Storage Server Standard",
+            Edition::StorageServerWorkgroup => "Storage Server Workgroup",
         };
 
         write!(f, "{}", out.to_string())
@@ -218,4 +290,48 @@ mod tests {
         assert_eq!(label.editions.len(), Editions::all().len());
         assert!(label.release.is_none());
     }
+
+    #[test]
+    fn test_from_string_nano_server() {
+        let label = WindowsServer2016::try_from("windows-nano-server-2016").unwrap();
+
+        assert_eq!(label.product, "Windows Server 2016".to_string());
+        assert_eq!(label.installation_option, Some(InstallationOption::NanoServer));
+        assert!(label.to_string().contains(&"Microsoft Windows Server 2016 Datacenter Nano Server".to_string()));
+        assert!(!label.editions.contains(Edition::Essentials));
+    }
+
+    #[test]
+    fn test_from_string_arbitrary1() {
+        let label = WindowsServer2016::try_from("Windows Server 2016 Datacenter 14393").unwrap();
+
+        assert!(label.release.is_none());
+        assert_eq!(label.to_string(), vec!["Microsoft Windows Server 2016 Datacenter".to_string()]);
+    }
+
+    #[test]
+    fn test_from_string_arbitrary_core() {
+        let label = WindowsServer2016::try_from("Windows Server 2016 Standard Server Core").unwrap();
+
+        assert_eq!(label.installation_option, Some(InstallationOption::ServerCore));
+        assert_eq!(label.to_string(), vec!["Microsoft Windows Server 2016 Standard Server Core".to_string()]);
+    }
+
+    #[test]
+    fn test_from_string_arbitrary_storage_server() {
+        let label = WindowsServer2016::try_from("Windows Storage Server 2016 Standard").unwrap();
+
+        assert!(label.editions.contains(Edition::StorageServerStandard));
+        assert_eq!(label.to_string(), vec!["Microsoft Windows Server 2016 Storage Server Standard".to_string()]);
+
+        let label = WindowsServer2016::try_from("Windows Storage Server 2016").unwrap();
+
+        assert_eq!(label.editions.len(), Editions::storage_server().len());
+    }
+
+    #[test]
+    fn test_from_string_arbitrary_essentials_core() {
+        assert!(WindowsServer2016::try_from("Windows Server 2016 Essentials Server Core").is_err());
+        assert!(WindowsServer2016::try_from("Windows Storage Server 2016 Workgroup Nano Server").is_err());
+    }
 }
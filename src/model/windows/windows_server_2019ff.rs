@@ -1,6 +1,8 @@
 const PRODUCT: &str = "Windows Server";
 const VENDOR: &str = "Microsoft";
 
+use super::InstallationOption;
+
 /// WindowsServer2019ff covers Windows Server 2019, 2022, and 2025.
 ///
 /// see [Windows Server release information](https://learn.microsoft.com/en-us/windows/release-health/windows-server-release-info)
@@ -12,6 +14,7 @@ pub(crate) struct WindowsServer2019ff {
     editions: Editions,
     #[allow(dead_code)]
     service_channel: ServiceChannel,
+    installation_option: Option<InstallationOption>,
 }
 
 impl WindowsServer2019ff {
@@ -22,6 +25,7 @@ impl WindowsServer2019ff {
             editions: Editions(vec![]),
             release,
             service_channel,
+            installation_option: None,
         }
     }
 
@@ -40,11 +44,44 @@ impl WindowsServer2019ff {
         }
     }
 
+    // Client release the server release was branched from
+    pub(super) fn based_on(&self) -> Option<String> {
+        self.compatible_with().into_iter().next()
+    }
+
+    // Client releases sharing the code base of this release, Windows Server 2022 has none
+    pub(super) fn compatible_with(&self) -> Vec<String> {
+        if self.product.ends_with("2019") {
+            vec!["windows-10-1809-e-lts".to_string()]
+        } else if self.product.ends_with("2025") {
+            vec!["windows-11-24h2-e-lts".to_string()]
+        } else {
+            vec![]
+        }
+    }
+
     pub(crate) fn editions(mut self, editions: Editions) -> WindowsServer2019ff {
         self.editions = editions;
         self
     }
 
+    pub(crate) fn installation_option(mut self, installation_option: Option<InstallationOption>) -> WindowsServer2019ff {
+        if let Some(option) = &installation_option {
+            self.editions.0.retain(|edition| edition.supports(option));
+        }
+        self.installation_option = installation_option;
+        self
+    }
+
+    pub(super) fn installation(&self) -> Option<InstallationOption> {
+        self.installation_option.clone()
+    }
+
+    // Hotpatch ships with Datacenter: Azure Edition, and with Windows Server 2025 through Azure Arc
+    pub(crate) fn supports_hotpatch(&self) -> bool {
+        self.editions.0.contains(&Edition::DatacenterAzure) || self.product.ends_with("2025")
+    }
+
     pub(super) fn is_enterprise(&self) -> bool {
         false
     }
@@ -54,6 +91,8 @@ impl WindowsServer2019ff {
     }
     
     pub(super) fn to_string(&self) -> Vec<String> {
+        let option = InstallationOption::suffix(&self.installation_option);
+
         let out = self
             .editions
             .0
@@ -61,13 +100,13 @@ impl WindowsServer2019ff {
             .map(|edition| {
                 if self.release.is_none() {
                     format!(
-                        "{} {} {edition}",
+                        "{} {} {edition}{option}",
                         self.vendor, self.product
                     )
                 } else {
                     let release = self.release.clone().unwrap();
                     format!(
-                        "{} {} {edition} {release}",
+                        "{} {} {edition} {release}{option}",
                         self.vendor, self.product
                     )
                 }
@@ -133,13 +172,28 @@ impl Editions {
 #[derive(PartialEq, Debug)]
 pub(crate) enum Edition {
     Datacenter,
+    // Windows Server 2022 and later, supports hotpatching
+    DatacenterAzure,
+    Essentials,
     Standard,
 }
 
+impl Edition {
+    // Essentials comes with the Desktop Experience only
+    pub(crate) fn supports(&self, option: &InstallationOption) -> bool {
+        match self {
+            Edition::Essentials => option.is_default(),
+            Edition::Datacenter | Edition::DatacenterAzure | Edition::Standard => true,
+        }
+    }
+}
+
 impl std::fmt::Display for Edition {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let out = match self {
             Edition::Datacenter => "Datacenter",
+            Edition::DatacenterAzure => "Datacenter: Azure Edition",
+            Edition::Essentials => "Essentials",
             Edition::Standard => "Standard",
         };
 
@@ -224,4 +278,73 @@ mod tests {
         assert_eq!(label.editions.len(), Editions::all().len());
         assert!(label.release.is_none());
     }
+
+    #[test]
+    fn test_from_string_core_2022() {
+        let label = WindowsServer2019ff::try_from("windows-server-core-2022").unwrap();
+
+        assert_eq!(label.product, "Windows Server 2022".to_string());
+        assert_eq!(label.installation_option, Some(InstallationOption::ServerCore));
+        assert!(label.to_string().contains(&"Microsoft Windows Server 2022 Datacenter Server Core".to_string()));
+    }
+
+    #[test]
+    fn test_from_string_arbitrary_core() {
+        let label = WindowsServer2019ff::try_from("Windows Server 2022 Datacenter (Server Core)").unwrap();
+
+        assert_eq!(label.product, "Windows Server 2022".to_string());
+        assert_eq!(label.editions.len(), 1);
+        assert_eq!(label.installation_option, Some(InstallationOption::ServerCore));
+
+        // "Core" alone is not an installation option
+        let label = WindowsServer2019ff::try_from("Windows Server 2022 Datacenter Core").unwrap();
+
+        assert!(label.installation_option.is_none());
+        assert!(WindowsServer2019ff::try_from("Windows Server 2022 Essentials Server Core").is_err());
+    }
+
+    #[test]
+    fn test_from_string_arbitrary_desktop_experience() {
+        let label = WindowsServer2019ff::try_from("Windows Server 2019 Standard (Desktop Experience)").unwrap();
+
+        assert_eq!(label.installation_option, Some(InstallationOption::DesktopExperience));
+        assert_eq!(label.to_string(), vec!["Microsoft Windows Server 2019 Standard".to_string()]);
+    }
+
+    #[test]
+    fn test_from_string_arbitrary_azure_edition() {
+        let label = WindowsServer2019ff::try_from("Windows Server 2022 Datacenter: Azure Edition").unwrap();
+
+        assert_eq!(label.editions.len(), 1);
+        assert!(label.editions.contains(Edition::DatacenterAzure));
+        assert_eq!(label.to_string(), vec!["Microsoft Windows Server 2022 Datacenter: Azure Edition".to_string()]);
+    }
+
+    #[test]
+    fn test_from_string_arbitrary_essentials() {
+        let label = WindowsServer2019ff::try_from("Windows Server 2019 Essentials (Build 17763)").unwrap();
+
+        assert!(label.editions.contains(Edition::Essentials));
+    }
+
+    #[test]
+    fn test_from_string_arbitrary_azure_edition_2019() {
+        // Datacenter: Azure Edition starts with Windows Server 2022
+        assert!(WindowsServer2019ff::try_from("Windows Server 2019 Datacenter: Azure Edition").is_err());
+    }
+
+    #[test]
+    fn test_from_string_arbitrary_hotpatch() {
+        let label = WindowsServer2019ff::try_from("Windows Server 2022 Datacenter: Azure Edition Hotpatch").unwrap();
+
+        assert!(label.supports_hotpatch());
+        assert!(!WindowsServer2019ff::try_from("Windows Server 2022 Standard").unwrap().supports_hotpatch());
+        assert!(WindowsServer2019ff::try_from("Windows Server 2019 Datacenter Hotpatch").is_err());
+    }
+
+    #[test]
+    fn test_from_string_arbitrary_storage_server() {
+        // Windows Storage Server 2016 was the last release
+        assert!(WindowsServer2019ff::try_from("Windows Storage Server 2019").is_err());
+    }
 }
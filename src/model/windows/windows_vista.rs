@@ -10,6 +10,20 @@ pub(crate) struct WindowsVista {
 }
 
 impl WindowsVista {
+    pub(crate) fn build(release: Release) -> WindowsVista {
+        WindowsVista {
+            vendor: "Microsoft".to_string(),
+            product: "Windows Vista".to_string(),
+            release,
+            editions: Editions(vec![]),
+        }
+    }
+
+    pub(crate) fn editions(mut self, editions: Editions) -> WindowsVista {
+        self.editions = editions;
+        self
+    }
+
     pub(super) fn vendor(&self) -> &str {
         self.vendor.as_str()
     }
@@ -38,10 +52,17 @@ impl WindowsVista {
             .0
             .iter()
             .map(|edition| {
-                format!(
-                    "{} {} {edition} {}",
-                    self.vendor, self.product, self.release
-                )
+                if self.release == Release::GA {
+                    format!(
+                        "{} {} {edition}",
+                        self.vendor, self.product
+                    )
+                } else {
+                    format!(
+                        "{} {} {edition} {}",
+                        self.vendor, self.product, self.release
+                    )
+                }
             })
             .collect();
 
@@ -53,6 +74,11 @@ impl TryFrom<&str> for WindowsVista {
     type Error = String;
 
     fn try_from(value: &str) -> Result<Self, Self::Error> {
+        if crate::parser::endoflife::EndOfLifeLabel::try_from(value).is_err() {
+            let label = crate::parser::generic::GenericLabel::from(value);
+            return crate::parser::generic::windows::WindowsVistaParser::parse(&label);
+        }
+
         let parts: Vec<&str> = value.split('-').collect();
         let parts: Vec<&str> = parts.iter().skip(1).map(|&s| s).collect();
 
@@ -84,10 +110,9 @@ impl TryFrom<&str> for WindowsVista {
     }
 }
 
-#[derive(Debug)]
-enum Release {
+#[derive(PartialEq, Debug)]
+pub(crate) enum Release {
     // Original Release
-    #[allow(dead_code)]
     GA,
     // Service Pack 1
     SP1,
@@ -100,6 +125,7 @@ impl TryFrom<&str> for Release {
 
     fn try_from(value: &str) -> Result<Self, Self::Error> {
         match value.to_uppercase().as_str() {
+            "GA" => Ok(Release::GA),
             "SP1" => Ok(Release::SP1),
             "SP2" => Ok(Release::SP2),
             _ => Err(String::from("This is not a Windows Vista Release.")),
@@ -120,10 +146,10 @@ impl std::fmt::Display for Release {
 }
 
 #[derive(Debug)]
-struct Editions(Vec<Edition>);
+pub(crate) struct Editions(pub(crate) Vec<Edition>);
 
 impl Editions {
-    fn all() -> Self {
+    pub(crate) fn all() -> Self {
         Editions(vec![
             Edition::Business,
             Edition::BusinessN,
@@ -155,7 +181,7 @@ impl Editions {
 }
 
 #[derive(PartialEq, Debug)]
-enum Edition {
+pub(crate) enum Edition {
     Business,
     BusinessN,
     BusinessN64bit,
@@ -211,4 +237,21 @@ mod tests {
 
         assert_eq!(label.editions.len(), Editions::all().len());
     }
+
+    #[test]
+    fn test_from_string_arbitrary1() {
+        let label = WindowsVista::try_from("Windows Vista Business 64-bit (Build 6002)").unwrap();
+
+        assert_eq!(label.release, Release::SP2);
+        assert_eq!(label.editions.len(), 1);
+        assert!(label.editions.contains(Edition::Business));
+    }
+
+    #[test]
+    fn test_from_string_arbitrary2() {
+        let label = WindowsVista::try_from("Windows Vista Home Premium 64-bit Service Pack 1").unwrap();
+
+        assert_eq!(label.release, Release::SP1);
+        assert!(label.editions.contains(Edition::HomePremium64bit));
+    }
 }
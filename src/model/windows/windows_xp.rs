@@ -10,6 +10,20 @@ pub(crate) struct WindowsXP {
 }
 
 impl WindowsXP {
+    pub(crate) fn build(release: Release) -> WindowsXP {
+        WindowsXP {
+            vendor: "Microsoft".to_string(),
+            product: "Windows XP".to_string(),
+            release,
+            editions: Editions(vec![]),
+        }
+    }
+
+    pub(crate) fn editions(mut self, editions: Editions) -> WindowsXP {
+        self.editions = editions;
+        self
+    }
+
     pub(super) fn vendor(&self) -> &str {
         self.vendor.as_str()
     }
@@ -36,10 +50,17 @@ impl WindowsXP {
             .0
             .iter()
             .map(|edition| {
-                format!(
-                    "{} {} {edition} {}",
-                    self.vendor, self.product, self.release
-                )
+                if self.release == Release::GA {
+                    format!(
+                        "{} {} {edition}",
+                        self.vendor, self.product
+                    )
+                } else {
+                    format!(
+                        "{} {} {edition} {}",
+                        self.vendor, self.product, self.release
+                    )
+                }
             })
             .collect();
 
@@ -51,6 +72,11 @@ impl TryFrom<&str> for WindowsXP {
     type Error = String;
 
     fn try_from(value: &str) -> Result<Self, Self::Error> {
+        if crate::parser::endoflife::EndOfLifeLabel::try_from(value).is_err() {
+            let label = crate::parser::generic::GenericLabel::from(value);
+            return crate::parser::generic::windows::WindowsXPParser::parse(&label);
+        }
+
         let parts: Vec<&str> = value.split('-').collect();
         let parts: Vec<&str> = parts.iter().skip(1).map(|&s| s).collect();
 
@@ -82,10 +108,9 @@ impl TryFrom<&str> for WindowsXP {
     }
 }
 
-#[derive(Debug)]
-enum Release {
+#[derive(PartialEq, Debug)]
+pub(crate) enum Release {
     // Original Release
-    #[allow(dead_code)]
     GA,
     // Service Pack 1
     SP1,
@@ -102,8 +127,9 @@ impl TryFrom<&str> for Release {
 
     fn try_from(value: &str) -> Result<Self, Self::Error> {
         match value.to_uppercase().as_str() {
+            "GA" => Ok(Release::GA),
             "SP1" => Ok(Release::SP1),
-            "SP1a" => Ok(Release::SP1a),
+            "SP1A" => Ok(Release::SP1a),
             "SP2" => Ok(Release::SP2),
             "SP3" => Ok(Release::SP3),
             _ => Err(String::from("This is not a Windows XP Release.")),
@@ -126,10 +152,10 @@ impl std::fmt::Display for Release {
 }
 
 #[derive(Debug)]
-struct Editions(Vec<Edition>);
+pub(crate) struct Editions(pub(crate) Vec<Edition>);
 
 impl Editions {
-    fn all() -> Self {
+    pub(crate) fn all() -> Self {
         Editions(vec![
             Edition::Home,
             Edition::Professional,
@@ -151,7 +177,7 @@ impl Editions {
 }
 
 #[derive(PartialEq, Debug)]
-enum Edition {
+pub(crate) enum Edition {
     Home,
     Professional,
     ProfessionalForEmbeddedSystems,
@@ -187,4 +213,42 @@ mod tests {
 
         assert_eq!(label.editions.len(), Editions::all().len());
     }
+
+    #[test]
+    fn test_from_string_sp1a() {
+        let label = WindowsXP::try_from("windows-5-sp1a").unwrap();
+
+        assert_eq!(label.release, Release::SP1a);
+    }
+
+    #[test]
+    fn test_from_string_arbitrary1() {
+        let label = WindowsXP::try_from("Microsoft Windows XP Professional Version 2002 Service Pack 3 (Build 2600)").unwrap();
+
+        assert_eq!(label.release, Release::SP3);
+        assert_eq!(label.editions.len(), 1);
+        assert!(label.editions.contains(Edition::Professional));
+        assert_eq!(label.to_string(), vec!["Microsoft Windows XP Professional SP3".to_string()]);
+    }
+
+    #[test]
+    fn test_from_string_arbitrary2() {
+        let label = WindowsXP::try_from("Windows XP Home Edition").unwrap();
+
+        assert_eq!(label.release, Release::GA);
+        assert_eq!(label.to_string(), vec!["Microsoft Windows XP Home".to_string()]);
+    }
+
+    #[test]
+    fn test_from_string_arbitrary_build_revision() {
+        let label = WindowsXP::try_from("Microsoft Windows XP Professional 5.1.2600.2180").unwrap();
+
+        assert_eq!(label.release, Release::SP2);
+    }
+
+    #[test]
+    fn test_from_string_arbitrary_embedded() {
+        assert!(WindowsXP::try_from("Windows XP Embedded").is_err());
+        assert!(WindowsXP::try_from("Windows XP Professional Build 7601").is_err());
+    }
 }
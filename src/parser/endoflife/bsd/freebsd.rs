@@ -0,0 +1,38 @@
+use crate::{model, util};
+use crate::model::freebsd::{Branch, Release};
+use super::super::EndOfLifeLabel;
+
+pub(crate) struct FreeBSDParser();
+
+impl FreeBSDParser {
+    pub(crate) fn parse(label: &EndOfLifeLabel) -> Result<model::FreeBSD, String> {
+        if label.starts_with("freebsd-") && label.len() == 2 {
+            if let Some(last) = label.last() {
+                match (util::identify_release(last, r#"[0-9]+"#), util::identify_release(last, r#"[0-9]+\.[0-9]+"#)) {
+                    // Major release only, endoflife.date tracks the stable branch
+                    (Some(major), None) => {
+                        let release = Release::from(major.as_str());
+
+                        let freebsd = model::FreeBSD::build(release, Branch::Stable);
+                        Ok(freebsd)
+                    },
+                    // Point release
+                    (_, Some(minor)) => {
+                        let release = Release::from(minor.as_str());
+
+                        let freebsd = model::FreeBSD::build(release, Branch::Release);
+                        Ok(freebsd)
+                    },
+                    // No valid release provided
+                    (_, _) => {
+                        Err(String::from("Not FreeBSD."))
+                    }
+                }
+            } else {
+                Err(String::from("This is not a FreeBSD."))
+            }
+        } else {
+            Err(String::from("This is not a FreeBSD."))
+        }
+    }
+}
@@ -0,0 +1,18 @@
+use crate::{model, util};
+use crate::model::esxi::Release;
+use super::super::EndOfLifeLabel;
+
+pub(crate) struct VMwareESXiParser();
+
+impl VMwareESXiParser {
+    pub(crate) fn parse(label: &EndOfLifeLabel) -> Result<model::VMwareESXi, String> {
+        if label.starts_with("esxi-") && label.len() == 2 {
+            match label.last().and_then(|last| util::identify_release(last, r#"[0-9]+\.[0-9]+"#)) {
+                Some(release) => Ok(model::VMwareESXi::build(Release::from(release.as_str()))),
+                None => Err(String::from("Not a VMware ESXi release.")),
+            }
+        } else {
+            Err(String::from("This is not a VMware ESXi."))
+        }
+    }
+}
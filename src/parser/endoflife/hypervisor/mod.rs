@@ -0,0 +1,5 @@
+mod esxi;
+pub(crate) use esxi::VMwareESXiParser;
+
+mod proxmox;
+pub(crate) use proxmox::ProxmoxVEParser;
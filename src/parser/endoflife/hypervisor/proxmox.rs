@@ -0,0 +1,18 @@
+use crate::{model, util};
+use crate::model::proxmox::Release;
+use super::super::EndOfLifeLabel;
+
+pub(crate) struct ProxmoxVEParser();
+
+impl ProxmoxVEParser {
+    pub(crate) fn parse(label: &EndOfLifeLabel) -> Result<model::ProxmoxVE, String> {
+        if label.starts_with("proxmox-ve-") && label.len() == 3 {
+            match label.last().and_then(|last| util::identify_release(last, r#"[0-9]+(\.[0-9]+)?"#)) {
+                Some(release) => Ok(model::ProxmoxVE::build(Release::from(release.as_str()))),
+                None => Err(String::from("Not a Proxmox VE release.")),
+            }
+        } else {
+            Err(String::from("This is not a Proxmox VE."))
+        }
+    }
+}
@@ -0,0 +1,40 @@
+use crate::{model, util};
+use crate::model::alpine::{Release, ServiceChannel};
+use super::super::EndOfLifeLabel;
+
+pub(crate) struct AlpineLinuxParser();
+
+impl AlpineLinuxParser {
+    pub(crate) fn parse(label: &EndOfLifeLabel) -> Result<model::AlpineLinux, String> {
+        if label.starts_with("alpine-linux-") || label.starts_with("alpine-") {
+            if let Some(last) = label.last() {
+                match (util::identify_release(last, r#"[0-9]+\.[0-9]+(\.[0-9]+)?"#), last == "edge") {
+                    // Stable branch or patch release
+                    (Some(release), _) => {
+                        let release = Release::from(release.as_str());
+                        let service_channel = ServiceChannel::from(&release);
+
+                        let alpine = model::AlpineLinux::build(release, service_channel);
+                        Ok(alpine)
+                    },
+                    // Rolling development branch
+                    (None, true) => {
+                        let release = Release::from(last);
+                        let service_channel = ServiceChannel::from(&release);
+
+                        let alpine = model::AlpineLinux::build(release, service_channel);
+                        Ok(alpine)
+                    },
+                    // No valid release provided
+                    (None, false) => {
+                        Err(String::from("Not Alpine Linux."))
+                    }
+                }
+            } else {
+                Err(String::from("This is not an Alpine Linux."))
+            }
+        } else {
+            Err(String::from("This is not an Alpine Linux."))
+        }
+    }
+}
@@ -0,0 +1,28 @@
+use crate::{model, util};
+use crate::model::amazon::{Release, ServiceChannel};
+use super::super::EndOfLifeLabel;
+
+pub(crate) struct AmazonLinuxParser();
+
+impl AmazonLinuxParser {
+    pub(crate) fn parse(label: &EndOfLifeLabel) -> Result<model::AmazonLinux, String> {
+        if label.starts_with("amazon-linux-") {
+            if let Some(last) = label.last() {
+                match util::identify_release(last, r#"2023(\.[0-9]+){0,2}|20[0-9]{2}\.[0-9]{2}|2"#) {
+                    Some(release) => {
+                        let release = Release::from(release.as_str());
+                        let service_channel = ServiceChannel::from(&release);
+
+                        let amazon = model::AmazonLinux::build(release, service_channel);
+                        Ok(amazon)
+                    },
+                    None => Err(String::from("Not Amazon Linux.")),
+                }
+            } else {
+                Err(String::from("This is not an Amazon Linux."))
+            }
+        } else {
+            Err(String::from("This is not an Amazon Linux."))
+        }
+    }
+}
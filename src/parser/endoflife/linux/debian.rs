@@ -7,23 +7,25 @@ pub(crate) struct DebianParser();
 impl DebianParser {
     pub(crate) fn parse(label: &EndOfLifeLabel) -> Result<model::Debian, String> {
         if label.starts_with("debian-") {
-            if let Some(last) = label.last() {
+            // The support phase may be appended to the release, e.g. debian-10-elts
+            let (last, phase) = match label.last().map(ServiceChannel::try_from) {
+                Some(Ok(phase)) if label.len() > 2 => (label.get(label.len() - 2), Some(phase)),
+                _ => (label.last(), None),
+            };
+
+            if let Some(last) = last {
                 match (util::identify_release(last, r#"[0-9]+"#), util::identify_release(last, r#"[0-9]+\.[0-9\.]+"#)) {
                     // Major release only
                     (Some(major), None) => {
                         let release = Release::from(major.as_str());
-                        let service_channel = ServiceChannel::from(&release);
 
-                        let debian = model::Debian::build(release, service_channel).editions(Editions::all());
-                        Ok(debian)
+                        build(release, phase)
                     },
                     // Minor release provided, major release does not matter
                     (_, Some(minor)) => {
                         let release = Release::from(minor.as_str());
-                        let service_channel = ServiceChannel::from(&release);
 
-                        let debian = model::Debian::build(release, service_channel).editions(Editions::all());
-                        Ok(debian)
+                        build(release, phase)
                     },
                     // No valid release provided
                     (_, _) => {
@@ -38,3 +40,11 @@ impl DebianParser {
         }
     }
 }
+
+// A support phase must exist for the release, e.g. there is no debian-12-elts
+fn build(release: Release, phase: Option<ServiceChannel>) -> Result<model::Debian, String> {
+    match phase {
+        Some(phase) if !phase.is_offered_for(&release) => Err(String::from("Not a Debian support phase.")),
+        phase => Ok(model::Debian::build(release, phase).editions(Editions::all())),
+    }
+}
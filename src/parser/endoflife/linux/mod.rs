@@ -1,3 +1,9 @@
+mod alpine;
+pub(crate) use alpine::AlpineLinuxParser;
+
+mod amazon;
+pub(crate) use amazon::AmazonLinuxParser;
+
 mod debian;
 pub(crate) use debian::DebianParser;
 
@@ -7,12 +7,18 @@ pub(crate) struct RedHatEnterpriseLinuxParser();
 impl RedHatEnterpriseLinuxParser {
     pub(crate) fn parse(label: &EndOfLifeLabel) -> Result<model::RedHatEnterpriseLinux, String> {
         if label.starts_with("rhel-") {
-            if let Some(last) = label.last() {
+            // Extended update streams are appended to the release, e.g. rhel-8.6-eus
+            let (last, stream) = match label.last().map(ServiceChannel::try_from) {
+                Some(Ok(service_channel)) if label.len() > 2 => (label.get(label.len() - 2), Some(service_channel)),
+                _ => (label.last(), None),
+            };
+
+            if let Some(last) = last {
                 match (util::identify_release(last, r#"[0-9]+"#), util::identify_release(last, r#"[0-9]+\.[0-9]+"#)) {
                     // Major release only
                     (Some(major), None) => {
                         let release = Release::from(major.as_str());
-                        let service_channel = ServiceChannel::from(&release);
+                        let service_channel = stream.unwrap_or_else(|| ServiceChannel::from(&release));
 
                         let rhel = model::RedHatEnterpriseLinux::build(release, service_channel).editions(Editions::all());
                         Ok(rhel)
@@ -20,7 +26,7 @@ impl RedHatEnterpriseLinuxParser {
                     // Minor release provided, major release does not matter
                     (_, Some(minor)) => {
                         let release = Release::from(minor.as_str());
-                        let service_channel = ServiceChannel::from(&release);
+                        let service_channel = stream.unwrap_or_else(|| ServiceChannel::from(&release));
 
                         let rhel = model::RedHatEnterpriseLinux::build(release, service_channel).editions(Editions::all());
                         Ok(rhel)
@@ -1,5 +1,5 @@
 use crate::{model, util};
-use crate::model::ubuntu::{Editions, Release, ServiceChannel};
+use crate::model::ubuntu::{Edition, Editions, Release, ServiceChannel};
 use super::super::EndOfLifeLabel;
 
 pub(crate) struct UbuntuParser();
@@ -7,12 +7,33 @@ pub(crate) struct UbuntuParser();
 impl UbuntuParser {
     pub(crate) fn parse(label: &EndOfLifeLabel) -> Result<model::Ubuntu, String> {
         if label.starts_with("ubuntu-linux-") || label.starts_with("ubuntu-") {
-            if let Some(last) = label.last() {
-                if let Some(release) = util::identify_release(last, r#"[0-9]+\.[0-9]+"#) {
-                    let release = Release::from(release.as_str());
-                    let service_channel = ServiceChannel::from(&release);
+            // Ubuntu Pro variants are appended to the release, e.g. ubuntu-18.04-esm; naming the
+            // product alone, e.g. ubuntu-pro-22.04, does not tell the service channel
+            let (last, pro) = match label.last() {
+                Some(channel @ ("esm" | "legacy")) if label.len() > 2 => (label.get(label.len() - 2), Some(ServiceChannel::from(channel))),
+                last => (last, None),
+            };
 
-                    let ubuntu = model::Ubuntu::build(release, service_channel).editions(Editions::all());
+            if let Some(last) = last {
+                if let Some(release) = util::identify_release(last, r#"[0-9]+\.[0-9]+(?:\.[0-9]+)?|[0-9]+"#) {
+                    // Ubuntu Core is published as ubuntu-core-24
+                    let core = label.get(1) == Some("core");
+                    let release = match Release::from(release.as_str()) {
+                        release if core => release.core(),
+                        release => release,
+                    };
+                    let service_channel = match (ServiceChannel::from(&release), pro) {
+                        (ServiceChannel::LTS, Some(pro)) => pro,
+                        (ServiceChannel::Interim, Some(_)) => return Err(String::from("Not an Ubuntu Pro release.")),
+                        (ServiceChannel::Interim, None) if label.get(1) == Some("pro") => {
+                            return Err(String::from("Not an Ubuntu Pro release."));
+                        },
+                        (service_channel, _) => service_channel,
+                    };
+
+                    let editions = if core { Editions(vec![Edition::Core]) } else { Editions::all() };
+
+                    let ubuntu = model::Ubuntu::build(release, service_channel).editions(editions);
                     Ok(ubuntu)
                 } else {
                     Err(String::from("Not Ubuntu."))
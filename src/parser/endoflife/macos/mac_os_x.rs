@@ -0,0 +1,25 @@
+use crate::{model, util};
+use crate::model::mac_os_x::Release;
+use super::super::EndOfLifeLabel;
+
+const RELEASE_PATTERN: &str = r#"10\.[0-9]+(\.[0-9]+)?"#;
+
+pub(crate) struct MacOSXParser();
+
+impl MacOSXParser {
+    pub(crate) fn parse(label: &EndOfLifeLabel) -> Result<model::MacOSX, String> {
+        if label.starts_with("macos-") && label.len() == 2 {
+            match label.last() {
+                Some(last) if util::identify_release(last, RELEASE_PATTERN).as_deref() == Some(last) => {
+                    let release = Release::from(last);
+
+                    let macos = model::MacOSX::build(release);
+                    Ok(macos)
+                },
+                _ => Err(String::from("This is not a Mac OS X.")),
+            }
+        } else {
+            Err(String::from("This is not a macOS."))
+        }
+    }
+}
@@ -0,0 +1,26 @@
+use crate::{model, util};
+use crate::model::macos_11ff::Release;
+use super::super::EndOfLifeLabel;
+
+const RELEASE_PATTERN: &str = r#"(1[1-9]|2[0-9])(\.[0-9]+){0,2}"#;
+
+pub(crate) struct MacOS11ffParser();
+
+impl MacOS11ffParser {
+    pub(crate) fn parse(label: &EndOfLifeLabel) -> Result<model::MacOS11ff, String> {
+        if label.starts_with("macos-") && label.len() == 2 {
+            match label.last() {
+                // The whole part has to match, otherwise 10.15 would be read as 15
+                Some(last) if util::identify_release(last, RELEASE_PATTERN).as_deref() == Some(last) => {
+                    let release = Release::from(last);
+
+                    let macos = model::MacOS11ff::build(release);
+                    Ok(macos)
+                },
+                _ => Err(String::from("This is not a macOS 11 or later.")),
+            }
+        } else {
+            Err(String::from("This is not a macOS."))
+        }
+    }
+}
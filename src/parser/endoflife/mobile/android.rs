@@ -0,0 +1,35 @@
+use crate::{model, util};
+use crate::model::android::Release;
+use super::super::EndOfLifeLabel;
+
+// Maps the API level to the Android release
+include!(concat!(env!("OUT_DIR"), "/android_api_to_release_map.rs"));
+
+pub(crate) struct AndroidParser();
+
+impl AndroidParser {
+    pub(crate) fn parse(label: &EndOfLifeLabel) -> Result<model::Android, String> {
+        if label.starts_with("android-") && label.len() == 2 {
+            // Releases of the map only, e.g. android-12l or android-6 for 6.0
+            let release = label.last().and_then(|last| {
+                let last = last.to_uppercase();
+
+                [last.clone(), format!("{last}.0")]
+                    .iter()
+                    .find_map(|candidate| util::identify_release(candidate, RELEASE_PATTERN).filter(|release| release == candidate))
+            });
+
+            match release {
+                Some(release) => {
+                    let api_level = util::resolve_release_to_build(&release, BUILD_TO_RELEASE_MAP)
+                        .and_then(|api| api.parse::<u32>().ok());
+
+                    Ok(model::Android::build(Release::from(release.as_str())).api(api_level))
+                },
+                None => Err(String::from("Not an Android release.")),
+            }
+        } else {
+            Err(String::from("This is not an Android."))
+        }
+    }
+}
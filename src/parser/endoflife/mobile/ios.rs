@@ -0,0 +1,28 @@
+use crate::{model, util};
+use crate::model::ios::{Product, Release};
+use super::super::EndOfLifeLabel;
+
+include!(concat!(env!("OUT_DIR"), "/ios_build_to_release_map.rs"));
+
+pub(crate) struct IOSParser();
+
+impl IOSParser {
+    pub(crate) fn parse(label: &EndOfLifeLabel) -> Result<model::IOS, String> {
+        let product = match label.get(0) {
+            Some("ios") => Product::IOS,
+            Some("ipados") => Product::IPadOS,
+            _ => return Err(String::from("This is not an iOS or iPadOS.")),
+        };
+
+        if label.len() == 2 {
+            match label.last().and_then(|last| util::identify_release(last, r#"[0-9]+(\.[0-9]+)?"#)) {
+                Some(release) if util::is_known_release(&release, BUILD_TO_RELEASE_MAP) => {
+                    Ok(model::IOS::build(product, Release::from(release.as_str())))
+                },
+                _ => Err(String::from("Not an iOS or iPadOS release.")),
+            }
+        } else {
+            Err(String::from("This is not an iOS or iPadOS."))
+        }
+    }
+}
@@ -0,0 +1,5 @@
+mod android;
+pub(crate) use android::AndroidParser;
+
+mod ios;
+pub(crate) use ios::IOSParser;
@@ -1,4 +1,8 @@
+pub(crate) mod bsd;
+pub(crate) mod hypervisor;
 pub(crate) mod linux;
+pub(crate) mod macos;
+pub(crate) mod mobile;
 pub(crate) mod windows;
 
 use crate::util;
@@ -4,5 +4,17 @@ pub(crate) use windows_10::Windows10Parser;
 mod windows_11;
 pub(crate) use windows_11::Windows11Parser;
 
+mod windows_embedded_8;
+pub(crate) use windows_embedded_8::WindowsEmbedded8Parser;
+
+mod windows_embedded_compact;
+pub(crate) use windows_embedded_compact::WindowsEmbeddedCompactParser;
+
+mod windows_embedded_posready;
+pub(crate) use windows_embedded_posready::WindowsEmbeddedPOSReadyParser;
+
+mod windows_embedded_standard_7;
+pub(crate) use windows_embedded_standard_7::WindowsEmbeddedStandard7Parser;
+
 mod windows_server_2019ff;
 pub(crate) use windows_server_2019ff::WindowsServer2019ffParser;
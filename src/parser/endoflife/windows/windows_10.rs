@@ -6,7 +6,7 @@ pub(crate) struct Windows10Parser();
 
 impl Windows10Parser {
     pub(crate) fn parse(label: &EndOfLifeLabel) -> Result<model::Windows10, String> {
-        if label.len() < 3 {
+        if !label.starts_with("windows-10-") || label.len() < 3 {
             Err(String::from("This is not a Windows 10."))
         } else {
             if let Some(second) = label.get(1) {
@@ -17,13 +17,23 @@ impl Windows10Parser {
                         let release = Release::from(label.get(2).unwrap());
                         let service_channel = ServiceChannel::try_from(&release).unwrap_or_default();
 
+                        let windows10 = model::Windows10::build(release, service_channel).editions(Editions::all());
+                        Ok(windows10)
+                    } else if let Some(service_channel) = service_channel_suffix(label) {
+                        let release = Release::from(label.get(2).unwrap());
+                        let service_channel = service_channel_of(&release, service_channel)?;
+
                         let windows10 = model::Windows10::build(release, service_channel).editions(Editions::all());
                         Ok(windows10)
                     } else if label.len() == 4 {
                         match label.get(3) {
                             Some("e") => {
                                 let release = Release::from(label.get(2).unwrap());
-                                let service_channel = ServiceChannel::GAC;
+                                let service_channel = match ServiceChannel::try_from(&release) {
+                                    // Enterprise and Education followed the Current Branch for Business
+                                    Ok(ServiceChannel::CB) => ServiceChannel::CBB,
+                                    service_channel => service_channel.unwrap_or_default(),
+                                };
 
                                 let windows10 = model::Windows10::build(release, service_channel).editions(Editions::all_e());
                                 Ok(windows10)
@@ -39,7 +49,7 @@ impl Windows10Parser {
                             },
                             Some("w") => {
                                 let release = Release::from(label.get(2).unwrap());
-                                let service_channel = ServiceChannel::GAC;
+                                let service_channel = ServiceChannel::try_from(&release).unwrap_or_default();
 
                                 let windows10 = model::Windows10::build(release, service_channel)
                                     .editions(Editions::all_w());
@@ -70,6 +80,26 @@ impl Windows10Parser {
     }
 }
 
+// Servicing branch appended to the release, e.g. windows-10-1511-cbb or windows-10-1709-sac-t
+fn service_channel_suffix<'a>(label: &'a EndOfLifeLabel) -> Option<&'a str> {
+    match (label.len(), label.get(3), label.get(4)) {
+        (4, Some(suffix @ ("cb" | "cbb" | "sac")), _) => Some(suffix),
+        (5, Some("sac"), Some("t")) => Some("sac-t"),
+        _ => None,
+    }
+}
+
+// CB and CBB were renamed to SAC-T and SAC with 1703, which in turn became GAC after 1809
+fn service_channel_of(release: &Release, suffix: &str) -> Result<ServiceChannel, String> {
+    match suffix {
+        "cb" if release.up_to_1607() => Ok(ServiceChannel::CB),
+        "cbb" if release.up_to_1607() => Ok(ServiceChannel::CBB),
+        "sac" if matches!(ServiceChannel::try_from(release), Ok(ServiceChannel::SAC)) => Ok(ServiceChannel::SAC),
+        "sac-t" if !release.up_to_1607() && release.up_to_1809() => Ok(ServiceChannel::SACT),
+        _ => Err(String::from("This is not a Windows 10 service channel.")),
+    }
+}
+
 impl From<&str> for Editions {
     fn from(value: &str) -> Self {
         let editions = match value {
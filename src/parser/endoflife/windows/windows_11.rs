@@ -6,7 +6,7 @@ pub(crate) struct Windows11Parser();
 
 impl Windows11Parser {
     pub(crate) fn parse(label: &EndOfLifeLabel) -> Result<model::Windows11, String> {
-        if label.len() < 4 {
+        if !label.starts_with("windows-11-") || label.len() < 4 {
             Err(String::from("This is not a Windows 11."))
         } else {
             if let Some(second) = label.get(1) {
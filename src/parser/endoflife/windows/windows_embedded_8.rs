@@ -0,0 +1,22 @@
+use crate::model;
+use crate::model::windows_embedded_8::{Editions, Release};
+use super::super::EndOfLifeLabel;
+
+pub(crate) struct WindowsEmbedded8Parser();
+
+impl WindowsEmbedded8Parser {
+    pub(crate) fn parse(label: &EndOfLifeLabel) -> Result<model::WindowsEmbedded8, String> {
+        if label.starts_with("windows-embedded-") && label.len() == 4 && label.last() == Some("industry") {
+            match label.get(2) {
+                Some(release) if release == "8" || release == "8.1" => {
+                    let release = Release::from(release);
+
+                    Ok(model::WindowsEmbedded8::build(release).editions(Editions::all()))
+                },
+                _ => Err(String::from("This is not a Windows Embedded Industry release.")),
+            }
+        } else {
+            Err(String::from("This is not a Windows Embedded Industry."))
+        }
+    }
+}
@@ -0,0 +1,19 @@
+use crate::model;
+use super::super::EndOfLifeLabel;
+
+pub(crate) struct WindowsEmbeddedCompactParser();
+
+impl WindowsEmbeddedCompactParser {
+    pub(crate) fn parse(label: &EndOfLifeLabel) -> Result<model::WindowsEmbeddedCompact, String> {
+        if label.starts_with("windows-embedded-compact-") && label.len() == 4 {
+            match label.last() {
+                Some(release) if release == "7" || release == "2013" => {
+                    Ok(model::WindowsEmbeddedCompact::build(release))
+                },
+                _ => Err(String::from("This is not a Windows Embedded Compact release.")),
+            }
+        } else {
+            Err(String::from("This is not a Windows Embedded Compact."))
+        }
+    }
+}
@@ -0,0 +1,19 @@
+use crate::model;
+use super::super::EndOfLifeLabel;
+
+pub(crate) struct WindowsEmbeddedPOSReadyParser();
+
+impl WindowsEmbeddedPOSReadyParser {
+    pub(crate) fn parse(label: &EndOfLifeLabel) -> Result<model::WindowsEmbeddedPOSReady, String> {
+        if label.starts_with("windows-embedded-posready-") && label.len() == 4 {
+            match label.last() {
+                Some(release) if release == "2009" || release == "7" => {
+                    Ok(model::WindowsEmbeddedPOSReady::build(release))
+                },
+                _ => Err(String::from("This is not a Windows Embedded POSReady release.")),
+            }
+        } else {
+            Err(String::from("This is not a Windows Embedded POSReady."))
+        }
+    }
+}
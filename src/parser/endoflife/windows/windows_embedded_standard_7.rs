@@ -0,0 +1,23 @@
+use crate::model;
+use crate::model::windows_embedded_standard_7::Release;
+use super::super::EndOfLifeLabel;
+
+pub(crate) struct WindowsEmbeddedStandard7Parser();
+
+impl WindowsEmbeddedStandard7Parser {
+    pub(crate) fn parse(label: &EndOfLifeLabel) -> Result<model::WindowsEmbeddedStandard7, String> {
+        if label.starts_with("windows-embedded-standard-7") {
+            match label.len() {
+                4 if label.get(3) == Some("7") => Ok(model::WindowsEmbeddedStandard7::build(None)),
+                5 if label.get(3) == Some("7") => {
+                    let release = label.last().map(Release::from);
+
+                    Ok(model::WindowsEmbeddedStandard7::build(release))
+                },
+                _ => Err(String::from("This is not a Windows Embedded Standard 7.")),
+            }
+        } else {
+            Err(String::from("This is not a Windows Embedded Standard 7."))
+        }
+    }
+}
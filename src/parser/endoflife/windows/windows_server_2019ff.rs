@@ -1,4 +1,5 @@
 use crate::{model, util};
+use crate::model::InstallationOption;
 use crate::model::windows_server_2019ff::{Editions, ServiceChannel};
 use super::super::EndOfLifeLabel;
 
@@ -10,14 +11,19 @@ pub(crate) struct WindowsServer2019ffParser();
 
 impl WindowsServer2019ffParser {
     pub(crate) fn parse(label: &EndOfLifeLabel) -> Result<model::WindowsServer2019ff, String> {
-        if label.len() < 3 {
+        let (installation_option, value) = InstallationOption::split_label(label.raw);
+        let label = EndOfLifeLabel::try_from(value.as_str())?;
+
+        if !label.starts_with("windows-server-") || label.len() < 3 {
             Err(String::from("This is not a Windows Server."))
         } else {
             if let Some(third) = label.get(2) {
-                match util::identify_release(third, RELEASE_PATTERN) {
+                // Container images are tagged ltsc2019, ltsc2022, ...
+                match util::identify_release(third.trim_start_matches("ltsc"), RELEASE_PATTERN) {
                     Some(version) => {
                         let windows = model::WindowsServer2019ff::build(&version, None, ServiceChannel::LTSC)
-                            .editions(Editions::all());
+                            .editions(Editions::all())
+                            .installation_option(installation_option);
                         Ok(windows)
                     },
                     None => Err(String::from(ERR_UNKNOWN_RELEASE)),
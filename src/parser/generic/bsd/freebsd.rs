@@ -0,0 +1,54 @@
+use crate::{model, util};
+use crate::model::freebsd::{Branch, Patch, Release};
+use crate::parser::generic::GenericLabel;
+
+const ERR_UNKNOWN_RELEASE: &str = "Not a FreeBSD release.";
+
+pub(crate) struct FreeBSDParser();
+
+impl FreeBSDParser {
+    pub(crate) fn parse(label: &GenericLabel) -> Result<model::FreeBSD, String> {
+        // FreeBSD is the product, not a derivative built on it, e.g. FreeBSD-based pfSense 2.7
+        if label.raw.split_whitespace().next() == Some("FreeBSD") {
+            let release = Release::try_from(label)?;
+            let branch = Branch::from(label);
+
+            let freebsd = model::FreeBSD::build(release, branch);
+
+            // Security patch level as appended by freebsd-update, e.g. 14.1-RELEASE-p5
+            match util::identify_release(label.raw, r#"RELEASE-p[0-9]+"#) {
+                Some(patch) => Ok(freebsd.patch(Patch::from(patch.trim_start_matches("RELEASE-")))),
+                None => Ok(freebsd),
+            }
+        } else {
+            Err(String::from(ERR_UNKNOWN_RELEASE))
+        }
+    }
+}
+
+impl<'a> TryFrom<&GenericLabel<'a>> for Release {
+    type Error = String;
+
+    fn try_from(value: &GenericLabel<'a>) -> Result<Self, Self::Error> {
+        let value = value.raw;
+
+        match util::identify_release(value, r#"[0-9]+(\.[0-9]+)?"#) {
+            Some(release) => Ok(Release::from(release.as_str())),
+            None => Err(format!("{} ({})", String::from(ERR_UNKNOWN_RELEASE), value)),
+        }
+    }
+}
+
+impl<'a> From<&GenericLabel<'a>> for Branch {
+    fn from(value: &GenericLabel<'a>) -> Self {
+        let value = value.raw;
+
+        if util::contains_any_word(value, &["STABLE"]) {
+            Branch::Stable
+        } else if util::contains_any_word(value, &["CURRENT"]) {
+            Branch::Current
+        } else {
+            Branch::Release
+        }
+    }
+}
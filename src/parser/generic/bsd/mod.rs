@@ -0,0 +1,2 @@
+mod freebsd;
+pub(crate) use freebsd::FreeBSDParser;
@@ -0,0 +1,109 @@
+use crate::{model, util};
+use crate::model::esxi::Release;
+use crate::parser::generic::GenericLabel;
+
+const ERR_UNKNOWN_PRODUCT: &str = "Not a VMware ESXi.";
+const ERR_UNKNOWN_RELEASE: &str = "Not a VMware ESXi release.";
+const ERR_UNKNOWN_BUILD: &str = "Not a VMware ESXi build of this release.";
+
+include!(concat!(env!("OUT_DIR"), "/esxi_build_to_release_map.rs"));
+
+pub(crate) struct VMwareESXiParser();
+
+impl VMwareESXiParser {
+    pub(crate) fn parse(label: &GenericLabel) -> Result<model::VMwareESXi, String> {
+        if util::contains_any_word(label.raw, &["ESXi"]) {
+            let release = Release::try_from(label)?;
+            let esxi = model::VMwareESXi::build(release);
+
+            match find_build(label.raw) {
+                Some(build) => Ok(esxi.installed_build(&build)),
+                None => Ok(esxi),
+            }
+        } else {
+            Err(String::from(ERR_UNKNOWN_PRODUCT))
+        }
+    }
+}
+
+// Build number as reported by vmware -v, e.g. build-24022510
+fn find_build(value: &str) -> Option<String> {
+    util::identify_release(value, r#"build-[0-9]+"#)
+        .map(|build| build.trim_start_matches("build-").to_string())
+        .or(util::find_number_with_digits(value, 8))
+        .or(util::find_number_with_digits(value, 7))
+}
+
+// Version following the product name, 8.0.3 being the same as 8.0 U3, 8.0U3 or 8.0 Update 3
+fn identify_version(value: &str) -> Option<String> {
+    let named = util::identify_release(value, r#"ESXi\s+[0-9]+\.[0-9]+(\.[0-9]+)?(\s*U[0-9]+[a-z]?|\s+Update\s+[0-9]+)?"#)?;
+    let named = named.trim_start_matches("ESXi").trim();
+    let version: String = named.chars().take_while(|c| c.is_ascii_digit() || *c == '.').collect();
+    let (major, minor, point) = util::release_components(&version);
+    let update = named[version.len()..]
+        .trim()
+        .trim_start_matches("Update")
+        .trim_start_matches('U')
+        .trim()
+        .trim_end_matches(char::is_alphabetic)
+        .parse::<u32>()
+        .ok()
+        .or(point);
+
+    match (major, minor, update) {
+        (Some(major), Some(minor), Some(update)) if update > 0 => Some(format!("{major}.{minor} U{update}")),
+        (Some(major), Some(minor), _) => Some(format!("{major}.{minor}")),
+        _ => None,
+    }
+}
+
+impl<'a> TryFrom<&GenericLabel<'a>> for Release {
+    type Error = String;
+
+    fn try_from(value: &GenericLabel<'a>) -> Result<Self, Self::Error> {
+        let value = value.raw;
+
+        // The version must be one of the map, e.g. there is no 7.0 U4
+        let version = match identify_version(value) {
+            Some(version) if util::identify_release(&version, RELEASE_PATTERN).as_ref() == Some(&version) => Some(version),
+            Some(_) => return Err(format!("{} ({})", String::from(ERR_UNKNOWN_RELEASE), value)),
+            None => None,
+        };
+
+        // A build newer than the map cannot tell the update level, the version still does
+        let build_release = find_build(value).and_then(|build| util::resolve_build_to_release(&build, BUILD_TO_RELEASE_MAP).ok());
+
+        match (version, build_release) {
+            // The build must belong to the version and may refine it, e.g. 7.0 (build-21930508) is 7.0 U3
+            (Some(version), Some(release)) => {
+                if release == version || release.starts_with(&format!("{version} ")) {
+                    Ok(Release::from(release.as_str()))
+                } else {
+                    Err(format!("{} ({})", String::from(ERR_UNKNOWN_BUILD), value))
+                }
+            },
+            (Some(release), None) | (None, Some(release)) => Ok(Release::from(release.as_str())),
+            (None, None) => Err(format!("{} ({})", String::from(ERR_UNKNOWN_RELEASE), value)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util;
+
+    #[test]
+    fn test_build_to_release_1() {
+        let release = util::resolve_build_to_release("24022510", BUILD_TO_RELEASE_MAP);
+
+        assert_eq!(release, Ok(String::from("8.0 U3")));
+    }
+
+    #[test]
+    fn test_build_to_release_2() {
+        let release = util::resolve_build_to_release("15843807", BUILD_TO_RELEASE_MAP);
+
+        assert_eq!(release, Ok(String::from("7.0")));
+    }
+}
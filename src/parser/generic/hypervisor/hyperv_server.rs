@@ -0,0 +1,55 @@
+use crate::{model, util};
+use crate::model::hyperv_server::Release;
+use crate::parser::generic::GenericLabel;
+
+const ERR_UNKNOWN_PRODUCT: &str = "Not a Hyper-V Server.";
+const ERR_UNKNOWN_RELEASE: &str = "Not a Hyper-V Server release.";
+
+include!(concat!(env!("OUT_DIR"), "/hyperv_server_build_to_release_map.rs"));
+
+pub(crate) struct HyperVServerParser();
+
+impl HyperVServerParser {
+    pub(crate) fn parse(label: &GenericLabel) -> Result<model::HyperVServer, String> {
+        if util::contains_any_word(label.raw, &["Hyper-V Server"]) {
+            let release = Release::try_from(label)?;
+
+            Ok(model::HyperVServer::build(release))
+        } else {
+            Err(String::from(ERR_UNKNOWN_PRODUCT))
+        }
+    }
+}
+
+impl<'a> TryFrom<&GenericLabel<'a>> for Release {
+    type Error = String;
+
+    fn try_from(value: &GenericLabel<'a>) -> Result<Self, Self::Error> {
+        let value = value.raw;
+
+        let release = util::identify_release(value, RELEASE_PATTERN).or_else(|| {
+            util::find_numbers_with_digits(value, 4)
+                .into_iter()
+                .chain(util::find_numbers_with_digits(value, 5))
+                .find_map(|build| util::resolve_build_to_release(&build, BUILD_TO_RELEASE_MAP).ok())
+        });
+
+        match release {
+            Some(release) => Ok(Release::from(release.as_str())),
+            None => Err(format!("{} ({})", String::from(ERR_UNKNOWN_RELEASE), value)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util;
+
+    #[test]
+    fn test_build_to_release_1() {
+        let release = util::resolve_build_to_release("9600", BUILD_TO_RELEASE_MAP);
+
+        assert_eq!(release, Ok(String::from("2012 R2")));
+    }
+}
@@ -0,0 +1,11 @@
+mod esxi;
+pub(crate) use esxi::VMwareESXiParser;
+
+mod hyperv_server;
+pub(crate) use hyperv_server::HyperVServerParser;
+
+mod proxmox;
+pub(crate) use proxmox::ProxmoxVEParser;
+
+mod xenserver;
+pub(crate) use xenserver::XenServerParser;
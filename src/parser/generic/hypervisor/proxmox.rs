@@ -0,0 +1,34 @@
+use crate::{model, util};
+use crate::model::proxmox::Release;
+use crate::parser::generic::GenericLabel;
+
+const ERR_UNKNOWN_PRODUCT: &str = "Not a Proxmox VE.";
+const ERR_UNKNOWN_RELEASE: &str = "Not a Proxmox VE release.";
+
+pub(crate) struct ProxmoxVEParser();
+
+impl ProxmoxVEParser {
+    pub(crate) fn parse(label: &GenericLabel) -> Result<model::ProxmoxVE, String> {
+        // pveversion reports pve-manager/8.2.4/faa83925c9641325 (running kernel: 6.8.12-1-pve)
+        if util::contains_any_word(label.raw, &["Proxmox VE", "Proxmox Virtual Environment", "pve-manager"]) {
+            let release = Release::try_from(label)?;
+
+            Ok(model::ProxmoxVE::build(release))
+        } else {
+            Err(String::from(ERR_UNKNOWN_PRODUCT))
+        }
+    }
+}
+
+impl<'a> TryFrom<&GenericLabel<'a>> for Release {
+    type Error = String;
+
+    fn try_from(value: &GenericLabel<'a>) -> Result<Self, Self::Error> {
+        let value = value.raw;
+
+        match util::identify_release(value, r#"[0-9]+(\.[0-9]+){0,2}"#) {
+            Some(release) => Ok(Release::from(release.as_str())),
+            None => Err(format!("{} ({})", String::from(ERR_UNKNOWN_RELEASE), value)),
+        }
+    }
+}
@@ -0,0 +1,52 @@
+use crate::{model, util};
+use crate::model::xenserver::{Product, Release};
+use crate::parser::generic::GenericLabel;
+
+const ERR_UNKNOWN_PRODUCT: &str = "Not a XenServer.";
+const ERR_UNKNOWN_RELEASE: &str = "Not a XenServer release.";
+
+pub(crate) struct XenServerParser();
+
+impl XenServerParser {
+    pub(crate) fn parse(label: &GenericLabel) -> Result<model::XenServer, String> {
+        let product = Product::try_from(label)?;
+        let release = Release::try_from(label)?;
+
+        let xenserver = model::XenServer::build(product, release);
+
+        // Cumulative update of a Long Term Service Release, e.g. 8.2 CU1
+        match util::identify_release(label.raw, r#"CU[0-9]+"#) {
+            Some(cu) => Ok(xenserver.cumulative_update(&cu)),
+            None => Ok(xenserver),
+        }
+    }
+}
+
+impl<'a> TryFrom<&GenericLabel<'a>> for Product {
+    type Error = String;
+
+    fn try_from(value: &GenericLabel<'a>) -> Result<Self, Self::Error> {
+        let value = value.raw;
+
+        if util::contains_any_word(value, &["Citrix Hypervisor"]) {
+            Ok(Product::CitrixHypervisor)
+        } else if util::contains_any_word(value, &["XenServer"]) {
+            Ok(Product::XenServer)
+        } else {
+            Err(String::from(ERR_UNKNOWN_PRODUCT))
+        }
+    }
+}
+
+impl<'a> TryFrom<&GenericLabel<'a>> for Release {
+    type Error = String;
+
+    fn try_from(value: &GenericLabel<'a>) -> Result<Self, Self::Error> {
+        let value = value.raw;
+
+        match util::identify_release(value, r#"[0-9]+(\.[0-9]+){0,2}"#) {
+            Some(release) => Ok(Release::from(release.as_str())),
+            None => Err(format!("{} ({})", String::from(ERR_UNKNOWN_RELEASE), value)),
+        }
+    }
+}
@@ -0,0 +1,37 @@
+use crate::{model, util};
+use crate::model::alpine::{Release, ServiceChannel};
+use crate::parser::generic::GenericLabel;
+
+const ERR_UNKNOWN_RELEASE: &str = "Not an Alpine Linux release.";
+
+pub(crate) struct AlpineLinuxParser();
+
+impl AlpineLinuxParser {
+    pub(crate) fn parse(label: &GenericLabel) -> Result<model::AlpineLinux, String> {
+        if label.raw.contains("Alpine") {
+            let release = Release::try_from(label)?;
+            let service_channel = ServiceChannel::from(&release);
+
+            let alpine = model::AlpineLinux::build(release, service_channel);
+
+            Ok(alpine)
+        } else {
+            Err(String::from(ERR_UNKNOWN_RELEASE))
+        }
+    }
+}
+
+impl<'a> TryFrom<&GenericLabel<'a>> for Release {
+    type Error = String;
+
+    fn try_from(value: &GenericLabel<'a>) -> Result<Self, Self::Error> {
+        let value = value.raw;
+
+        // Branches are often prefixed by a "v", e.g. "Alpine Linux v3.20"
+        match (util::identify_release(value, r#"v?[0-9]+\.[0-9]+(\.[0-9]+)?"#), util::contains_any_word(value, &["edge"])) {
+            (Some(release), _) => Ok(Release::from(release.trim_start_matches('v'))),
+            (None, true) => Ok(Release::from("edge")),
+            (None, false) => Err(format!("{} ({})", String::from(ERR_UNKNOWN_RELEASE), value)),
+        }
+    }
+}
@@ -0,0 +1,36 @@
+use crate::{model, util};
+use crate::model::amazon::{Release, ServiceChannel};
+use crate::parser::generic::GenericLabel;
+
+const ERR_UNKNOWN_RELEASE: &str = "Not an Amazon Linux release.";
+
+pub(crate) struct AmazonLinuxParser();
+
+impl AmazonLinuxParser {
+    pub(crate) fn parse(label: &GenericLabel) -> Result<model::AmazonLinux, String> {
+        if label.raw.contains("Amazon Linux") {
+            let release = Release::try_from(label)?;
+            let service_channel = ServiceChannel::from(&release);
+
+            let amazon = model::AmazonLinux::build(release, service_channel);
+
+            Ok(amazon)
+        } else {
+            Err(String::from(ERR_UNKNOWN_RELEASE))
+        }
+    }
+}
+
+impl<'a> TryFrom<&GenericLabel<'a>> for Release {
+    type Error = String;
+
+    fn try_from(value: &GenericLabel<'a>) -> Result<Self, Self::Error> {
+        let value = value.raw;
+
+        // AL2023 point releases first, then AMI year.month releases, then AL2
+        match util::identify_release(value, r#"2023(\.[0-9]+){0,2}|20[0-9]{2}\.[0-9]{2}|2"#) {
+            Some(release) => Ok(Release::from(release.as_str())),
+            None => Err(format!("{} ({})", String::from(ERR_UNKNOWN_RELEASE), value)),
+        }
+    }
+}
@@ -0,0 +1,31 @@
+use crate::{model, util};
+use crate::model::arch::Release;
+use crate::parser::generic::GenericLabel;
+
+const ERR_UNKNOWN_RELEASE: &str = "Not an Arch Linux release.";
+
+pub(crate) struct ArchLinuxParser();
+
+impl ArchLinuxParser {
+    pub(crate) fn parse(label: &GenericLabel) -> Result<model::ArchLinux, String> {
+        if util::contains_any_word(label.raw, &["Arch Linux", "archlinux"]) {
+            let release = Release::from(label);
+
+            Ok(model::ArchLinux::build(release))
+        } else {
+            Err(String::from(ERR_UNKNOWN_RELEASE))
+        }
+    }
+}
+
+impl<'a> From<&GenericLabel<'a>> for Release {
+    fn from(value: &GenericLabel<'a>) -> Self {
+        let value = value.raw;
+
+        // Installation media are dated, e.g. archlinux-2024.10.01-x86_64.iso
+        match util::identify_release(value, r#"[0-9]{4}\.[0-9]{2}\.[0-9]{2}"#) {
+            Some(snapshot) => Release::from(snapshot.as_str()),
+            None => Release::rolling(),
+        }
+    }
+}
@@ -3,6 +3,7 @@ use crate::model::debian::{Editions, Release, ServiceChannel};
 use crate::parser::generic::GenericLabel;
 
 const ERR_UNKNOWN_RELEASE: &str = "Not a Debian Linux release.";
+const ERR_UNKNOWN_SERVICE_CHANNEL: &str = "Not a Debian support phase.";
 
 pub(crate) struct DebianParser();
 
@@ -10,7 +11,12 @@ impl DebianParser {
     pub(crate) fn parse(label: &GenericLabel) -> Result<model::Debian, String> {
         if label.raw.contains("Debian") {
             let release = Release::try_from(label)?;
-            let service_channel = ServiceChannel::from(&release);
+            let service_channel = ServiceChannel::try_from(label).ok();
+
+            // A support phase must exist for the release, e.g. there is no Debian 12 ELTS
+            if service_channel.as_ref().is_some_and(|channel| !channel.is_offered_for(&release)) {
+                return Err(format!("{} ({})", String::from(ERR_UNKNOWN_SERVICE_CHANNEL), label.raw));
+            }
 
             let debian = model::Debian::build(release, service_channel).editions(Editions::all());
 
@@ -27,6 +33,13 @@ impl<'a> TryFrom<&GenericLabel<'a>> for Release {
     fn try_from(value: &GenericLabel<'a>) -> Result<Self, Self::Error> {
         let value = value.raw;
 
+        // Testing and unstable report the next release as "trixie/sid" alike
+        if value.contains("/sid") || util::contains_any_word(value, &["testing"]) {
+            return Ok(Release::from("testing"));
+        } else if util::contains_any_word(value, &["unstable", "sid"]) {
+            return Ok(Release::from("unstable"));
+        }
+
         match (util::identify_release(value, r#"[0-9]+"#), util::identify_release(value, r#"[0-9]+\.[0-9\.]+"#)) {
             (Some(major), None) => Ok(Release::from(major.as_str())),
             (_, Some(minor)) => Ok(Release::from(minor.as_str())),
@@ -34,3 +47,19 @@ impl<'a> TryFrom<&GenericLabel<'a>> for Release {
         }
     }
 }
+
+impl<'a> TryFrom<&GenericLabel<'a>> for ServiceChannel {
+    type Error = String;
+
+    fn try_from(value: &GenericLabel<'a>) -> Result<Self, Self::Error> {
+        let value = value.raw;
+
+        if util::contains_any_word(value, &["Extended LTS", "ELTS"]) {
+            Ok(ServiceChannel::ELTS)
+        } else if util::contains_any_word(value, &["Long Term Support", "LTS"]) {
+            Ok(ServiceChannel::LTS)
+        } else {
+            Err(String::from(ERR_UNKNOWN_SERVICE_CHANNEL))
+        }
+    }
+}
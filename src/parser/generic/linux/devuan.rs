@@ -0,0 +1,32 @@
+use crate::{model, util};
+use crate::model::devuan::Release;
+use crate::parser::generic::GenericLabel;
+
+const ERR_UNKNOWN_RELEASE: &str = "Not a Devuan release.";
+
+pub(crate) struct DevuanParser();
+
+impl DevuanParser {
+    pub(crate) fn parse(label: &GenericLabel) -> Result<model::Devuan, String> {
+        if util::contains_any_word(label.raw, &["Devuan"]) {
+            let release = Release::try_from(label)?;
+
+            Ok(model::Devuan::build(release))
+        } else {
+            Err(String::from(ERR_UNKNOWN_RELEASE))
+        }
+    }
+}
+
+impl<'a> TryFrom<&GenericLabel<'a>> for Release {
+    type Error = String;
+
+    fn try_from(value: &GenericLabel<'a>) -> Result<Self, Self::Error> {
+        let value = value.raw;
+
+        match util::identify_release(value, r#"[0-9]+(?:\.[0-9]+)?"#) {
+            Some(release) => Ok(Release::from(release.as_str())),
+            None => Err(String::from(ERR_UNKNOWN_RELEASE)),
+        }
+    }
+}
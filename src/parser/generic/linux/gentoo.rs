@@ -0,0 +1,31 @@
+use crate::{model, util};
+use crate::model::gentoo::Release;
+use crate::parser::generic::GenericLabel;
+
+const ERR_UNKNOWN_RELEASE: &str = "Not a Gentoo release.";
+
+pub(crate) struct GentooParser();
+
+impl GentooParser {
+    pub(crate) fn parse(label: &GenericLabel) -> Result<model::Gentoo, String> {
+        if util::contains_any_word(label.raw, &["Gentoo", "gentoo"]) {
+            let release = Release::from(label);
+
+            Ok(model::Gentoo::build(release))
+        } else {
+            Err(String::from(ERR_UNKNOWN_RELEASE))
+        }
+    }
+}
+
+impl<'a> From<&GenericLabel<'a>> for Release {
+    fn from(value: &GenericLabel<'a>) -> Self {
+        let value = value.raw;
+
+        // Stage3 tarballs are dated, e.g. stage3-amd64-openrc-20241013T170333Z
+        match util::identify_release(value, r#"[0-9]{8}(?:T[0-9]{6}Z)?"#) {
+            Some(snapshot) => Release::from(&snapshot[..8]),
+            None => Release::rolling(),
+        }
+    }
+}
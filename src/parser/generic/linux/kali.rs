@@ -0,0 +1,31 @@
+use crate::{model, util};
+use crate::model::kali::Release;
+use crate::parser::generic::GenericLabel;
+
+const ERR_UNKNOWN_RELEASE: &str = "Not a Kali Linux release.";
+
+pub(crate) struct KaliLinuxParser();
+
+impl KaliLinuxParser {
+    pub(crate) fn parse(label: &GenericLabel) -> Result<model::KaliLinux, String> {
+        if util::contains_any_word(label.raw, &["Kali"]) {
+            let release = Release::from(label);
+
+            Ok(model::KaliLinux::build(release))
+        } else {
+            Err(String::from(ERR_UNKNOWN_RELEASE))
+        }
+    }
+}
+
+impl<'a> From<&GenericLabel<'a>> for Release {
+    fn from(value: &GenericLabel<'a>) -> Self {
+        let value = value.raw;
+
+        // Quarterly snapshots are named after year and quarter, e.g. 2024.3
+        match util::identify_release(value, r#"[0-9]{4}\.[1-4]"#) {
+            Some(snapshot) => Release::from(snapshot.as_str()),
+            None => Release::rolling(),
+        }
+    }
+}
@@ -0,0 +1,58 @@
+use crate::{model, util};
+use crate::model::mint::{Edition, Editions, Release};
+use crate::parser::generic::GenericLabel;
+
+const ERR_UNKNOWN_RELEASE: &str = "Not a Linux Mint release.";
+const ERR_UNKNOWN_EDITION: &str = "Not a Linux Mint edition.";
+
+pub(crate) struct LinuxMintParser();
+
+impl LinuxMintParser {
+    pub(crate) fn parse(label: &GenericLabel) -> Result<model::LinuxMint, String> {
+        if util::contains_any_word(label.raw, &["Linux Mint", "LMDE"]) {
+            let release = Release::try_from(label)?;
+
+            let editions = match Edition::try_from(label) {
+                Ok(edition) => Editions(vec![edition]),
+                Err(_) => Editions::all(),
+            };
+
+            Ok(model::LinuxMint::build(release).editions(editions))
+        } else {
+            Err(String::from(ERR_UNKNOWN_RELEASE))
+        }
+    }
+}
+
+impl<'a> TryFrom<&GenericLabel<'a>> for Release {
+    type Error = String;
+
+    fn try_from(value: &GenericLabel<'a>) -> Result<Self, Self::Error> {
+        let value = value.raw;
+
+        match util::identify_release(value, r#"[0-9]+(?:\.[0-9]+)?"#) {
+            Some(release) => Ok(Release::from(release.as_str())),
+            None => Err(String::from(ERR_UNKNOWN_RELEASE)),
+        }
+    }
+}
+
+impl<'a> TryFrom<&GenericLabel<'a>> for Edition {
+    type Error = String;
+
+    fn try_from(value: &GenericLabel<'a>) -> Result<Self, Self::Error> {
+        let value = value.raw;
+
+        if util::contains_any_word(value, &["Linux Mint Debian Edition", "LMDE"]) {
+            Ok(Edition::LMDE)
+        } else if util::contains_any_word(value, &["Cinnamon"]) {
+            Ok(Edition::Cinnamon)
+        } else if util::contains_any_word(value, &["MATE"]) {
+            Ok(Edition::MATE)
+        } else if util::contains_any_word(value, &["Xfce", "XFCE"]) {
+            Ok(Edition::Xfce)
+        } else {
+            Err(String::from(ERR_UNKNOWN_EDITION))
+        }
+    }
+}
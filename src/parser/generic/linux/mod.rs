@@ -1,9 +1,33 @@
+mod alpine;
+pub(crate) use alpine::AlpineLinuxParser;
+
+mod amazon;
+pub(crate) use amazon::AmazonLinuxParser;
+
+mod arch;
+pub(crate) use arch::ArchLinuxParser;
+
 mod debian;
 pub(crate) use debian::DebianParser;
 
+mod devuan;
+pub(crate) use devuan::DevuanParser;
+
+mod gentoo;
+pub(crate) use gentoo::GentooParser;
+
+mod kali;
+pub(crate) use kali::KaliLinuxParser;
+
+mod mint;
+pub(crate) use mint::LinuxMintParser;
+
 mod oracle;
 pub(crate) use oracle::OracleLinuxParser;
 
+mod raspberry_pi_os;
+pub(crate) use raspberry_pi_os::RaspberryPiOSParser;
+
 mod rhel;
 pub(crate) use rhel::RedHatEnterpriseLinuxParser;
 
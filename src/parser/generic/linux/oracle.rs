@@ -1,8 +1,14 @@
 use crate::{model, util};
-use crate::model::oracle::{Editions, Release, ServiceChannel};
+use crate::model::oracle::{Editions, Kernel, Release, ServiceChannel};
 use crate::parser::generic::GenericLabel;
 
 const ERR_UNKNOWN_RELEASE: &str = "Not an Oracle Linux release.";
+const ERR_UNKNOWN_KERNEL: &str = "Not an Oracle Linux kernel.";
+
+// Kernel version as reported by uname -r, e.g. 5.15.0-206.153.7.el9uek.x86_64
+const KERNEL_PATTERN: &str = r#"[0-9]+\.[0-9]+\.[0-9]+-[0-9A-Za-z_\.]+"#;
+
+include!(concat!(env!("OUT_DIR"), "/oracle_uek_kernel_to_release_map.rs"));
 
 pub(crate) struct OracleLinuxParser();
 
@@ -12,7 +18,11 @@ impl OracleLinuxParser {
             let release = Release::try_from(label)?;
             let service_channel = ServiceChannel::from(&release);
 
-            let linux = model::OracleLinux::build(release, service_channel).editions(Editions::all());
+            let kernel = Kernel::try_from(label).ok();
+
+            let linux = model::OracleLinux::build(release, service_channel)
+                .editions(Editions::all())
+                .kernel(kernel);
 
             Ok(linux)
         } else {
@@ -24,12 +34,64 @@ impl OracleLinuxParser {
 impl<'a> TryFrom<&GenericLabel<'a>> for Release {
     type Error = String;
 
+    fn try_from(value: &GenericLabel<'a>) -> Result<Self, Self::Error> {
+        // The kernel version must not be taken for the release, e.g. 5.15 of Oracle Linux 9
+        let value = util::remove_release(value.raw, KERNEL_PATTERN);
+        let value = value.as_str();
+
+        match (util::identify_release(value, r#"[0-9]+"#), util::identify_release(value, r#"[0-9]+\.[0-9]+"#)) {
+            (Some(major), None) => Ok(Release::from(major.as_str())),
+            (_, Some(minor)) => Ok(Release::from(minor.as_str())),
+            (_, _) => Err(String::from(ERR_UNKNOWN_RELEASE)),
+        }
+    }
+}
+
+impl<'a> TryFrom<&GenericLabel<'a>> for Kernel {
+    type Error = String;
+
     fn try_from(value: &GenericLabel<'a>) -> Result<Self, Self::Error> {
         let value = value.raw;
 
-        match util::identify_release(value, r#"[0-9]+\.[0-9]+"#) {
-            Some(release) => Ok(Release::from(release.as_str())),
-            None => Err(String::from(ERR_UNKNOWN_RELEASE)),
+        let kernel = util::identify_release(value, KERNEL_PATTERN);
+
+        if util::contains_any_word(value, &["Unbreakable Enterprise Kernel", "UEK"])
+            || kernel.as_ref().is_some_and(|kernel| kernel.contains("uek"))
+        {
+            // UEK release named in the label, e.g. UEK R7, otherwise resolved from the kernel version
+            let release = match util::identify_release(value, RELEASE_PATTERN) {
+                Some(release) => Some(release),
+                None => kernel.and_then(|kernel| {
+                    let (major, minor, point) = util::release_components(kernel.split('-').next().unwrap_or(""));
+                    let candidates = [
+                        format!("{}.{}.{}", major?, minor?, point.unwrap_or(0)),
+                        format!("{}.{}", major?, minor?),
+                    ];
+
+                    candidates.iter().find_map(|candidate| util::resolve_build_to_release(candidate, BUILD_TO_RELEASE_MAP).ok())
+                }),
+            };
+
+            Ok(Kernel::UEK(release))
+        // Only an Enterprise Linux kernel, e.g. 4.18.0-553.el8_10.x86_64, is the Red Hat Compatible Kernel
+        } else if util::contains_any_word(value, &["Red Hat Compatible Kernel", "RHCK"])
+            || kernel.is_some_and(|kernel| util::identify_release(&kernel, r#"el[0-9]+(_[0-9]+)?"#).is_some())
+        {
+            Ok(Kernel::RHCK)
+        } else {
+            Err(String::from(ERR_UNKNOWN_KERNEL))
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util;
+
+    #[test]
+    fn test_kernel_to_uek_release() {
+        assert_eq!(util::resolve_build_to_release("5.15", BUILD_TO_RELEASE_MAP), Ok(String::from("R7")));
+        assert_eq!(util::resolve_build_to_release("2.6.39", BUILD_TO_RELEASE_MAP), Ok(String::from("R2")));
+    }
+}
@@ -0,0 +1,50 @@
+use crate::{model, util};
+use crate::model::raspberry_pi_os::Release;
+use crate::parser::generic::GenericLabel;
+
+const ERR_UNKNOWN_RELEASE: &str = "Not a Raspberry Pi OS release.";
+
+// Debian codenames, Raspberry Pi OS is often named after them only
+const CODENAMES: [(&str, &str); 7] = [
+    ("wheezy", "7"),
+    ("jessie", "8"),
+    ("stretch", "9"),
+    ("buster", "10"),
+    ("bullseye", "11"),
+    ("bookworm", "12"),
+    ("trixie", "13"),
+];
+
+pub(crate) struct RaspberryPiOSParser();
+
+impl RaspberryPiOSParser {
+    pub(crate) fn parse(label: &GenericLabel) -> Result<model::RaspberryPiOS, String> {
+        if util::contains_any_word(label.raw, &["Raspberry Pi OS", "Raspbian"]) {
+            let release = Release::try_from(label)?;
+
+            Ok(model::RaspberryPiOS::build(release))
+        } else {
+            Err(String::from(ERR_UNKNOWN_RELEASE))
+        }
+    }
+}
+
+impl<'a> TryFrom<&GenericLabel<'a>> for Release {
+    type Error = String;
+
+    fn try_from(value: &GenericLabel<'a>) -> Result<Self, Self::Error> {
+        let value = value.raw;
+
+        // Skip the architecture, e.g. "Raspberry Pi OS (64-bit)"
+        let major = util::identify_release(&value.replace("64-bit", "").replace("32-bit", ""), r#"[0-9]+"#);
+
+        match major {
+            Some(major) => Ok(Release::from(major.as_str())),
+            None => CODENAMES
+                .iter()
+                .find(|(codename, _)| util::contains_any_word(value, &[codename]))
+                .map(|(_, major)| Release::from(*major))
+                .ok_or(String::from(ERR_UNKNOWN_RELEASE)),
+        }
+    }
+}
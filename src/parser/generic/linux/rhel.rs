@@ -1,18 +1,32 @@
 use crate::{model, util};
-use crate::model::rhel::{Editions, Release, ServiceChannel};
+use crate::model::rhel::{Edition, Editions, Release, ServiceChannel};
 use crate::parser::generic::GenericLabel;
 
 const ERR_UNKNOWN_RELEASE: &str = "Not an RHEL release.";
+const ERR_UNKNOWN_EDITION: &str = "Not an RHEL variant.";
+const ERR_UNKNOWN_SERVICE_CHANNEL: &str = "Not an RHEL update stream.";
 
 pub(crate) struct RedHatEnterpriseLinuxParser();
 
 impl RedHatEnterpriseLinuxParser {
     pub(crate) fn parse(label: &GenericLabel) -> Result<model::RedHatEnterpriseLinux, String> {
-        if label.raw.contains("Red Hat") || label.raw.contains("RHEL") {
-            let release = Release::try_from(label)?;
-            let service_channel = ServiceChannel::from(&release);
+        if label.raw.contains("Red Hat") || util::contains_any_word(label.raw, &["RHEL", "RHCOS"]) {
+            let edition = Edition::try_from(label).ok();
 
-            let rhel = model::RedHatEnterpriseLinux::build(release, service_channel).editions(Editions::all());
+            // RHCOS builds start with the OpenShift release, e.g. 414.92.202310170514-0 for 4.14
+            let release = match (&edition, util::find_number_with_digits(label.raw, 3)) {
+                (Some(Edition::CoreOS), Some(build)) => Release::from(format!("{}.{}", &build[..1], &build[1..]).as_str()),
+                _ => Release::try_from(label)?,
+            };
+            let service_channel = ServiceChannel::try_from(label)
+                .unwrap_or_else(|_| ServiceChannel::from(&release));
+
+            let editions = match edition {
+                Some(edition) => Editions(vec![edition]),
+                None => Editions::all(),
+            };
+
+            let rhel = model::RedHatEnterpriseLinux::build(release, service_channel).editions(editions);
 
             Ok(rhel)
         } else {
@@ -27,9 +41,54 @@ impl<'a> TryFrom<&GenericLabel<'a>> for Release {
     fn try_from(value: &GenericLabel<'a>) -> Result<Self, Self::Error> {
         let value = value.raw;
 
-        match util::identify_release(value, r#"[0-9]+\.[0-9]+"#) {
-            Some(release) => Ok(Release::from(release.as_str())),
-            None => Err(String::from(ERR_UNKNOWN_RELEASE)),
+        match (util::identify_release(value, r#"[0-9]+"#), util::identify_release(value, r#"[0-9]+\.[0-9]+"#)) {
+            (Some(major), None) => Ok(Release::from(major.as_str())),
+            (_, Some(minor)) => Ok(Release::from(minor.as_str())),
+            (_, _) => Err(String::from(ERR_UNKNOWN_RELEASE)),
+        }
+    }
+}
+
+impl<'a> TryFrom<&GenericLabel<'a>> for Edition {
+    type Error = String;
+
+    fn try_from(value: &GenericLabel<'a>) -> Result<Self, Self::Error> {
+        let value = value.raw;
+
+        if util::contains_any_word(value, &["CoreOS", "RHCOS"]) {
+            Ok(Edition::CoreOS)
+        } else if util::contains_any_word(value, &["for SAP Solutions", "for SAP"]) {
+            Ok(Edition::SAP)
+        } else if util::contains_any_word(value, &["for Real Time", "Real Time"]) {
+            Ok(Edition::RealTime)
+        } else if util::contains_any_word(value, &["Workstation"]) {
+            Ok(Edition::Workstation)
+        } else if util::contains_any_word(value, &["Desktop", "Client"]) {
+            Ok(Edition::Desktop)
+        } else if util::contains_any_word(value, &["Server"]) {
+            Ok(Edition::Server)
+        } else {
+            Err(String::from(ERR_UNKNOWN_EDITION))
+        }
+    }
+}
+
+impl<'a> TryFrom<&GenericLabel<'a>> for ServiceChannel {
+    type Error = String;
+
+    fn try_from(value: &GenericLabel<'a>) -> Result<Self, Self::Error> {
+        let value = value.raw;
+
+        if util::contains_any_word(value, &["Update Services for SAP Solutions", "E4S"]) {
+            Ok(ServiceChannel::E4S)
+        } else if util::contains_any_word(value, &["Extended Update Support", "EUS"]) {
+            Ok(ServiceChannel::EUS)
+        } else if util::contains_any_word(value, &["Advanced Update Support", "AUS"]) {
+            Ok(ServiceChannel::AUS)
+        } else if util::contains_any_word(value, &["Extended Life Cycle Support", "Extended Lifecycle Support", "ELS"]) {
+            Ok(ServiceChannel::ELS)
+        } else {
+            Err(String::from(ERR_UNKNOWN_SERVICE_CHANNEL))
         }
     }
 }
@@ -1,18 +1,43 @@
 use crate::{model, util};
-use crate::model::ubuntu::{Editions, Release, ServiceChannel};
+use crate::model::ubuntu::{Edition, Editions, Release, ServiceChannel};
 use crate::parser::generic::GenericLabel;
 
 const ERR_UNKNOWN_RELEASE: &str = "Not an Ubuntu release.";
+const ERR_UNKNOWN_EDITION: &str = "Not an Ubuntu edition or flavour.";
+const ERR_UNKNOWN_SERVICE_CHANNEL: &str = "Not an Ubuntu Pro service channel.";
 
 pub(crate) struct UbuntuParser();
 
 impl UbuntuParser {
     pub(crate) fn parse(label: &GenericLabel) -> Result<model::Ubuntu, String> {
-        if label.raw.contains("Ubuntu") {
-            let release = Release::try_from(label)?;
-            let service_channel = ServiceChannel::from(&release);
+        if label.raw.contains("Ubuntu") || util::contains_any_word(label.raw, &["Kubuntu", "Lubuntu", "Xubuntu"]) {
+            let edition = Edition::try_from(label).ok();
 
-            let ubuntu = model::Ubuntu::build(release, service_channel).editions(Editions::all());
+            let release = match (Release::try_from(label)?, &edition) {
+                (release, Some(Edition::Core)) => release.core(),
+                (release, _) => release,
+            };
+
+            // Ubuntu Pro covers LTS releases only. The label must name ESM or Legacy Support,
+            // "Ubuntu Pro" alone stays LTS until service_channel_at() is asked about a date
+            let pro = util::contains_any_word(label.raw, &["Pro"]);
+            let service_channel = match (ServiceChannel::from(&release), ServiceChannel::try_from(label)) {
+                (ServiceChannel::LTS, Ok(service_channel)) => service_channel,
+                (ServiceChannel::Interim, Ok(_)) => return Err(String::from(ERR_UNKNOWN_SERVICE_CHANNEL)),
+                (ServiceChannel::Interim, Err(_)) if pro => return Err(String::from(ERR_UNKNOWN_SERVICE_CHANNEL)),
+                (service_channel, _) => service_channel,
+            };
+
+            let editions = match edition {
+                Some(edition) => Editions(vec![edition]),
+                None => Editions::all(),
+            };
+
+            let development = util::contains_any_word(label.raw, &["development branch"]);
+
+            let ubuntu = model::Ubuntu::build(release, service_channel)
+                .editions(editions)
+                .development(development);
 
             Ok(ubuntu)
         } else {
@@ -27,9 +52,55 @@ impl<'a> TryFrom<&GenericLabel<'a>> for Release {
     fn try_from(value: &GenericLabel<'a>) -> Result<Self, Self::Error> {
         let value = value.raw;
 
-        match util::identify_release(value, r#"[0-9]+\.[0-9]+"#) {
-            Some(release) => Ok(Release::from(release.as_str())),
-            None => Err(String::from(ERR_UNKNOWN_RELEASE)),
+        match (util::identify_release(value, r#"[0-9]+"#), util::identify_release(value, r#"[0-9]+\.[0-9]+(?:\.[0-9]+)?"#)) {
+            (Some(major), None) => Ok(Release::from(major.as_str())),
+            (_, Some(minor)) => Ok(Release::from(minor.as_str())),
+            (None, None) if util::contains_any_word(value, &["devel"]) => Ok(Release::from("devel")),
+            (_, _) => Err(String::from(ERR_UNKNOWN_RELEASE)),
+        }
+    }
+}
+
+impl<'a> TryFrom<&GenericLabel<'a>> for Edition {
+    type Error = String;
+
+    fn try_from(value: &GenericLabel<'a>) -> Result<Self, Self::Error> {
+        let value = value.raw;
+
+        if util::contains_any_word(value, &["Ubuntu Studio"]) {
+            Ok(Edition::Studio)
+        } else if util::contains_any_word(value, &["Ubuntu MATE"]) {
+            Ok(Edition::MATE)
+        } else if util::contains_any_word(value, &["Kubuntu"]) {
+            Ok(Edition::Kubuntu)
+        } else if util::contains_any_word(value, &["Lubuntu"]) {
+            Ok(Edition::Lubuntu)
+        } else if util::contains_any_word(value, &["Xubuntu"]) {
+            Ok(Edition::Xubuntu)
+        } else if util::contains_any_word(value, &["Ubuntu Core"]) {
+            Ok(Edition::Core)
+        } else if util::contains_any_word(value, &["Server"]) {
+            Ok(Edition::Server)
+        } else if util::contains_any_word(value, &["Desktop"]) {
+            Ok(Edition::Desktop)
+        } else {
+            Err(String::from(ERR_UNKNOWN_EDITION))
+        }
+    }
+}
+
+impl<'a> TryFrom<&GenericLabel<'a>> for ServiceChannel {
+    type Error = String;
+
+    fn try_from(value: &GenericLabel<'a>) -> Result<Self, Self::Error> {
+        let value = value.raw;
+
+        if util::contains_any_word(value, &["Legacy Support", "Legacy"]) {
+            Ok(ServiceChannel::Legacy)
+        } else if util::contains_any_word(value, &["Expanded Security Maintenance", "ESM"]) {
+            Ok(ServiceChannel::ESM)
+        } else {
+            Err(String::from(ERR_UNKNOWN_SERVICE_CHANNEL))
         }
     }
 }
@@ -0,0 +1,52 @@
+use crate::{model, util};
+use crate::model::mac_os_x::{Release, CODENAMES};
+use crate::parser::generic::GenericLabel;
+
+const ERR_UNKNOWN_RELEASE: &str = "Not a Mac OS X release.";
+
+// Maps the Darwin kernel major version to the Mac OS X release
+include!(concat!(env!("OUT_DIR"), "/mac_os_x_darwin_to_release_map.rs"));
+
+pub(crate) struct MacOSXParser();
+
+impl MacOSXParser {
+    pub(crate) fn parse(label: &GenericLabel) -> Result<model::MacOSX, String> {
+        let release = Release::try_from(label)?;
+
+        let macos = model::MacOSX::build(release);
+
+        Ok(macos)
+    }
+}
+
+impl<'a> TryFrom<&GenericLabel<'a>> for Release {
+    type Error = String;
+
+    fn try_from(value: &GenericLabel<'a>) -> Result<Self, Self::Error> {
+        let value = value.raw;
+
+        // Kernel version as reported by uname, e.g. "Darwin Kernel Version 19.6.0"
+        if let Some(darwin) = util::find_number_after(value, "Darwin Kernel Version|Darwin") {
+            return util::resolve_build_to_release(darwin.as_str(), BUILD_TO_RELEASE_MAP)
+                .map(|release| Release::from(release.as_str()));
+        }
+
+        if !util::contains_any_word(value, &["macOS", "Mac OS X", "OS X", "Mac OS", "MacOS"]) {
+            return Err(format!("{} ({})", String::from(ERR_UNKNOWN_RELEASE), value));
+        }
+
+        // Prefer an explicit version, then the longest matching codename,
+        // so "Snow Leopard" is not mistaken for "Leopard"
+        if let Some(release) = util::identify_release(value, &format!(r#"({})(\.[0-9]+)?"#, RELEASE_PATTERN)) {
+            Ok(Release::from(release.as_str()))
+        } else if let Some((release, _)) = CODENAMES
+            .iter()
+            .filter(|(_, codename)| util::contains_any_word(value, &[codename]))
+            .max_by_key(|(_, codename)| codename.len())
+        {
+            Ok(Release::from(*release))
+        } else {
+            Err(format!("{} ({})", String::from(ERR_UNKNOWN_RELEASE), value))
+        }
+    }
+}
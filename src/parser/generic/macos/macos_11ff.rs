@@ -0,0 +1,55 @@
+use crate::{model, util};
+use crate::model::macos_11ff::{Release, CODENAMES};
+use crate::parser::generic::GenericLabel;
+
+const ERR_UNKNOWN_RELEASE: &str = "Not a macOS 11 or later release.";
+
+// Maps the Darwin kernel major version to the macOS release
+include!(concat!(env!("OUT_DIR"), "/macos_11ff_darwin_to_release_map.rs"));
+
+pub(crate) struct MacOS11ffParser();
+
+impl MacOS11ffParser {
+    pub(crate) fn parse(label: &GenericLabel) -> Result<model::MacOS11ff, String> {
+        let release = Release::try_from(label)?;
+
+        let macos = model::MacOS11ff::build(release);
+
+        Ok(macos)
+    }
+}
+
+impl<'a> TryFrom<&GenericLabel<'a>> for Release {
+    type Error = String;
+
+    fn try_from(value: &GenericLabel<'a>) -> Result<Self, Self::Error> {
+        let value = value.raw;
+
+        // 10.x releases are handled by MacOSX
+        if util::identify_release(value, r#"10\.[0-9]+"#).is_some() {
+            return Err(format!("{} ({})", String::from(ERR_UNKNOWN_RELEASE), value));
+        }
+
+        // Kernel version as reported by uname, e.g. "Darwin Kernel Version 23.6.0"
+        if let Some(darwin) = util::find_number_after(value, "Darwin Kernel Version|Darwin") {
+            return util::resolve_build_to_release(darwin.as_str(), BUILD_TO_RELEASE_MAP)
+                .map(|release| Release::from(release.as_str()));
+        }
+
+        if !util::contains_any_word(value, &["macOS", "Mac OS X", "OS X", "Mac OS", "MacOS"]) {
+            return Err(format!("{} ({})", String::from(ERR_UNKNOWN_RELEASE), value));
+        }
+
+        if let Some(release) = util::identify_release(value, &format!(r#"({})(\.[0-9]+){{0,2}}"#, RELEASE_PATTERN)) {
+            Ok(Release::from(release.as_str()))
+        } else if let Some((release, _)) = CODENAMES
+            .iter()
+            .filter(|(_, codename)| util::contains_any_word(value, &[codename]))
+            .max_by_key(|(_, codename)| codename.len())
+        {
+            Ok(Release::from(*release))
+        } else {
+            Err(format!("{} ({})", String::from(ERR_UNKNOWN_RELEASE), value))
+        }
+    }
+}
@@ -0,0 +1,5 @@
+mod mac_os_x;
+pub(crate) use mac_os_x::MacOSXParser;
+
+mod macos_11ff;
+pub(crate) use macos_11ff::MacOS11ffParser;
@@ -0,0 +1,87 @@
+use crate::{model, util};
+use crate::model::android::Release;
+use crate::parser::generic::GenericLabel;
+
+const ERR_UNKNOWN_PRODUCT: &str = "Not an Android.";
+const ERR_UNKNOWN_RELEASE: &str = "Not an Android release.";
+const ERR_UNKNOWN_API_LEVEL: &str = "Not an API level of this Android release.";
+
+// Maps the API level to the Android release
+include!(concat!(env!("OUT_DIR"), "/android_api_to_release_map.rs"));
+
+pub(crate) struct AndroidParser();
+
+impl AndroidParser {
+    pub(crate) fn parse(label: &GenericLabel) -> Result<model::Android, String> {
+        // Android must be the product, not the host of another one, e.g. "Windows 10 on Android 14"
+        if label.raw.trim_start().starts_with("Android") || label.raw.trim_start().starts_with("Google Android") {
+            let release = Release::try_from(label)?;
+
+            // An API level named in the label must belong to the release
+            let api_level = match (
+                util::find_number_after(label.raw, "API level|API|SDK"),
+                util::resolve_release_to_build(&release.to_string(), BUILD_TO_RELEASE_MAP),
+            ) {
+                (Some(named), Some(api)) if named != api => return Err(String::from(ERR_UNKNOWN_API_LEVEL)),
+                (named, api) => named.or(api).and_then(|api| api.parse::<u32>().ok()),
+            };
+
+            Ok(model::Android::build(release).api(api_level))
+        } else {
+            Err(String::from(ERR_UNKNOWN_PRODUCT))
+        }
+    }
+}
+
+// Release of the map a version belongs to, e.g. 4.4 for 4.4.2 or 8.0 for 8
+fn identify_mapped_release(version: &str) -> Option<String> {
+    [version.to_string(), format!("{version}.0")]
+        .iter()
+        .filter_map(|candidate| util::identify_release(candidate, RELEASE_PATTERN))
+        .find(|release| util::resolve_release_to_build(release, BUILD_TO_RELEASE_MAP).is_some())
+}
+
+impl<'a> TryFrom<&GenericLabel<'a>> for Release {
+    type Error = String;
+
+    fn try_from(value: &GenericLabel<'a>) -> Result<Self, Self::Error> {
+        let value = value.raw;
+
+        // Version following the product name, e.g. Android 14 or Android 8.1.0, as far as
+        // releases are told apart, e.g. 8.1; the release must be one of the map
+        if let Some(release) = util::identify_release(value, r#"Android [0-9]+(\.[0-9]+)*[LW]?"#) {
+            let release = release.trim_start_matches("Android ");
+
+            return identify_mapped_release(release)
+                .map(|release| Release::from(release.as_str()))
+                .ok_or(format!("{} ({})", String::from(ERR_UNKNOWN_RELEASE), value));
+        }
+
+        // Otherwise the API level, e.g. API level 34
+        match util::find_number_after(value, "API level|API|SDK") {
+            Some(api) => util::resolve_build_to_release(&api, BUILD_TO_RELEASE_MAP)
+                .map(|release| Release::from(release.as_str())),
+            None => Err(format!("{} ({})", String::from(ERR_UNKNOWN_RELEASE), value)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util;
+
+    #[test]
+    fn test_build_to_release_1() {
+        let release = util::resolve_build_to_release("34", BUILD_TO_RELEASE_MAP);
+
+        assert_eq!(release, Ok(String::from("14")));
+    }
+
+    #[test]
+    fn test_release_to_build_1() {
+        let api = util::resolve_release_to_build("12L", BUILD_TO_RELEASE_MAP);
+
+        assert_eq!(api, Some(String::from("32")));
+    }
+}
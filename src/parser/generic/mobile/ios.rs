@@ -0,0 +1,84 @@
+use crate::{model, util};
+use crate::model::ios::{Product, Release};
+use crate::parser::generic::GenericLabel;
+
+const ERR_UNKNOWN_PRODUCT: &str = "Not an iOS or iPadOS.";
+const ERR_UNKNOWN_RELEASE: &str = "Not an iOS or iPadOS release.";
+
+include!(concat!(env!("OUT_DIR"), "/ios_build_to_release_map.rs"));
+
+pub(crate) struct IOSParser();
+
+impl IOSParser {
+    pub(crate) fn parse(label: &GenericLabel) -> Result<model::IOS, String> {
+        let product = Product::try_from(label)?;
+        let release = Release::try_from(label)?;
+
+        let ios = model::IOS::build(product, release);
+
+        match find_build(label.raw) {
+            Some(build) => Ok(ios.installed_build(&build)),
+            None => Ok(ios),
+        }
+    }
+}
+
+// Build number, e.g. 21G93
+fn find_build(value: &str) -> Option<String> {
+    util::identify_release(value, r#"[0-9]{2}[A-Z][0-9]{1,4}[a-z]?"#)
+}
+
+impl<'a> TryFrom<&GenericLabel<'a>> for Product {
+    type Error = String;
+
+    fn try_from(value: &GenericLabel<'a>) -> Result<Self, Self::Error> {
+        let value = value.raw;
+
+        if util::contains_any_word(value, &["iPadOS"]) {
+            Ok(Product::IPadOS)
+        } else if util::contains_any_word(value, &["iOS", "iPhone OS"]) {
+            Ok(Product::IOS)
+        } else {
+            Err(String::from(ERR_UNKNOWN_PRODUCT))
+        }
+    }
+}
+
+impl<'a> TryFrom<&GenericLabel<'a>> for Release {
+    type Error = String;
+
+    fn try_from(value: &GenericLabel<'a>) -> Result<Self, Self::Error> {
+        let value = value.raw;
+
+        let version = util::identify_release(value, r#"[0-9]+(\.[0-9]+){0,2}"#);
+        let build_release = find_build(value).and_then(|build| util::resolve_build_to_release(&build, BUILD_TO_RELEASE_MAP).ok());
+
+        match (version, build_release) {
+            // A known build must belong to the version and may refine it, e.g. 17 (21G93) is 17.6.1
+            (Some(version), Some(release)) => {
+                if release == version || release.starts_with(&format!("{version}.")) {
+                    Ok(Release::from(release.as_str()))
+                } else {
+                    Err(format!("{} ({})", String::from(ERR_UNKNOWN_RELEASE), value))
+                }
+            },
+            (Some(version), None) if util::is_known_release(&version, BUILD_TO_RELEASE_MAP) => Ok(Release::from(version.as_str())),
+            (Some(_), None) => Err(format!("{} ({})", String::from(ERR_UNKNOWN_RELEASE), value)),
+            (None, Some(release)) => Ok(Release::from(release.as_str())),
+            (None, None) => Err(format!("{} ({})", String::from(ERR_UNKNOWN_RELEASE), value)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util;
+
+    #[test]
+    fn test_build_to_release_1() {
+        let release = util::resolve_build_to_release("21G93", BUILD_TO_RELEASE_MAP);
+
+        assert_eq!(release, Ok(String::from("17.6.1")));
+    }
+}
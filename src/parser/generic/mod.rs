@@ -1,5 +1,13 @@
+pub(crate) mod bsd;
+
+pub(crate) mod hypervisor;
+
 pub(crate) mod linux;
 
+pub(crate) mod macos;
+
+pub(crate) mod mobile;
+
 pub(crate) mod windows;
 
 pub struct GenericLabel<'a> {
@@ -1,8 +1,53 @@
+mod windows_7;
+pub(crate) use windows_7::Windows7Parser;
+
+mod windows_8;
+pub(crate) use windows_8::Windows8Parser;
+
 mod windows_10;
 pub(crate) use windows_10::Windows10Parser;
 
 mod windows_11;
 pub(crate) use windows_11::Windows11Parser;
 
+mod windows_embedded_8;
+pub(crate) use windows_embedded_8::WindowsEmbedded8Parser;
+
+mod windows_embedded_compact;
+pub(crate) use windows_embedded_compact::WindowsEmbeddedCompactParser;
+
+mod windows_embedded_posready;
+pub(crate) use windows_embedded_posready::WindowsEmbeddedPOSReadyParser;
+
+mod windows_embedded_standard_7;
+pub(crate) use windows_embedded_standard_7::WindowsEmbeddedStandard7Parser;
+
+mod windows_vista;
+pub(crate) use windows_vista::WindowsVistaParser;
+
+mod windows_xp;
+pub(crate) use windows_xp::WindowsXPParser;
+
+mod windows_server_1709ff;
+pub(crate) use windows_server_1709ff::WindowsServer1709ffParser;
+
+mod windows_server_2003;
+pub(crate) use windows_server_2003::WindowsServer2003Parser;
+
+mod windows_server_2008;
+pub(crate) use windows_server_2008::WindowsServer2008Parser;
+
+mod windows_server_2008_r2;
+pub(crate) use windows_server_2008_r2::WindowsServer2008R2Parser;
+
+mod windows_server_2012;
+pub(crate) use windows_server_2012::WindowsServer2012Parser;
+
+mod windows_server_2012_r2;
+pub(crate) use windows_server_2012_r2::WindowsServer2012R2Parser;
+
+mod windows_server_2016;
+pub(crate) use windows_server_2016::WindowsServer2016Parser;
+
 mod windows_server_2019ff;
 pub(crate) use windows_server_2019ff::WindowsServer2019ffParser;
@@ -2,6 +2,7 @@ use crate::{model, util};
 use crate::model::windows_10::{Edition, Editions, Release, ServiceChannel};
 use crate::parser::generic::GenericLabel;
 
+const ERR_UNKNOWN_PRODUCT: &str = "Not a Windows 10.";
 const ERR_UNKNOWN_RELEASE: &str = "Not a Windows 10 release.";
 const ERR_UNKNOWN_EDITION: &str = "Not a Windows 10 edition.";
 const ERR_UNKNOWN_SERVICE_CHANNEL: &str = "Not a Windows 10 service channel.";
@@ -12,11 +13,30 @@ pub(crate) struct Windows10Parser();
 
 impl Windows10Parser {
     pub(crate) fn parse(label: &GenericLabel) -> Result<model::Windows10, String> {
-        let edition = Edition::try_from(label)?;
-        let release = Release::try_from(label)?;
-        let service_channel = ServiceChannel::try_from(label).unwrap_or_default();
+        // Windows Server shares its builds with Windows 10, e.g. 14393 and 17763
+        if !util::contains_any_word(label.raw, &["Windows 10"]) {
+            return Err(String::from(ERR_UNKNOWN_PRODUCT));
+        }
 
-        let windows10 = model::Windows10::build(release, service_channel).editions(Editions(vec![edition]));
+        let release = Release::try_from(label)?;
+        let service_channel = match ServiceChannel::try_from(label) {
+            // LTSB or LTSC follows from the release, 1607 and earlier were LTSB
+            Ok(service_channel) if service_channel.is_lts() => {
+                ServiceChannel::try_from((&release, &service_channel)).unwrap_or_default()
+            },
+            Ok(service_channel) => service_channel,
+            // The servicing branch of the release, e.g. CB for 1511 or SAC for 1809
+            Err(_) => ServiceChannel::try_from(&release).unwrap_or_default(),
+        };
+
+        let windows10 = match Edition::try_from(label) {
+            Ok(edition) if !edition.supports(&service_channel) => return Err(String::from(ERR_UNKNOWN_SERVICE_CHANNEL)),
+            Ok(edition) => model::Windows10::build(release, service_channel)
+                .editions(Editions(vec![edition])),
+            Err(_) => model::Windows10::build(release, service_channel)
+                .editions(Editions::all())
+                .edition_known(false),
+        };
 
         Ok(windows10)
     }
@@ -28,6 +48,15 @@ impl<'a> TryFrom<&GenericLabel<'a>> for Release {
     fn try_from(value: &GenericLabel<'a>) -> Result<Self, Self::Error> {
         let value = value.raw;
 
+        // Long-term servicing releases are named after their year, e.g. "LTSC 2019"
+        if util::contains_any_word(value, &["LTSB", "LTSC", "LTS"])
+            && let Some(release) = util::find_numbers_with_digits(value, 4)
+                .iter()
+                .find_map(|year| Release::from_lts_year(year))
+        {
+            return Ok(release);
+        }
+
         // Look for a build number or identify the release
         if let Some(build) = util::find_number_with_digits(value, 5) {
             util::resolve_build_to_release(build.as_str(), BUILD_TO_RELEASE_MAP)
@@ -50,14 +79,44 @@ impl<'a> TryFrom<&GenericLabel<'a>> for Edition {
     fn try_from(value: &GenericLabel<'a>) -> Result<Self, Self::Error> {
         let value = value.raw;
 
-        if util::contains_any_word(value, &["Education Edition", "Education"]) {
-            Ok(Edition::Education)
+        // N editions ship without media features, KN editions are their Korean counterpart
+        let n = util::contains_any_word(value, &["N"]);
+        let kn = util::contains_any_word(value, &["KN"]);
+
+        // Longer edition names first, "Pro for Workstations" must not end up as "Pro"
+        if util::contains_any_word(value, &["Pro for Workstations", "Pro N for Workstations"]) {
+            if n { Ok(Edition::ProForWorkstationsN) } else { Ok(Edition::ProForWorkstations) }
+        } else if util::contains_any_word(value, &["Pro Education"]) {
+            if n { Ok(Edition::ProEducationN) } else { Ok(Edition::ProEducation) }
+        } else if util::contains_any_word(value, &["Enterprise multi-session", "Enterprise for Virtual Desktops"]) {
+            Ok(Edition::EnterpriseMultiSession)
+        } else if util::contains_any_word(value, &["IoT Enterprise", "Enterprise IoT"]) {
+            Ok(Edition::EnterpriseIoT)
+        } else if util::contains_any_word(value, &["Home Single Language", "Single Language"]) {
+            Ok(Edition::HomeSingleLanguage)
+        } else if util::contains_any_word(value, &["S mode"]) {
+            // Home is the only edition shipped in S mode without naming it
+            if util::contains_any_word(value, &["Pro", "Professional"]) {
+                Ok(Edition::ProInSMode)
+            } else if util::contains_any_word(value, &["Education"]) {
+                Ok(Edition::EducationInSMode)
+            } else {
+                Ok(Edition::HomeInSMode)
+            }
+        } else if util::contains_any_word(value, &["Windows 10 S"]) {
+            Ok(Edition::S)
+        } else if util::contains_any_word(value, &["Team"]) {
+            Ok(Edition::Team)
+        } else if util::contains_any_word(value, &["Holographic"]) {
+            Ok(Edition::Holographic)
+        } else if util::contains_any_word(value, &["Education Edition", "Education"]) {
+            if kn { Ok(Edition::EducationKN) } else if n { Ok(Edition::EducationN) } else { Ok(Edition::Education) }
         } else if util::contains_any_word(value, &["Enterprise Edition", "Enterprise"]) {
-            Ok(Edition::Enterprise)
+            if kn { Ok(Edition::EnterpriseKN) } else if n { Ok(Edition::EnterpriseN) } else { Ok(Edition::Enterprise) }
         } else if util::contains_any_word(value, &["Home Edition", "Home"]) {
-            Ok(Edition::Home)
+            if kn { Ok(Edition::HomeKN) } else if n { Ok(Edition::HomeN) } else { Ok(Edition::Home) }
         } else if util::contains_any_word(value, &["Professional Edition", "Professional", "Pro"]) {
-            Ok(Edition::Pro)
+            if kn { Ok(Edition::ProKN) } else if n { Ok(Edition::ProN) } else { Ok(Edition::Pro) }
         } else {
             Err(String::from(ERR_UNKNOWN_EDITION))
         }
@@ -72,7 +131,17 @@ impl<'a> TryFrom<&GenericLabel<'a>> for ServiceChannel {
 
         if util::contains_any_word(value, &["General Availability", "GA"]) {
             Ok(ServiceChannel::GAC)
-        } else if util::contains_any_word(value, &["LTSC"]) {
+        } else if util::contains_any_word(value, &["Current Branch for Business", "CBB"]) {
+            Ok(ServiceChannel::CBB)
+        } else if util::contains_any_word(value, &["Current Branch", "CB"]) {
+            Ok(ServiceChannel::CB)
+        } else if util::contains_any_word(value, &["SAC-T", "Targeted"]) {
+            Ok(ServiceChannel::SACT)
+        } else if util::contains_any_word(value, &["Semi-Annual Channel", "SAC"]) {
+            Ok(ServiceChannel::SAC)
+        } else if util::contains_any_word(value, &["LTSB"]) {
+            Ok(ServiceChannel::LTSB)
+        } else if util::contains_any_word(value, &["LTS", "LTSC"]) {
             Ok(ServiceChannel::LTSC)
         } else {
             Err(String::from(ERR_UNKNOWN_SERVICE_CHANNEL))
@@ -2,6 +2,7 @@ use crate::{model, util};
 use crate::model::windows_11::{Edition, Editions, Release, ServiceChannel};
 use crate::parser::generic::GenericLabel;
 
+const ERR_UNKNOWN_PRODUCT: &str = "Not a Windows 11.";
 const ERR_UNKNOWN_RELEASE: &str = "Not a Windows 11 release.";
 const ERR_UNKNOWN_EDITION: &str = "Not a Windows 11 edition.";
 const ERR_UNKNOWN_SERVICE_CHANNEL: &str = "Not a Windows 11 service channel.";
@@ -12,11 +13,21 @@ pub(crate) struct Windows11Parser();
 
 impl Windows11Parser {
     pub(crate) fn parse(label: &GenericLabel) -> Result<model::Windows11, String> {
+        // Windows Server shares its builds with Windows 11, e.g. 26100
+        if !util::contains_any_word(label.raw, &["Windows 11"]) {
+            return Err(String::from(ERR_UNKNOWN_PRODUCT));
+        }
+
         let release = Release::try_from(label)?;
-        let edition = Edition::try_from(label)?;
         let service_channel = ServiceChannel::try_from(label).unwrap_or_default();
 
-        let windows11 = model::Windows11::build(release, service_channel).editions(Editions(vec![edition]));
+        let windows11 = match Edition::try_from(label) {
+            Ok(edition) => model::Windows11::build(release, service_channel)
+                .editions(Editions(vec![edition])),
+            Err(_) => model::Windows11::build(release, service_channel)
+                .editions(Editions::all())
+                .edition_known(false),
+        };
 
         Ok(windows11)
     }
@@ -28,6 +39,15 @@ impl<'a> TryFrom<&GenericLabel<'a>> for Release {
     fn try_from(value: &GenericLabel<'a>) -> Result<Self, Self::Error> {
         let value = value.raw;
 
+        // Long-term servicing releases are named after their year, e.g. "LTSC 2019"
+        if util::contains_any_word(value, &["LTSB", "LTSC", "LTS"])
+            && let Some(release) = util::find_numbers_with_digits(value, 4)
+                .iter()
+                .find_map(|year| Release::from_lts_year(year))
+        {
+            return Ok(release);
+        }
+
         // Look for a build number or identify the release
         if let Some(build) = util::find_number_with_digits(value, 5) {
             util::resolve_build_to_release(build.as_str(), BUILD_TO_RELEASE_MAP)
@@ -50,14 +70,40 @@ impl<'a> TryFrom<&GenericLabel<'a>> for Edition {
     fn try_from(value: &GenericLabel<'a>) -> Result<Self, Self::Error> {
         let value = value.raw;
 
-        if crate::util::contains_any_word(value, &["Education Edition", "Education"]) {
-            Ok(Edition::Education)
-        } else if crate::util::contains_any_word(value, &["Enterprise Edition", "Enterprise"]) {
-            Ok(Edition::Enterprise)
-        } else if crate::util::contains_any_word(value, &["Home Edition", "Home"]) {
-            Ok(Edition::Home)
-        } else if crate::util::contains_any_word(value, &["Professional Edition", "Professional", "Pro"]) {
-            Ok(Edition::Pro)
+        // N editions ship without media features, KN editions are their Korean counterpart
+        let n = util::contains_any_word(value, &["N"]);
+        let kn = util::contains_any_word(value, &["KN"]);
+
+        // Longer edition names first, "Pro for Workstations" must not end up as "Pro"
+        if util::contains_any_word(value, &["Pro for Workstations", "Pro N for Workstations"]) {
+            if n { Ok(Edition::ProForWorkstationsN) } else { Ok(Edition::ProForWorkstations) }
+        } else if util::contains_any_word(value, &["Pro Education"]) {
+            if n { Ok(Edition::ProEducationN) } else { Ok(Edition::ProEducation) }
+        } else if util::contains_any_word(value, &["Enterprise multi-session", "Enterprise for Virtual Desktops"]) {
+            Ok(Edition::EnterpriseMultiSession)
+        } else if util::contains_any_word(value, &["IoT Enterprise", "Enterprise IoT"]) {
+            Ok(Edition::IoTEnterprise)
+        } else if util::contains_any_word(value, &["Home Single Language", "Single Language"]) {
+            Ok(Edition::HomeSingleLanguage)
+        } else if util::contains_any_word(value, &["S mode"]) {
+            // Home is the only edition shipped in S mode without naming it
+            if util::contains_any_word(value, &["Pro", "Professional"]) {
+                Ok(Edition::ProInSMode)
+            } else if util::contains_any_word(value, &["Education"]) {
+                Ok(Edition::EducationInSMode)
+            } else {
+                Ok(Edition::HomeInSMode)
+            }
+        } else if util::contains_any_word(value, &["Windows 11 SE"]) {
+            Ok(Edition::SE)
+        } else if util::contains_any_word(value, &["Education Edition", "Education"]) {
+            if kn { Ok(Edition::EducationKN) } else if n { Ok(Edition::EducationN) } else { Ok(Edition::Education) }
+        } else if util::contains_any_word(value, &["Enterprise Edition", "Enterprise"]) {
+            if kn { Ok(Edition::EnterpriseKN) } else if n { Ok(Edition::EnterpriseN) } else { Ok(Edition::Enterprise) }
+        } else if util::contains_any_word(value, &["Home Edition", "Home"]) {
+            if kn { Ok(Edition::HomeKN) } else if n { Ok(Edition::HomeN) } else { Ok(Edition::Home) }
+        } else if util::contains_any_word(value, &["Professional Edition", "Professional", "Pro"]) {
+            if kn { Ok(Edition::ProKN) } else if n { Ok(Edition::ProN) } else { Ok(Edition::Pro) }
         } else {
             Err(String::from(ERR_UNKNOWN_EDITION))
         }
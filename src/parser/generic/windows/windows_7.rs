@@ -0,0 +1,84 @@
+use crate::{model, util};
+use crate::model::windows_7::{Edition, Editions, Release};
+use crate::parser::generic::GenericLabel;
+
+const ERR_UNKNOWN_PRODUCT: &str = "Not a Windows 7.";
+const ERR_UNKNOWN_RELEASE: &str = "Not a Windows 7 release.";
+const ERR_UNKNOWN_EDITION: &str = "Not a Windows 7 edition.";
+
+include!(concat!(env!("OUT_DIR"), "/windows_7_build_to_release_map.rs"));
+
+pub(crate) struct Windows7Parser();
+
+impl Windows7Parser {
+    pub(crate) fn parse(label: &GenericLabel) -> Result<model::Windows7, String> {
+        if util::contains_any_word(label.raw, &["Windows 7"]) {
+            let release = Release::try_from(label)?;
+
+            match Edition::try_from(label) {
+                Ok(edition) => Ok(model::Windows7::build(release).editions(Editions(vec![edition]))),
+                Err(_) => Ok(model::Windows7::build(release).editions(Editions::all())),
+            }
+        } else {
+            Err(String::from(ERR_UNKNOWN_PRODUCT))
+        }
+    }
+}
+
+impl<'a> TryFrom<&GenericLabel<'a>> for Release {
+    type Error = String;
+
+    fn try_from(value: &GenericLabel<'a>) -> Result<Self, Self::Error> {
+        let value = value.raw;
+
+        let release = util::identify_service_pack(value, RELEASE_PATTERN)
+            .and_then(|stated| util::resolve_release_with_build(stated, value, 4, &[], BUILD_TO_RELEASE_MAP))
+            .map_err(|_| String::from(ERR_UNKNOWN_RELEASE))?
+            .unwrap_or(String::from("GA"));
+
+        Release::try_from(release.as_str())
+    }
+}
+
+impl<'a> TryFrom<&GenericLabel<'a>> for Edition {
+    type Error = String;
+
+    fn try_from(value: &GenericLabel<'a>) -> Result<Self, Self::Error> {
+        let value = value.raw;
+
+        let n = util::contains_any_word(value, &["N"]);
+
+        if util::contains_any_word(value, &["Professional for Embedded Systems"]) {
+            Ok(Edition::ProfessionalForEmbeddedSystems)
+        } else if util::contains_any_word(value, &["Ultimate for Embedded Systems"]) {
+            Ok(Edition::UltimateForEmbeddedSystems)
+        } else if util::contains_any_word(value, &["Home Premium"]) {
+            if n { Ok(Edition::HomePremiumN) } else { Ok(Edition::HomePremium) }
+        } else if util::contains_any_word(value, &["Home Basic"]) {
+            Ok(Edition::HomeBasic)
+        } else if util::contains_any_word(value, &["Enterprise"]) {
+            if n { Ok(Edition::EnterpriseN) } else { Ok(Edition::Enterprise) }
+        } else if util::contains_any_word(value, &["Ultimate"]) {
+            if n { Ok(Edition::UltimateN) } else { Ok(Edition::Ultimate) }
+        } else if util::contains_any_word(value, &["Professional", "Pro"]) {
+            if n { Ok(Edition::ProfessionalN) } else { Ok(Edition::Professional) }
+        } else if util::contains_any_word(value, &["Starter"]) {
+            if n { Ok(Edition::StarterN) } else { Ok(Edition::Starter) }
+        } else {
+            Err(String::from(ERR_UNKNOWN_EDITION))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util;
+
+    #[test]
+    fn test_build_to_release_1() {
+        let release = util::resolve_build_to_release("7601", BUILD_TO_RELEASE_MAP);
+
+        assert_eq!(release, Ok(String::from("SP1")));
+    }
+}
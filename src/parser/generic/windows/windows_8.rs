@@ -0,0 +1,74 @@
+use crate::{model, util};
+use crate::model::windows_8::{Edition, Editions};
+use crate::parser::generic::GenericLabel;
+
+const ERR_UNKNOWN_PRODUCT: &str = "Not a Windows 8.";
+const ERR_UNKNOWN_EDITION: &str = "Not a Windows 8 edition.";
+
+include!(concat!(env!("OUT_DIR"), "/windows_8_build_to_release_map.rs"));
+
+pub(crate) struct Windows8Parser();
+
+impl Windows8Parser {
+    pub(crate) fn parse(label: &GenericLabel) -> Result<model::Windows8, String> {
+        let value = label.raw;
+
+        // Longest match first, "Windows 8" is also found in "Windows 8.1"
+        let stated = match RELEASE_PATTERN
+            .split('|')
+            .filter(|release| util::contains_any_word(value, &[format!("Windows {}", release).as_str()]))
+            .max_by_key(|release| release.len())
+        {
+            Some(release) => release.to_string(),
+            None => return Err(String::from(ERR_UNKNOWN_PRODUCT)),
+        };
+
+        // Without the point release the build tells 8 and 8.1 apart, otherwise it must match
+        let point_release = Some(stated.clone()).filter(|release| release != "8");
+        let release = util::resolve_release_with_build(point_release, value, 4, &[], BUILD_TO_RELEASE_MAP)
+            .map_err(|_| String::from(ERR_UNKNOWN_PRODUCT))?
+            .unwrap_or(stated);
+
+        match Edition::try_from(label) {
+            Ok(edition) => Ok(model::Windows8::build(&release).editions(Editions(vec![edition]))),
+            Err(_) => Ok(model::Windows8::build(&release).editions(Editions::all())),
+        }
+    }
+}
+
+impl<'a> TryFrom<&GenericLabel<'a>> for Edition {
+    type Error = String;
+
+    fn try_from(value: &GenericLabel<'a>) -> Result<Self, Self::Error> {
+        let value = value.raw;
+
+        let n = util::contains_any_word(value, &["N"]);
+
+        if util::contains_any_word(value, &["Pro with Media Center"]) {
+            Ok(Edition::ProWithMediaCenter)
+        } else if util::contains_any_word(value, &["Single Language", "SL"]) {
+            Ok(Edition::SL)
+        } else if util::contains_any_word(value, &["Enterprise"]) {
+            if n { Ok(Edition::EnterpriseN) } else { Ok(Edition::Enterprise) }
+        } else if util::contains_any_word(value, &["Professional", "Pro"]) {
+            if n { Ok(Edition::ProfessionalN) } else { Ok(Edition::Professional) }
+        } else if n {
+            Ok(Edition::N)
+        } else {
+            Err(String::from(ERR_UNKNOWN_EDITION))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util;
+
+    #[test]
+    fn test_build_to_release_1() {
+        let release = util::resolve_build_to_release("9600", BUILD_TO_RELEASE_MAP);
+
+        assert_eq!(release, Ok(String::from("8.1")));
+    }
+}
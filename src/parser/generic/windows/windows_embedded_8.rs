@@ -0,0 +1,55 @@
+use crate::{model, util};
+use crate::model::windows_embedded_8::{Edition, Editions, Release};
+use crate::parser::generic::GenericLabel;
+
+const ERR_UNKNOWN_RELEASE: &str = "Not a Windows Embedded Industry release.";
+const ERR_UNKNOWN_EDITION: &str = "Not a Windows Embedded Industry edition.";
+
+pub(crate) struct WindowsEmbedded8Parser();
+
+impl WindowsEmbedded8Parser {
+    pub(crate) fn parse(label: &GenericLabel) -> Result<model::WindowsEmbedded8, String> {
+        if label.raw.contains("Windows Embedded") && util::contains_any_word(label.raw, &["Industry"]) {
+            let release = Release::try_from(label)?;
+
+            match Edition::try_from(label) {
+                Ok(edition) => Ok(model::WindowsEmbedded8::build(release).editions(Editions(vec![edition]))),
+                Err(_) => Ok(model::WindowsEmbedded8::build(release).editions(Editions::all())),
+            }
+        } else {
+            Err(String::from(ERR_UNKNOWN_RELEASE))
+        }
+    }
+}
+
+impl<'a> TryFrom<&GenericLabel<'a>> for Release {
+    type Error = String;
+
+    fn try_from(value: &GenericLabel<'a>) -> Result<Self, Self::Error> {
+        let value = value.raw;
+
+        match util::identify_release(value, r#"8\.1|8"#) {
+            Some(release) => Ok(Release::from(release.as_str())),
+            None => Err(String::from(ERR_UNKNOWN_RELEASE)),
+        }
+    }
+}
+
+impl<'a> TryFrom<&GenericLabel<'a>> for Edition {
+    type Error = String;
+
+    fn try_from(value: &GenericLabel<'a>) -> Result<Self, Self::Error> {
+        let value = value.raw;
+
+        // "Pro Retail" has to be checked before "Pro"
+        if util::contains_any_word(value, &["Pro Retail"]) {
+            Ok(Edition::ProRetail)
+        } else if util::contains_any_word(value, &["Enterprise"]) {
+            Ok(Edition::Enterprise)
+        } else if util::contains_any_word(value, &["Pro"]) {
+            Ok(Edition::Pro)
+        } else {
+            Err(String::from(ERR_UNKNOWN_EDITION))
+        }
+    }
+}
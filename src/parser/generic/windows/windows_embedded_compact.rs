@@ -0,0 +1,19 @@
+use crate::{model, util};
+use crate::parser::generic::GenericLabel;
+
+const ERR_UNKNOWN_RELEASE: &str = "Not a Windows Embedded Compact release.";
+
+pub(crate) struct WindowsEmbeddedCompactParser();
+
+impl WindowsEmbeddedCompactParser {
+    pub(crate) fn parse(label: &GenericLabel) -> Result<model::WindowsEmbeddedCompact, String> {
+        if util::contains_any_word(label.raw, &["Embedded Compact"]) {
+            let release = util::identify_release(label.raw, r#"2013|7"#)
+                .ok_or(String::from(ERR_UNKNOWN_RELEASE))?;
+
+            Ok(model::WindowsEmbeddedCompact::build(&release))
+        } else {
+            Err(String::from(ERR_UNKNOWN_RELEASE))
+        }
+    }
+}
@@ -0,0 +1,19 @@
+use crate::{model, util};
+use crate::parser::generic::GenericLabel;
+
+const ERR_UNKNOWN_RELEASE: &str = "Not a Windows Embedded POSReady release.";
+
+pub(crate) struct WindowsEmbeddedPOSReadyParser();
+
+impl WindowsEmbeddedPOSReadyParser {
+    pub(crate) fn parse(label: &GenericLabel) -> Result<model::WindowsEmbeddedPOSReady, String> {
+        if util::contains_any_word(label.raw, &["POSReady"]) {
+            let release = util::identify_release(label.raw, r#"2009|7"#)
+                .ok_or(String::from(ERR_UNKNOWN_RELEASE))?;
+
+            Ok(model::WindowsEmbeddedPOSReady::build(&release))
+        } else {
+            Err(String::from(ERR_UNKNOWN_RELEASE))
+        }
+    }
+}
@@ -0,0 +1,33 @@
+use crate::{model, util};
+use crate::model::windows_embedded_standard_7::Release;
+use crate::parser::generic::GenericLabel;
+
+const ERR_UNKNOWN_PRODUCT: &str = "Not a Windows Embedded Standard 7.";
+
+pub(crate) struct WindowsEmbeddedStandard7Parser();
+
+impl WindowsEmbeddedStandard7Parser {
+    pub(crate) fn parse(label: &GenericLabel) -> Result<model::WindowsEmbeddedStandard7, String> {
+        if util::contains_any_word(label.raw, &["Embedded Standard 7"]) {
+            let release = Release::try_from(label).ok();
+
+            Ok(model::WindowsEmbeddedStandard7::build(release))
+        } else {
+            Err(String::from(ERR_UNKNOWN_PRODUCT))
+        }
+    }
+}
+
+impl<'a> TryFrom<&GenericLabel<'a>> for Release {
+    type Error = String;
+
+    fn try_from(value: &GenericLabel<'a>) -> Result<Self, Self::Error> {
+        let value = value.raw;
+
+        if util::contains_any_word(value, &["SP1", "Service Pack 1"]) {
+            Ok(Release::from("SP1"))
+        } else {
+            Err(String::from(ERR_UNKNOWN_PRODUCT))
+        }
+    }
+}
@@ -0,0 +1,105 @@
+use crate::{model, util};
+use crate::model::InstallationOption;
+use crate::model::windows_server_1709ff::{Edition, Editions, Release, ServiceChannel};
+use crate::parser::generic::GenericLabel;
+
+const ERR_UNKNOWN_PRODUCT: &str = "Not a Windows Server.";
+const ERR_UNKNOWN_RELEASE: &str = "Not a Windows Server release.";
+const ERR_UNKNOWN_EDITION: &str = "Not a Windows Server edition.";
+const ERR_UNKNOWN_SERVICE_CHANNEL: &str = "Not a Windows Server service channel.";
+
+include!(concat!(env!("OUT_DIR"), "/windows_server_1709ff_build_to_release_map.rs"));
+
+pub(crate) struct WindowsServer1709ffParser();
+
+impl WindowsServer1709ffParser {
+    pub(crate) fn parse(label: &GenericLabel) -> Result<model::WindowsServer1709ff, String> {
+        // Releases named after a year belong to the long-term servicing products
+        if util::contains_any_word(label.raw, &["Windows Server"])
+            && !util::contains_any_word(label.raw, &["2003", "2008", "2012", "2016", "2019", "2022", "2025"])
+        {
+            let release = Release::try_from(label)?;
+            let service_channel = ServiceChannel::try_from(label)
+                .unwrap_or_else(|_| ServiceChannel::from(&release));
+            let installation_option = InstallationOption::try_from(label).ok();
+
+            let editions = match Edition::try_from(label) {
+                Ok(edition) => Editions(vec![edition]),
+                Err(_) => Editions::from(&service_channel),
+            };
+
+            Ok(model::WindowsServer1709ff::build(release, service_channel)
+                .editions(editions)
+                .installation_option(installation_option))
+        } else {
+            Err(String::from(ERR_UNKNOWN_PRODUCT))
+        }
+    }
+}
+
+impl<'a> TryFrom<&GenericLabel<'a>> for Release {
+    type Error = String;
+
+    fn try_from(value: &GenericLabel<'a>) -> Result<Self, Self::Error> {
+        let value = value.raw;
+
+        let stated = util::identify_release(&value.to_uppercase(), RELEASE_PATTERN);
+
+        match util::resolve_release_with_build(stated, value, 5, &[], BUILD_TO_RELEASE_MAP) {
+            Ok(Some(release)) => Ok(Release::from(release.as_str())),
+            _ => Err(String::from(ERR_UNKNOWN_RELEASE)),
+        }
+    }
+}
+
+impl<'a> TryFrom<&GenericLabel<'a>> for Edition {
+    type Error = String;
+
+    fn try_from(value: &GenericLabel<'a>) -> Result<Self, Self::Error> {
+        let value = value.raw;
+
+        if util::contains_any_word(value, &["Datacenter"]) {
+            Ok(Edition::Datacenter)
+        } else if util::contains_any_word(value, &["Standard"]) {
+            Ok(Edition::Standard)
+        } else {
+            Err(String::from(ERR_UNKNOWN_EDITION))
+        }
+    }
+}
+
+impl<'a> TryFrom<&GenericLabel<'a>> for ServiceChannel {
+    type Error = String;
+
+    fn try_from(value: &GenericLabel<'a>) -> Result<Self, Self::Error> {
+        let value = value.raw;
+
+        if util::contains_any_word(value, &["Semi-Annual Channel", "SAC"]) {
+            Ok(ServiceChannel::SAC)
+        } else if util::contains_any_word(value, &["Annual Channel", "AC"]) {
+            Ok(ServiceChannel::AC)
+        } else {
+            Err(String::from(ERR_UNKNOWN_SERVICE_CHANNEL))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util;
+
+    #[test]
+    fn test_build_to_release_1() {
+        let release = util::resolve_build_to_release("19042", BUILD_TO_RELEASE_MAP);
+
+        assert_eq!(release, Ok(String::from("20H2")));
+    }
+
+    #[test]
+    fn test_build_to_release_2() {
+        let release = util::resolve_build_to_release("25398", BUILD_TO_RELEASE_MAP);
+
+        assert_eq!(release, Ok(String::from("23H2")));
+    }
+}
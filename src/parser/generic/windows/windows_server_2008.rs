@@ -0,0 +1,77 @@
+use crate::{model, util};
+use crate::model::windows_server_2008::{Edition, Editions, Release};
+use crate::parser::generic::GenericLabel;
+
+const ERR_UNKNOWN_PRODUCT: &str = "Not a Windows Server 2008.";
+const ERR_UNKNOWN_RELEASE: &str = "Not a Windows Server 2008 release.";
+const ERR_UNKNOWN_EDITION: &str = "Not a Windows Server 2008 edition.";
+
+include!(concat!(env!("OUT_DIR"), "/windows_server_2008_build_to_release_map.rs"));
+
+pub(crate) struct WindowsServer2008Parser();
+
+impl WindowsServer2008Parser {
+    pub(crate) fn parse(label: &GenericLabel) -> Result<model::WindowsServer2008, String> {
+        // Windows Server 2008 R2 is a product of its own
+        if util::contains_any_word(label.raw, &["Windows Server 2008", "Windows Web Server 2008"]) && !util::contains_any_word(label.raw, &["R2"]) {
+            let release = Release::try_from(label)?;
+
+            match Edition::try_from(label) {
+                Ok(edition) => Ok(model::WindowsServer2008::build(release).editions(Editions(vec![edition]))),
+                Err(_) => Ok(model::WindowsServer2008::build(release).editions(Editions::all())),
+            }
+        } else {
+            Err(String::from(ERR_UNKNOWN_PRODUCT))
+        }
+    }
+}
+
+impl<'a> TryFrom<&GenericLabel<'a>> for Release {
+    type Error = String;
+
+    fn try_from(value: &GenericLabel<'a>) -> Result<Self, Self::Error> {
+        let value = value.raw;
+
+        let release = util::identify_service_pack(value, RELEASE_PATTERN)
+            .and_then(|stated| util::resolve_release_with_build(stated, value, 4, &["2008"], BUILD_TO_RELEASE_MAP))
+            .map_err(|_| String::from(ERR_UNKNOWN_RELEASE))?
+            .unwrap_or(String::from("GA"));
+
+        Ok(Release::from(release.as_str()))
+    }
+}
+
+impl<'a> TryFrom<&GenericLabel<'a>> for Edition {
+    type Error = String;
+
+    fn try_from(value: &GenericLabel<'a>) -> Result<Self, Self::Error> {
+        let value = value.raw;
+
+        if util::contains_any_word(value, &["Datacenter"]) {
+            Ok(Edition::Datacenter)
+        } else if util::contains_any_word(value, &["Enterprise"]) {
+            Ok(Edition::Enterprise)
+        } else if util::contains_any_word(value, &["Foundation"]) {
+            Ok(Edition::Foundation)
+        } else if util::contains_any_word(value, &["Standard"]) {
+            Ok(Edition::Standard)
+        } else if util::contains_any_word(value, &["Web"]) {
+            Ok(Edition::Web)
+        } else {
+            Err(String::from(ERR_UNKNOWN_EDITION))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util;
+
+    #[test]
+    fn test_build_to_release_1() {
+        let release = util::resolve_build_to_release("6003", BUILD_TO_RELEASE_MAP);
+
+        assert_eq!(release, Ok(String::from("SP2")));
+    }
+}
@@ -0,0 +1,85 @@
+use crate::{model, util};
+use crate::model::windows_server_2008_r2::{Edition, Editions, Release};
+use crate::parser::generic::GenericLabel;
+
+const ERR_UNKNOWN_PRODUCT: &str = "Not a Windows Server 2008 R2.";
+const ERR_UNKNOWN_RELEASE: &str = "Not a Windows Server 2008 R2 release.";
+const ERR_UNKNOWN_EDITION: &str = "Not a Windows Server 2008 R2 edition.";
+
+include!(concat!(env!("OUT_DIR"), "/windows_server_2008_r2_build_to_release_map.rs"));
+
+pub(crate) struct WindowsServer2008R2Parser();
+
+impl WindowsServer2008R2Parser {
+    pub(crate) fn parse(label: &GenericLabel) -> Result<model::WindowsServer2008R2, String> {
+        if util::contains_any_word(label.raw, &["Windows Server 2008", "Windows Web Server 2008", "Windows HPC Server 2008"]) {
+            let release = Release::try_from(label)?;
+
+            // Without the R2 suffix only a build number tells Windows Server 2008 R2 apart
+            if !util::contains_any_word(label.raw, &["R2"])
+                && util::resolve_release_with_build(None, label.raw, 4, &["2008"], BUILD_TO_RELEASE_MAP)?.is_none()
+            {
+                return Err(String::from(ERR_UNKNOWN_PRODUCT));
+            }
+
+            match Edition::try_from(label) {
+                Ok(edition) => Ok(model::WindowsServer2008R2::build(release).editions(Editions(vec![edition]))),
+                Err(_) => Ok(model::WindowsServer2008R2::build(release).editions(Editions::all())),
+            }
+        } else {
+            Err(String::from(ERR_UNKNOWN_PRODUCT))
+        }
+    }
+}
+
+impl<'a> TryFrom<&GenericLabel<'a>> for Release {
+    type Error = String;
+
+    fn try_from(value: &GenericLabel<'a>) -> Result<Self, Self::Error> {
+        let value = value.raw;
+
+        let release = util::identify_service_pack(value, RELEASE_PATTERN)
+            .and_then(|stated| util::resolve_release_with_build(stated, value, 4, &["2008"], BUILD_TO_RELEASE_MAP))
+            .map_err(|_| String::from(ERR_UNKNOWN_RELEASE))?
+            .unwrap_or(String::from("GA"));
+
+        Ok(Release::from(release.as_str()))
+    }
+}
+
+impl<'a> TryFrom<&GenericLabel<'a>> for Edition {
+    type Error = String;
+
+    fn try_from(value: &GenericLabel<'a>) -> Result<Self, Self::Error> {
+        let value = value.raw;
+
+        if util::contains_any_word(value, &["Datacenter"]) {
+            Ok(Edition::Datacenter)
+        } else if util::contains_any_word(value, &["Enterprise"]) {
+            Ok(Edition::Enterprise)
+        } else if util::contains_any_word(value, &["Foundation"]) {
+            Ok(Edition::Foundation)
+        } else if util::contains_any_word(value, &["HPC"]) {
+            Ok(Edition::HPC)
+        } else if util::contains_any_word(value, &["Standard"]) {
+            Ok(Edition::Standard)
+        } else if util::contains_any_word(value, &["Web"]) {
+            Ok(Edition::Web)
+        } else {
+            Err(String::from(ERR_UNKNOWN_EDITION))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util;
+
+    #[test]
+    fn test_build_to_release_1() {
+        let release = util::resolve_build_to_release("7601", BUILD_TO_RELEASE_MAP);
+
+        assert_eq!(release, Ok(String::from("SP1")));
+    }
+}
@@ -0,0 +1,93 @@
+use crate::{model, util};
+use crate::model::InstallationOption;
+use crate::model::windows_server_2016::{Edition, Editions, Release};
+use crate::parser::generic::GenericLabel;
+
+const ERR_UNKNOWN_PRODUCT: &str = "Not a Windows Server 2016.";
+const ERR_UNKNOWN_RELEASE: &str = "Not a Windows Server 2016 release.";
+const ERR_UNKNOWN_EDITION: &str = "Not a Windows Server 2016 edition.";
+
+include!(concat!(env!("OUT_DIR"), "/windows_server_2016_build_to_release_map.rs"));
+
+pub(crate) struct WindowsServer2016Parser();
+
+impl WindowsServer2016Parser {
+    pub(crate) fn parse(label: &GenericLabel) -> Result<model::WindowsServer2016, String> {
+        // Windows Storage Server 2016 is the last release of Storage Server
+        let storage_server = util::contains_any_word(label.raw, &["Windows Storage Server 2016"]);
+
+        if util::contains_any_word(label.raw, &["Windows Server 2016"]) || storage_server {
+            let release = Release::try_from(label)?;
+            let installation_option = InstallationOption::try_from(label).ok();
+
+            let editions = match Edition::try_from(label) {
+                Ok(edition) if installation_option.as_ref().is_some_and(|option| !edition.supports(option)) => {
+                    return Err(String::from(ERR_UNKNOWN_EDITION));
+                },
+                Ok(edition) => Editions(vec![edition]),
+                Err(_) if storage_server => Editions::storage_server(),
+                Err(_) => Editions::all(),
+            };
+
+            Ok(model::WindowsServer2016::build(release)
+                .editions(editions)
+                .installation_option(installation_option))
+        } else {
+            Err(String::from(ERR_UNKNOWN_PRODUCT))
+        }
+    }
+}
+
+impl<'a> TryFrom<&GenericLabel<'a>> for Release {
+    type Error = String;
+
+    fn try_from(value: &GenericLabel<'a>) -> Result<Self, Self::Error> {
+        let value = value.raw;
+
+        let release = util::identify_service_pack(value, RELEASE_PATTERN)
+            .and_then(|stated| util::resolve_release_with_build(stated, value, 5, &[], BUILD_TO_RELEASE_MAP))
+            .map_err(|_| String::from(ERR_UNKNOWN_RELEASE))?
+            .unwrap_or(String::from("GA"));
+
+        Ok(Release::from(release.as_str()))
+    }
+}
+
+impl<'a> TryFrom<&GenericLabel<'a>> for Edition {
+    type Error = String;
+
+    fn try_from(value: &GenericLabel<'a>) -> Result<Self, Self::Error> {
+        let value = value.raw;
+
+        if util::contains_any_word(value, &["Storage Server"]) {
+            if util::contains_any_word(value, &["Workgroup"]) {
+                Ok(Edition::StorageServerWorkgroup)
+            } else if util::contains_any_word(value, &["Standard"]) {
+                Ok(Edition::StorageServerStandard)
+            } else {
+                Err(String::from(ERR_UNKNOWN_EDITION))
+            }
+        } else if util::contains_any_word(value, &["Datacenter"]) {
+            Ok(Edition::Datacenter)
+        } else if util::contains_any_word(value, &["Essentials"]) {
+            Ok(Edition::Essentials)
+        } else if util::contains_any_word(value, &["Standard"]) {
+            Ok(Edition::Standard)
+        } else {
+            Err(String::from(ERR_UNKNOWN_EDITION))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util;
+
+    #[test]
+    fn test_build_to_release_1() {
+        let release = util::resolve_build_to_release("14393", BUILD_TO_RELEASE_MAP);
+
+        assert_eq!(release, Ok(String::from("GA")));
+    }
+}
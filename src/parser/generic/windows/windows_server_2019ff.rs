@@ -1,10 +1,14 @@
 use crate::{model, util};
+use crate::model::InstallationOption;
 use crate::model::windows_server_2019ff::{Edition, Editions, Release, ServiceChannel};
 use crate::parser::generic::GenericLabel;
 
 const ERR_UNKNOWN_RELEASE: &str = "Not a Windows Server release.";
 const ERR_UNKNOWN_EDITION: &str = "Not a Windows Server edition.";
 const ERR_UNKNOWN_SERVICE_CHANNEL: &str = "Not a Windows Server service channel.";
+const ERR_UNKNOWN_INSTALLATION_OPTION: &str = "Not a Windows Server installation option.";
+const ERR_AZURE_EDITION: &str = "Datacenter: Azure Edition is available with Windows Server 2022 and later.";
+const ERR_HOTPATCH: &str = "Hotpatch is not available for this Windows Server edition.";
 
 include!(concat!(env!("OUT_DIR"), "/windows_server_2019ff_build_to_release_map.rs"));
 
@@ -12,16 +16,39 @@ pub(crate) struct WindowsServer2019ffParser();
 
 impl WindowsServer2019ffParser {
     pub(crate) fn parse(label: &GenericLabel) -> Result<model::WindowsServer2019ff, String> {
+        // Storage Server was discontinued after 2016
+        if util::contains_any_word(label.raw, &["Storage Server"]) {
+            return Err(String::from(ERR_UNKNOWN_EDITION));
+        }
+
+        // Version 1809 of the Semi-Annual Channel shares build 17763 with Windows Server 2019
+        if util::identify_release(label.raw, RELEASE_PATTERN).is_none()
+            && util::contains_any_word(label.raw, &["1809", "Semi-Annual Channel", "Annual Channel", "SAC", "AC"])
+        {
+            return Err(String::from(ERR_UNKNOWN_RELEASE));
+        }
+
         let version = Release::try_from(label)?;
         let service_channel = ServiceChannel::try_from(label).unwrap_or_default();
+        let installation_option = InstallationOption::try_from(label).ok();
 
-        match Edition::try_from(label) {
-            Ok(edition) => {
-                Ok(model::WindowsServer2019ff::build(&version.to_string(), None, service_channel).editions(Editions(vec![edition])))
-            },
-            Err(_) => {
-                Ok(model::WindowsServer2019ff::build(&version.to_string(), None, service_channel).editions(Editions::all()))
+        let editions = match Edition::try_from(label) {
+            Ok(Edition::DatacenterAzure) if version.to_string() == "2019" => return Err(String::from(ERR_AZURE_EDITION)),
+            Ok(edition) if installation_option.as_ref().is_some_and(|option| !edition.supports(option)) => {
+                return Err(String::from(ERR_UNKNOWN_EDITION));
             },
+            Ok(edition) => Editions(vec![edition]),
+            Err(_) => Editions::all(),
+        };
+
+        let windows = model::WindowsServer2019ff::build(&version.to_string(), None, service_channel)
+            .editions(editions)
+            .installation_option(installation_option);
+
+        if util::contains_any_word(label.raw, &["Hotpatch"]) && !windows.supports_hotpatch() {
+            Err(String::from(ERR_HOTPATCH))
+        } else {
+            Ok(windows)
         }
     }
 }
@@ -32,18 +59,15 @@ impl<'a> TryFrom<&GenericLabel<'a>> for Release {
     fn try_from(value: &GenericLabel<'a>) -> Result<Self, Self::Error> {
         let value = value.raw;
 
-        // Look for a build number or identify the release
-        if let Some(build) = util::find_number_with_digits(value, 5) {
-            util::resolve_build_to_release(build.as_str(), BUILD_TO_RELEASE_MAP)
-                .map_or_else(
-                    |_e| Err(String::from(ERR_UNKNOWN_RELEASE)),
-                    |release| Ok(Release::from(release.as_str()))
-                )
-        } else {
-            match util::identify_release(value, RELEASE_PATTERN) {
-                Some(release) => Ok(Release::from(release.as_str())),
-                None => Err(String::from(ERR_UNKNOWN_RELEASE)),
-            }
+        // The product name must not contradict the build, e.g. "Windows Server 2016" with build 17763
+        let stated = util::identify_release(value, RELEASE_PATTERN);
+        if stated.is_none() && util::find_number_after(value, "Windows Server").is_some() {
+            return Err(String::from(ERR_UNKNOWN_RELEASE));
+        }
+
+        match util::resolve_release_with_build(stated, value, 5, &[], BUILD_TO_RELEASE_MAP) {
+            Ok(Some(release)) => Ok(Release::from(release.as_str())),
+            _ => Err(String::from(ERR_UNKNOWN_RELEASE)),
         }
     }
 }
@@ -54,7 +78,12 @@ impl<'a> TryFrom<&GenericLabel<'a>> for Edition {
     fn try_from(value: &GenericLabel<'a>) -> Result<Self, Self::Error> {
         let value = value.raw;
 
-        if util::contains_any_word(value, &["Standard"]) {
+        // "Datacenter: Azure Edition" must not end up as "Datacenter"
+        if util::contains_any_word(value, &["Datacenter: Azure Edition", "Azure Edition"]) {
+            Ok(Edition::DatacenterAzure)
+        } else if util::contains_any_word(value, &["Essentials"]) {
+            Ok(Edition::Essentials)
+        } else if util::contains_any_word(value, &["Standard"]) {
             Ok(Edition::Standard)
         } else if util::contains_any_word(value, &["Datacenter"]) {
             Ok(Edition::Datacenter)
@@ -78,6 +107,24 @@ impl<'a> TryFrom<&GenericLabel<'a>> for ServiceChannel {
     }
 }
 
+impl<'a> TryFrom<&GenericLabel<'a>> for InstallationOption {
+    type Error = String;
+
+    fn try_from(value: &GenericLabel<'a>) -> Result<Self, Self::Error> {
+        let value = value.raw;
+
+        if util::contains_any_word(value, &["Server Core"]) {
+            Ok(InstallationOption::ServerCore)
+        } else if util::contains_any_word(value, &["Nano Server"]) {
+            Ok(InstallationOption::NanoServer)
+        } else if util::contains_any_word(value, &["Desktop Experience"]) {
+            Ok(InstallationOption::DesktopExperience)
+        } else {
+            Err(String::from(ERR_UNKNOWN_INSTALLATION_OPTION))
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,97 @@
+use crate::{model, util};
+use crate::model::windows_vista::{Edition, Editions, Release};
+use crate::parser::generic::GenericLabel;
+
+const ERR_UNKNOWN_PRODUCT: &str = "Not a Windows Vista.";
+const ERR_UNKNOWN_RELEASE: &str = "Not a Windows Vista release.";
+const ERR_UNKNOWN_EDITION: &str = "Not a Windows Vista edition.";
+
+include!(concat!(env!("OUT_DIR"), "/windows_vista_build_to_release_map.rs"));
+
+pub(crate) struct WindowsVistaParser();
+
+impl WindowsVistaParser {
+    pub(crate) fn parse(label: &GenericLabel) -> Result<model::WindowsVista, String> {
+        if util::contains_any_word(label.raw, &["Windows Vista"]) {
+            let release = Release::try_from(label)?;
+
+            match Edition::try_from(label) {
+                Ok(edition) => Ok(model::WindowsVista::build(release).editions(Editions(vec![edition]))),
+                Err(_) => Ok(model::WindowsVista::build(release).editions(Editions::all())),
+            }
+        } else {
+            Err(String::from(ERR_UNKNOWN_PRODUCT))
+        }
+    }
+}
+
+impl<'a> TryFrom<&GenericLabel<'a>> for Release {
+    type Error = String;
+
+    fn try_from(value: &GenericLabel<'a>) -> Result<Self, Self::Error> {
+        let value = value.raw;
+
+        let release = util::identify_service_pack(value, RELEASE_PATTERN)
+            .and_then(|stated| util::resolve_release_with_build(stated, value, 4, &[], BUILD_TO_RELEASE_MAP))
+            .map_err(|_| String::from(ERR_UNKNOWN_RELEASE))?
+            .unwrap_or(String::from("GA"));
+
+        Release::try_from(release.as_str())
+    }
+}
+
+impl<'a> TryFrom<&GenericLabel<'a>> for Edition {
+    type Error = String;
+
+    fn try_from(value: &GenericLabel<'a>) -> Result<Self, Self::Error> {
+        let value = value.raw;
+
+        let n = util::contains_any_word(value, &["N"]);
+        let x64 = util::contains_any_word(value, &["x64"]);
+        let bit64 = x64 || util::contains_any_word(value, &["64-bit", "64 bit"]);
+
+        if util::contains_any_word(value, &["Home Premium"]) {
+            if bit64 { Ok(Edition::HomePremium64bit) } else { Ok(Edition::HomePremium) }
+        } else if util::contains_any_word(value, &["Home Basic"]) {
+            match (n, bit64) {
+                (true, true) => Ok(Edition::HomeBasicN64bit),
+                (true, false) => Ok(Edition::HomeBasicN),
+                (false, true) => Ok(Edition::HomeBasic64bit),
+                (false, false) => Ok(Edition::HomeBasic),
+            }
+        } else if util::contains_any_word(value, &["Business"]) {
+            match (n, bit64) {
+                (true, true) => Ok(Edition::BusinessN64bit),
+                (true, false) => Ok(Edition::BusinessN),
+                (false, _) => Ok(Edition::Business),
+            }
+        } else if util::contains_any_word(value, &["Enterprise"]) {
+            if x64 {
+                Ok(Edition::EnterpriseX64)
+            } else if bit64 {
+                Ok(Edition::Enterprise64bit)
+            } else {
+                Ok(Edition::Enterprise)
+            }
+        } else if util::contains_any_word(value, &["Ultimate"]) {
+            if bit64 { Ok(Edition::Ultimate64bit) } else { Ok(Edition::Ultimate) }
+        } else if util::contains_any_word(value, &["Starter"]) {
+            Ok(Edition::Starter)
+        } else {
+            Err(String::from(ERR_UNKNOWN_EDITION))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util;
+
+    #[test]
+    fn test_build_to_release_1() {
+        let release = util::resolve_build_to_release("6002", BUILD_TO_RELEASE_MAP);
+
+        assert_eq!(release, Ok(String::from("SP2")));
+    }
+}
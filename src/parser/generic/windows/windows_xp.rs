@@ -0,0 +1,78 @@
+use crate::{model, util};
+use crate::model::windows_xp::{Edition, Editions, Release};
+use crate::parser::generic::GenericLabel;
+
+const ERR_UNKNOWN_PRODUCT: &str = "Not a Windows XP.";
+const ERR_UNKNOWN_RELEASE: &str = "Not a Windows XP release.";
+const ERR_UNKNOWN_EDITION: &str = "Not a Windows XP edition.";
+
+include!(concat!(env!("OUT_DIR"), "/windows_xp_build_to_release_map.rs"));
+
+pub(crate) struct WindowsXPParser();
+
+impl WindowsXPParser {
+    pub(crate) fn parse(label: &GenericLabel) -> Result<model::WindowsXP, String> {
+        // Windows XP Embedded is a product of its own
+        if util::contains_any_word(label.raw, &["Windows XP"]) && !util::contains_any_word(label.raw, &["Windows XP Embedded"]) {
+            let release = Release::try_from(label)?;
+
+            match Edition::try_from(label) {
+                Ok(edition) => Ok(model::WindowsXP::build(release).editions(Editions(vec![edition]))),
+                Err(_) => Ok(model::WindowsXP::build(release).editions(Editions::all())),
+            }
+        } else {
+            Err(String::from(ERR_UNKNOWN_PRODUCT))
+        }
+    }
+}
+
+impl<'a> TryFrom<&GenericLabel<'a>> for Release {
+    type Error = String;
+
+    fn try_from(value: &GenericLabel<'a>) -> Result<Self, Self::Error> {
+        let value = value.raw;
+
+        // Version and Media Center Edition years are not builds
+        let release = util::identify_service_pack(value, RELEASE_PATTERN)
+            .and_then(|stated| util::resolve_release_with_build(stated, value, 4, &["2002", "2003", "2004", "2005"], BUILD_TO_RELEASE_MAP))
+            .map_err(|_| String::from(ERR_UNKNOWN_RELEASE))?
+            .unwrap_or(String::from("GA"));
+
+        Release::try_from(release.as_str())
+    }
+}
+
+impl<'a> TryFrom<&GenericLabel<'a>> for Edition {
+    type Error = String;
+
+    fn try_from(value: &GenericLabel<'a>) -> Result<Self, Self::Error> {
+        let value = value.raw;
+
+        if util::contains_any_word(value, &["Professional for Embedded Systems"]) {
+            Ok(Edition::ProfessionalForEmbeddedSystems)
+        } else if util::contains_any_word(value, &["Professional x64", "x64"]) {
+            Ok(Edition::ProfessionalX64)
+        } else if util::contains_any_word(value, &["Professional"]) {
+            Ok(Edition::Professional)
+        } else if util::contains_any_word(value, &["Home Edition", "Home"]) {
+            Ok(Edition::Home)
+        } else if util::contains_any_word(value, &["Starter Edition", "Starter"]) {
+            Ok(Edition::Starter)
+        } else {
+            Err(String::from(ERR_UNKNOWN_EDITION))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util;
+
+    #[test]
+    fn test_build_to_release_1() {
+        let release = util::resolve_build_to_release("2600", BUILD_TO_RELEASE_MAP);
+
+        assert_eq!(release, Ok(String::from("GA")));
+    }
+}
@@ -41,6 +41,19 @@ pub fn find_number_with_digits(input: &str, digits: usize) -> Option<String> {
     re.find(input).map(|m| m.as_str().to_string())
 }
 
+pub fn find_numbers_with_digits(input: &str, digits: usize) -> Vec<String> {
+    let pattern = format!(r"\b\d{{{}}}\b", digits);
+    let re = Regex::new(&pattern).unwrap();
+    re.find_iter(input).map(|m| m.as_str().to_string()).collect()
+}
+
+// Rewrites "Service Pack 1" or "service pack 1a" as "SP1" and "SP1A" respectively
+pub fn normalize_service_pack(input: &str) -> String {
+    let re = Regex::new(r"(?i)\b(?:Service Pack|SP)\s*([0-9]+[a-z]?)\b").unwrap();
+    re.replace_all(input, |c: &regex::Captures| format!("SP{}", c[1].to_uppercase()))
+        .to_string()
+}
+
 pub fn resolve_build_to_release(build: &str, map: phf::Map<&'static str, &'static [&'static str]>) -> Result<String, String> {
     if let Some(release) = map.get(build) {
         Ok(release.get(0).unwrap().to_string())
@@ -49,12 +62,118 @@ pub fn resolve_build_to_release(build: &str, map: phf::Map<&'static str, &'stati
     }
 }
 
+// Service pack named in a label, e.g. "SP2" for "Service Pack 2"; one outside the pattern is an error
+pub(crate) fn identify_service_pack(input: &str, pattern: &str) -> Result<Option<String>, String> {
+    let normalized = normalize_service_pack(input);
+    let re = Regex::new(r"\bSP[0-9]+[A-Z]?\b").unwrap();
+
+    match re.find(&normalized) {
+        Some(service_pack) => identify_release(service_pack.as_str(), pattern)
+            .map(Some)
+            .ok_or(format!("Service pack '{}' does not exist.", service_pack.as_str())),
+        None => Ok(identify_release(&normalized, pattern)),
+    }
+}
+
+// Checks the release stated in a label against its build number, e.g. "SP1" against "6.1.7601".
+// Numbers in `ignore`, such as the year of a product name, are not builds. A build may carry a
+// revision, "2600.5512", that is looked up before the build itself. Without a stated release the
+// build decides; a build that is unknown or belongs to another release is an error.
+pub(crate) fn resolve_release_with_build(
+    stated: Option<String>,
+    input: &str,
+    digits: usize,
+    ignore: &[&str],
+    map: phf::Map<&'static str, &'static [&'static str]>,
+) -> Result<Option<String>, String> {
+    let re = Regex::new(&format!(r"\b(\d{{{}}})(?:\.(\d+))?\b", digits)).unwrap();
+    let builds: Vec<(String, Option<String>)> = re
+        .captures_iter(input)
+        .map(|c| (c[1].to_string(), c.get(2).map(|m| format!("{}.{}", &c[1], m.as_str()))))
+        .filter(|(build, _)| !ignore.contains(&build.as_str()))
+        .collect();
+
+    let Some((build, releases)) = builds.iter().find_map(|(build, revision)| {
+        revision
+            .as_ref()
+            .and_then(|revision| map.get(revision.as_str()).map(|releases| (revision, releases)))
+            .or_else(|| map.get(build.as_str()).map(|releases| (build, releases)))
+    }) else {
+        return match builds.first() {
+            Some((build, _)) => Err(format!("Build '{}' does not exist.", build)),
+            None => Ok(stated),
+        };
+    };
+
+    match stated {
+        Some(release) if !releases.contains(&release.as_str()) => {
+            Err(format!("Build '{}' does not belong to release '{}'.", build, release))
+        },
+        Some(release) => Ok(Some(release)),
+        None => Ok(releases.first().map(|release| release.to_string())),
+    }
+}
+
+// Reverse lookup of resolve_build_to_release, e.g. the API level of an Android release
+pub(crate) fn resolve_release_to_build(release: &str, map: phf::Map<&'static str, &'static [&'static str]>) -> Option<String> {
+    map.entries()
+        .find(|(_, releases)| releases.contains(&release))
+        .map(|(build, _)| build.to_string())
+}
+
+// A release of the map or the major or minor release of one, e.g. 17 or 17.6 for 17.6.1
+pub(crate) fn is_known_release(release: &str, map: phf::Map<&'static str, &'static [&'static str]>) -> bool {
+    map.values()
+        .flat_map(|releases| releases.iter())
+        .any(|known| *known == release || known.starts_with(&format!("{release}.")))
+}
+
 pub fn identify_release(input: &str, pattern: &str) -> Option<String> {
     let pattern = format!(r"\b({})\b", pattern);
     let re = Regex::new(&pattern).unwrap();
     re.find(input).map(|m| m.as_str().to_string())
 }
 
+// Removes every match of the pattern, e.g. a kernel version that would pass for a release
+pub(crate) fn remove_release(input: &str, pattern: &str) -> String {
+    let pattern = format!(r"\b({})\b", pattern);
+    let re = Regex::new(&pattern).unwrap();
+    re.replace_all(input, "").to_string()
+}
+
+pub fn find_number_after(input: &str, pattern: &str) -> Option<String> {
+    let pattern = format!(r"\b(?:{})\s+([0-9]+)", pattern);
+    let re = Regex::new(&pattern).unwrap();
+    re.captures(input).and_then(|c| c.get(1)).map(|m| m.as_str().to_string())
+}
+
+// Numeric major, minor and point components of a dotted release, e.g. "6.0.10"
+pub(crate) fn release_components(release: &str) -> (Option<u32>, Option<u32>, Option<u32>) {
+    let mut parts = release.split('.').map(|part| part.parse::<u32>().ok());
+
+    (
+        parts.next().flatten(),
+        parts.next().flatten(),
+        parts.next().flatten(),
+    )
+}
+
+// The original release (GA) is not rendered, only the service packs after it, e.g. SP2
+pub(crate) fn without_original_release<T: std::fmt::Display>(release: T) -> Option<T> {
+    Some(release).filter(|release| release.to_string() != "GA")
+}
+
+// Calendar date formatted as "YYYY-MM-DD", the only format support phases are compared in
+pub(crate) fn is_iso_date(date: &str) -> bool {
+    let re = Regex::new(r"^[0-9]{4}-(0[1-9]|1[0-2])-(0[1-9]|[12][0-9]|3[01])$").unwrap();
+    re.is_match(date)
+}
+
+// Leading components of a dotted release, e.g. "3.20" of "3.20.3" with two components
+pub(crate) fn truncate_release(release: &str, components: usize) -> String {
+    release.split('.').take(components).collect::<Vec<_>>().join(".")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -121,4 +240,88 @@ mod tests {
 
         assert_eq!(identify_release(label1, "22H2|24H2"), None);
     }
+
+    #[test]
+    fn test_find_numbers_with_digits() {
+        let numbers = find_numbers_with_digits("Windows XP Professional Version 2002 (Build 2600)", 4);
+
+        assert_eq!(numbers, vec!["2002".to_string(), "2600".to_string()]);
+    }
+
+    #[test]
+    fn test_normalize_service_pack() {
+        assert_eq!(normalize_service_pack("Windows 7 Service Pack 1"), "Windows 7 SP1".to_string());
+        assert_eq!(normalize_service_pack("Windows XP sp1a"), "Windows XP SP1A".to_string());
+        assert_eq!(normalize_service_pack("Windows Vista SP 2"), "Windows Vista SP2".to_string());
+    }
+
+    #[test]
+    fn test_find_number_after_some() {
+        let label1 = "Darwin Kernel Version 23.6.0: Mon Jul 29 21:14:30 PDT 2024";
+        let label2 = "Darwin 19.6.0";
+
+        assert_eq!(find_number_after(label1, "Darwin Kernel Version|Darwin"), Some(String::from("23")));
+        assert_eq!(find_number_after(label2, "Darwin Kernel Version|Darwin"), Some(String::from("19")));
+    }
+
+    #[test]
+    fn test_find_number_after_none() {
+        let label1 = "macOS Sonoma 14.6.1";
+
+        assert_eq!(find_number_after(label1, "Darwin Kernel Version|Darwin"), None);
+    }
+
+    #[test]
+    fn test_release_components() {
+        assert_eq!(release_components("6.0.10"), (Some(6), Some(0), Some(10)));
+        assert_eq!(release_components("22.04"), (Some(22), Some(4), None));
+        assert_eq!(release_components("9"), (Some(9), None, None));
+        assert_eq!(release_components("24H2"), (None, None, None));
+    }
+
+    #[test]
+    fn test_truncate_release() {
+        assert_eq!(truncate_release("3.20.3", 2), "3.20".to_string());
+        assert_eq!(truncate_release("2023.6.20241010", 1), "2023".to_string());
+        assert_eq!(truncate_release("14", 2), "14".to_string());
+    }
+
+    #[test]
+    fn test_without_original_release() {
+        assert_eq!(without_original_release("GA"), None);
+        assert_eq!(without_original_release("SP2"), Some("SP2"));
+    }
+
+    #[test]
+    fn test_is_iso_date() {
+        assert!(is_iso_date("2025-01-01"));
+        assert!(is_iso_date("2027-12-31"));
+        assert!(!is_iso_date("2025-1-1"));
+        assert!(!is_iso_date("01.01.2025"));
+        assert!(!is_iso_date("2025-13-01"));
+        assert!(!is_iso_date("2025-01-01T00:00:00"));
+        assert!(!is_iso_date(""));
+    }
+
+    #[test]
+    fn test_identify_service_pack() {
+        assert_eq!(identify_service_pack("Windows 7 Service Pack 1", "SP1|GA"), Ok(Some(String::from("SP1"))));
+        assert_eq!(identify_service_pack("Windows 7", "SP1|GA"), Ok(None));
+        assert!(identify_service_pack("Windows 7 SP2", "SP1|GA").is_err());
+    }
+
+    #[test]
+    fn test_resolve_release_with_build() {
+        const MAP: phf::Map<&'static str, &'static [&'static str]> = phf::phf_map! {
+            "2600" => &["GA", "SP3"],
+            "2600.5512" => &["SP3"],
+        };
+
+        assert_eq!(resolve_release_with_build(None, "Windows XP 5.1.2600", 4, &[], MAP), Ok(Some(String::from("GA"))));
+        assert_eq!(resolve_release_with_build(None, "Windows XP 5.1.2600.5512", 4, &[], MAP), Ok(Some(String::from("SP3"))));
+        assert_eq!(resolve_release_with_build(Some(String::from("SP3")), "Windows XP 2600", 4, &[], MAP), Ok(Some(String::from("SP3"))));
+        assert_eq!(resolve_release_with_build(None, "Windows XP Version 2002", 4, &["2002"], MAP), Ok(None));
+        assert!(resolve_release_with_build(Some(String::from("GA")), "Windows XP 2600.5512", 4, &[], MAP).is_err());
+        assert!(resolve_release_with_build(None, "Windows XP 9600", 4, &[], MAP).is_err());
+    }
 }
@@ -6,5 +6,256 @@ fn test_ubuntu_24_04_generic_1() {
 
     assert_eq!(linux.vendor(), "Canonical".to_string());
     assert_eq!(linux.product(), "Ubuntu Linux".to_string());
-    assert_eq!(linux.to_string().get(0).unwrap(), "Ubuntu Linux 24.04 LTS");
+    assert_eq!(linux.to_string().first().unwrap(), "Ubuntu Linux 24.04 LTS");
+}
+
+#[test]
+fn test_rhel_7_9_server_generic_1() {
+    use os_identifier::Linux;
+
+    let linux = Linux::parse("Red Hat Enterprise Linux Server release 7.9 (Maipo)").unwrap();
+
+    assert_eq!(linux.vendor(), "Red Hat".to_string());
+    assert_eq!(linux.release(), "7.9".to_string());
+    assert_eq!(linux.to_string(), vec!["Red Hat Enterprise Linux Server 7.9".to_string()]);
+}
+
+#[test]
+fn test_rhel_8_8_sap_e4s_generic_1() {
+    use os_identifier::Linux;
+
+    let linux = Linux::parse("Red Hat Enterprise Linux for SAP Solutions 8.8 E4S").unwrap();
+
+    assert_eq!(linux.to_string(), vec!["Red Hat Enterprise Linux for SAP Solutions 8.8 E4S".to_string()]);
+}
+
+#[test]
+fn test_amazon_linux_2023_generic_1() {
+    use os_identifier::Linux;
+
+    let linux = Linux::parse("Amazon Linux 2023.6.20241010").unwrap();
+
+    assert_eq!(linux.vendor(), "Amazon".to_string());
+    assert_eq!(linux.product(), "Amazon Linux".to_string());
+    assert_eq!(linux.release(), "2023.6.20241010".to_string());
+    assert!(linux.is_lts());
+}
+
+#[test]
+fn test_amazon_linux_ami_generic_1() {
+    use os_identifier::Linux;
+
+    let linux = Linux::parse("Amazon Linux AMI 2018.03").unwrap();
+
+    assert_eq!(linux.to_string().first().unwrap(), "Amazon Linux AMI 2018.03");
+}
+
+#[test]
+fn test_alpine_linux_3_20_generic_1() {
+    use os_identifier::Linux;
+
+    let linux = Linux::parse("Alpine Linux v3.20").unwrap();
+
+    assert_eq!(linux.vendor(), "Alpine".to_string());
+    assert_eq!(linux.product(), "Alpine Linux".to_string());
+    assert_eq!(linux.to_string().first().unwrap(), "Alpine Linux 3.20");
+}
+
+#[test]
+fn test_ubuntu_18_04_esm_generic_1() {
+    use os_identifier::Linux;
+
+    let linux = Linux::parse("Ubuntu 18.04.6 LTS ESM").unwrap();
+
+    assert!(linux.is_lts());
+    assert_eq!(linux.service_channel(), Some("ESM".to_string()));
+    assert_eq!(linux.to_string().first().unwrap(), "Ubuntu Linux 18.04.6 ESM");
+}
+
+#[test]
+fn test_ubuntu_22_04_pro_generic_1() {
+    use os_identifier::Linux;
+
+    let linux = Linux::parse("Ubuntu Pro 22.04 LTS").unwrap();
+
+    assert!(linux.is_lts());
+    assert_eq!(linux.service_channel(), None);
+    assert_eq!(linux.service_channel_at("2026-10-19"), None);
+    assert_eq!(linux.service_channel_at("2027-07-01"), Some("ESM".to_string()));
+    assert_eq!(linux.to_string().first().unwrap(), "Ubuntu Linux 22.04 LTS");
+}
+
+#[test]
+fn test_rhel_8_6_eus_generic_1() {
+    use os_identifier::Linux;
+
+    let linux = Linux::parse("RHEL 8.6 EUS").unwrap();
+
+    assert_eq!(linux.service_channel(), Some("EUS".to_string()));
+    assert_eq!(linux.to_string().first().unwrap(), "Red Hat Enterprise Linux 8.6 EUS");
+}
+
+#[test]
+fn test_ubuntu_22_04_4_release_components() {
+    use os_identifier::Linux;
+
+    let linux = Linux::parse("Ubuntu 22.04.4 LTS").unwrap();
+
+    assert_eq!(linux.release(), "22.04.4".to_string());
+    assert_eq!(linux.release_major(), Some(22));
+    assert_eq!(linux.release_minor(), Some(4));
+    assert_eq!(linux.release_point(), Some(4));
+    assert_eq!(linux.release_cycle(), "22.04".to_string());
+    assert!(linux.is_lts());
+}
+
+#[test]
+fn test_debian_6_0_10_release_components() {
+    use os_identifier::Linux;
+
+    let linux = Linux::parse("debian-6.0.10").unwrap();
+
+    assert_eq!(linux.release_major(), Some(6));
+    assert_eq!(linux.release_minor(), Some(0));
+    assert_eq!(linux.release_point(), Some(10));
+    assert_eq!(linux.release_cycle(), "6".to_string());
+}
+
+#[test]
+fn test_rhel_9_major_only_generic_1() {
+    use os_identifier::Linux;
+
+    let linux = Linux::parse("RHEL 9").unwrap();
+
+    assert_eq!(linux.release(), "9".to_string());
+    assert_eq!(linux.release_major(), Some(9));
+    assert_eq!(linux.release_minor(), None);
+    assert_eq!(linux.release_cycle(), "9".to_string());
+}
+
+#[test]
+fn test_oracle_linux_8_10_release_components() {
+    use os_identifier::Linux;
+
+    let linux = Linux::parse("Oracle Linux Server release 8.10").unwrap();
+
+    assert_eq!(linux.release_major(), Some(8));
+    assert_eq!(linux.release_minor(), Some(10));
+    assert_eq!(linux.release_cycle(), "8".to_string());
+}
+
+#[test]
+fn test_oracle_linux_uek_generic_1() {
+    use os_identifier::Linux;
+
+    let linux = Linux::parse("Oracle Linux Server release 8.9 UEK R6").unwrap();
+
+    assert_eq!(linux.release(), "8.9".to_string());
+    assert_eq!(linux.kernel_flavour(), Some("UEK".to_string()));
+    assert_eq!(linux.uek_release(), Some("R6".to_string()));
+}
+
+#[test]
+fn test_arch_linux_generic_1() {
+    use os_identifier::Linux;
+
+    let linux = Linux::parse("Arch Linux").unwrap();
+
+    assert_eq!(linux.product(), "Arch Linux".to_string());
+    assert!(linux.is_rolling());
+    assert!(!linux.is_pre_release());
+}
+
+#[test]
+fn test_gentoo_generic_1() {
+    use os_identifier::Linux;
+
+    let linux = Linux::parse("Gentoo Base System release 2.17").unwrap();
+
+    assert_eq!(linux.product(), "Gentoo Linux".to_string());
+    assert!(linux.is_rolling());
+}
+
+#[test]
+fn test_debian_testing_generic_1() {
+    use os_identifier::Linux;
+
+    let linux = Linux::parse("Debian GNU/Linux trixie/sid").unwrap();
+
+    assert_eq!(linux.release(), "testing".to_string());
+    assert!(linux.is_pre_release());
+    assert!(!linux.is_rolling());
+}
+
+#[test]
+fn test_ubuntu_development_branch_generic_1() {
+    use os_identifier::Linux;
+
+    let linux = Linux::parse("Ubuntu 25.04 (development branch)").unwrap();
+
+    assert_eq!(linux.release(), "25.04".to_string());
+    assert!(linux.is_pre_release());
+}
+
+#[test]
+fn test_linux_mint_22_based_on() {
+    use os_identifier::Linux;
+
+    let linux = Linux::parse("Linux Mint 22").unwrap();
+    let base = linux.based_on().unwrap();
+
+    assert_eq!(linux.product(), "Linux Mint".to_string());
+    assert_eq!(base.product(), "Ubuntu Linux".to_string());
+    assert_eq!(base.release(), "24.04".to_string());
+}
+
+#[test]
+fn test_lmde_6_based_on() {
+    use os_identifier::Linux;
+
+    let linux = Linux::parse("Linux Mint Debian Edition 6").unwrap();
+
+    assert_eq!(linux.product(), "Linux Mint".to_string());
+    assert_eq!(linux.based_on().unwrap().product(), "Debian Linux".to_string());
+    assert_eq!(linux.based_on().unwrap().release(), "12".to_string());
+}
+
+#[test]
+fn test_devuan_5_based_on() {
+    use os_identifier::Linux;
+
+    let linux = Linux::parse("Devuan GNU/Linux 5 (daedalus)").unwrap();
+
+    assert_eq!(linux.based_on().unwrap().release(), "12".to_string());
+}
+
+#[test]
+fn test_raspberry_pi_os_based_on() {
+    use os_identifier::Linux;
+
+    let linux = Linux::parse("Raspbian GNU/Linux 11 (bullseye)").unwrap();
+
+    assert_eq!(linux.product(), "Raspberry Pi OS".to_string());
+    assert_eq!(linux.based_on().unwrap().release(), "11".to_string());
+}
+
+#[test]
+fn test_kali_linux_based_on() {
+    use os_identifier::Linux;
+
+    let linux = Linux::parse("Kali GNU/Linux Rolling").unwrap();
+
+    assert!(linux.is_rolling());
+    assert!(linux.based_on().unwrap().is_pre_release());
+}
+
+#[test]
+fn test_rhel_coreos_generic_1() {
+    use os_identifier::Linux;
+
+    let linux = Linux::parse("Red Hat Enterprise Linux CoreOS 4.14").unwrap();
+
+    assert_eq!(linux.product(), "Red Hat Enterprise Linux CoreOS".to_string());
+    assert_eq!(linux.release(), "4.14".to_string());
+    assert!(linux.compatible_with().is_empty());
 }
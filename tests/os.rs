@@ -77,6 +77,19 @@ fn test_windows_server_generic_2() {
     assert!(canonical_names.contains(&String::from("Microsoft Windows Server 2025 Datacenter")));
 }
 
+#[test]
+fn test_windows_server_generic_3() {
+    use os_identifier::OS;
+
+    let windows = OS::parse("Windows Server 23H2 Annual Channel");
+    assert!(windows.is_ok());
+
+    let windows = windows.unwrap();
+    assert_eq!(windows.product(), "Windows Server".to_string());
+    let canonical_names = windows.to_string();
+    assert!(canonical_names.contains(&String::from("Microsoft Windows Server Datacenter 23H2")));
+}
+
 #[test]
 fn test_debian_13_endoflife_1() {
     use os_identifier::OS;
@@ -141,6 +154,97 @@ fn test_ubuntu_24_04_generic_1() {
     assert!(os.is_lts());
 }
 
+#[test]
+fn test_macos_14_endoflife_1() {
+    use os_identifier::OS;
+
+    let os = OS::parse("macos-14");
+    assert!(os.is_ok());
+
+    let os = os.unwrap();
+    assert_eq!(os.vendor(), "Apple".to_string());
+    assert_eq!(os.product(), "macOS".to_string());
+    assert_eq!(os.release(), "14".to_string());
+    assert!(os.to_string().contains(&String::from("Apple macOS 14 Sonoma")));
+
+    assert!(!os.is_enterprise());
+    assert!(!os.is_lts());
+}
+
+#[test]
+fn test_macos_generic_1() {
+    use os_identifier::OS;
+
+    let os = OS::parse("macOS 14.6.1");
+    assert!(os.is_ok());
+
+    let os = os.unwrap();
+    assert_eq!(os.release(), "14.6.1".to_string());
+    assert!(os.to_string().contains(&String::from("Apple macOS 14 Sonoma")));
+}
+
+#[test]
+fn test_macos_generic_2() {
+    use os_identifier::OS;
+
+    let os = OS::parse("Mac OS X El Capitan");
+    assert!(os.is_ok());
+
+    let os = os.unwrap();
+    assert_eq!(os.product(), "OS X".to_string());
+    assert!(os.to_string().contains(&String::from("Apple OS X 10.11 El Capitan")));
+}
+
+#[test]
+fn test_freebsd_endoflife_1() {
+    use os_identifier::OS;
+
+    let os = OS::parse("freebsd-14.1");
+    assert!(os.is_ok());
+
+    let os = os.unwrap();
+    assert_eq!(os.vendor(), "The FreeBSD Project".to_string());
+    assert_eq!(os.product(), "FreeBSD".to_string());
+    assert_eq!(os.release(), "14.1".to_string());
+    assert!(os.to_string().contains(&String::from("FreeBSD 14.1-RELEASE")));
+}
+
+#[test]
+fn test_freebsd_generic_1() {
+    use os_identifier::OS;
+
+    let os = OS::parse("FreeBSD 14.1-RELEASE-p5");
+    assert!(os.is_ok());
+
+    let os = os.unwrap();
+    assert_eq!(os.release(), "14.1".to_string());
+    assert!(os.to_string().contains(&String::from("FreeBSD 14.1-RELEASE-p5")));
+}
+
+#[test]
+fn test_windows_server_core_endoflife_1() {
+    use os_identifier::OS;
+
+    let os = OS::parse("windows-server-core-2022");
+    assert!(os.is_ok());
+
+    let os = os.unwrap();
+    assert_eq!(os.product(), "Windows Server 2022".to_string());
+    assert_eq!(os.installation_option(), Some("Server Core".to_string()));
+}
+
+#[test]
+fn test_windows_server_core_generic_1() {
+    use os_identifier::OS;
+
+    let os = OS::parse("Windows Server 2022 Datacenter (Server Core)");
+    assert!(os.is_ok());
+
+    let os = os.unwrap();
+    assert_eq!(os.installation_option(), Some("Server Core".to_string()));
+    assert!(os.to_string().contains(&String::from("Microsoft Windows Server 2022 Datacenter Server Core")));
+}
+
 #[test]
 fn test_unknown_endoflife_1() {
     use os_identifier::OS;
@@ -156,3 +260,257 @@ fn test_unknown_generic_1() {
     let windows = OS::parse("Ops unknown");
     assert!(windows.is_err());
 }
+
+#[test]
+fn test_ubuntu_flavour_generic_1() {
+    use os_identifier::OS;
+
+    let os = OS::parse("Xubuntu 24.04 LTS").unwrap();
+
+    assert_eq!(os.vendor(), "Canonical".to_string());
+    assert_eq!(os.to_string(), vec!["Xubuntu 24.04 LTS".to_string()]);
+}
+
+#[test]
+fn test_ubuntu_server_generic_1() {
+    use os_identifier::OS;
+
+    let os = OS::parse("Ubuntu Server 22.04").unwrap();
+
+    assert_eq!(os.to_string(), vec!["Ubuntu Linux Server 22.04 LTS".to_string()]);
+}
+
+#[test]
+fn test_release_components_not_numbered() {
+    use os_identifier::OS;
+
+    let os = OS::parse("windows-11-24h2-e-lts").unwrap();
+
+    assert_eq!(os.release_major(), None);
+    assert_eq!(os.release_cycle(), os.release());
+}
+
+#[test]
+fn test_linux_mint_based_on() {
+    use os_identifier::OS;
+
+    let os = OS::parse("Linux Mint 21.3 Cinnamon").unwrap();
+
+    assert_eq!(os.based_on().unwrap().to_string(), vec!["Ubuntu Linux 22.04 LTS".to_string()]);
+    assert!(OS::parse("Debian GNU/Linux 13 (trixie)").unwrap().based_on().is_none());
+}
+
+#[test]
+fn test_oracle_linux_compatible_with_rhel() {
+    use os_identifier::OS;
+
+    let os = OS::parse("Oracle Linux Server release 9.4").unwrap();
+    let rhel = os.compatible_with();
+
+    assert_eq!(rhel.len(), 1);
+    assert_eq!(rhel[0].to_string(), vec!["Red Hat Enterprise Linux 9.4".to_string()]);
+    assert_eq!(os.based_on().unwrap().product(), "Red Hat Enterprise Linux".to_string());
+
+    let oracle = OS::parse("rhel-9.4").unwrap().compatible_with();
+
+    assert_eq!(oracle[0].to_string(), vec!["Oracle Linux 9.4".to_string()]);
+}
+
+#[test]
+fn test_ubuntu_based_on_debian() {
+    use os_identifier::OS;
+
+    let debian = OS::parse("Ubuntu 24.04 LTS").unwrap().based_on().unwrap();
+
+    assert_eq!(debian.product(), "Debian Linux".to_string());
+    assert_eq!(debian.release(), "13".to_string());
+    assert!(!debian.is_pre_release());
+}
+
+#[test]
+fn test_windows_server_compatible_with_windows_10() {
+    use os_identifier::OS;
+
+    let os = OS::parse("windows-server-2019").unwrap();
+    let windows = os.compatible_with();
+
+    assert_eq!(windows.len(), 1);
+    assert_eq!(windows[0].product(), "Windows 10".to_string());
+    assert_eq!(windows[0].release(), "1809".to_string());
+
+    let server = OS::parse("windows-10-1809-e-lts").unwrap().compatible_with();
+
+    assert_eq!(server[0].product(), "Windows Server 2019".to_string());
+
+    let server = OS::parse("windows-11-24h2-e-lts").unwrap().compatible_with();
+
+    assert_eq!(server[0].product(), "Windows Server 2025".to_string());
+    assert!(OS::parse("windows-server-2022").unwrap().compatible_with().is_empty());
+}
+
+#[test]
+fn test_windows_server_based_on_windows_10() {
+    use os_identifier::OS;
+
+    let windows = OS::parse("Windows Server 2019 Datacenter").unwrap().based_on().unwrap();
+
+    assert_eq!(windows.product(), "Windows 10".to_string());
+    assert_eq!(windows.release(), "1809".to_string());
+
+    let windows = OS::parse("windows-server-2016").unwrap().based_on().unwrap();
+
+    assert_eq!(windows.release(), "1607".to_string());
+    assert!(OS::parse("windows-server-2022").unwrap().based_on().is_none());
+    assert!(OS::parse("windows-10-1809-e-lts").unwrap().based_on().is_none());
+}
+
+#[test]
+fn test_hypervisor_esxi() {
+    use os_identifier::OS;
+
+    let os = OS::parse("VMware ESXi 8.0.3 build-24022510").unwrap();
+
+    assert_eq!(os.vendor(), "VMware".to_string());
+    assert_eq!(os.product(), "VMware ESXi".to_string());
+    assert_eq!(os.release(), "8.0 U3".to_string());
+    assert!(os.is_enterprise());
+    assert_eq!(os.to_string(), vec!["VMware ESXi 8.0 U3".to_string()]);
+}
+
+#[test]
+fn test_hypervisor_hyperv_server() {
+    use os_identifier::{Hypervisor, OS};
+
+    let os = OS::parse("Microsoft Hyper-V Server 2019").unwrap();
+
+    assert_eq!(os.product(), "Hyper-V Server".to_string());
+    assert_eq!(os.to_string(), vec!["Microsoft Hyper-V Server 2019".to_string()]);
+
+    let hypervisor = Hypervisor::parse("Microsoft Hyper-V Server 2012 R2").unwrap();
+
+    assert_eq!(hypervisor.release(), "2012 R2".to_string());
+    assert!(hypervisor.is_lts());
+}
+
+#[test]
+fn test_hypervisor_proxmox_and_xenserver() {
+    use os_identifier::OS;
+
+    let os = OS::parse("proxmox-ve-8").unwrap();
+
+    assert_eq!(os.to_string(), vec!["Proxmox VE 8".to_string()]);
+
+    let os = OS::parse("Citrix Hypervisor 8.2 CU1").unwrap();
+
+    assert_eq!(os.vendor(), "Citrix".to_string());
+    assert_eq!(os.product(), "Citrix Hypervisor".to_string());
+    assert!(os.is_lts());
+}
+
+#[test]
+fn test_mobile_android() {
+    use os_identifier::{Mobile, OS};
+
+    let os = OS::parse("Android 14 (API 34)").unwrap();
+
+    assert_eq!(os.vendor(), "Google".to_string());
+    assert_eq!(os.product(), "Android".to_string());
+    assert_eq!(os.release(), "14".to_string());
+
+    let mobile = Mobile::parse("android-13").unwrap();
+
+    assert_eq!(mobile.api_level(), Some(33));
+    assert_eq!(mobile.to_string(), vec!["Android 13 (API 33)".to_string()]);
+}
+
+#[test]
+fn test_mobile_ios() {
+    use os_identifier::{Mobile, OS};
+
+    let os = OS::parse("iOS 17.6.1 (21G93)").unwrap();
+
+    assert_eq!(os.vendor(), "Apple".to_string());
+    assert_eq!(os.product(), "iOS".to_string());
+    assert_eq!(os.release(), "17.6.1".to_string());
+
+    let mobile = Mobile::parse("iPadOS 21G93").unwrap();
+
+    assert_eq!(mobile.product(), "iPadOS".to_string());
+    assert_eq!(mobile.release(), "17.6.1".to_string());
+    assert_eq!(mobile.build(), Some("21G93".to_string()));
+    assert_eq!(OS::parse("ios-17").unwrap().to_string(), vec!["Apple iOS 17".to_string()]);
+}
+
+#[test]
+fn test_debian_support_phase_label() {
+    use os_identifier::OS;
+
+    let os = OS::parse("debian-10-elts").unwrap();
+
+    assert_eq!(os.product(), "Debian Linux".to_string());
+    assert_eq!(os.service_channel(), Some("ELTS".to_string()));
+    assert!(os.is_lts());
+
+    assert!(OS::parse("foo-10-bar").is_err());
+    assert!(OS::parse("debian-12-elts").is_err());
+    assert!(OS::parse("Debian 12 ELTS").is_err());
+}
+
+#[test]
+fn test_debian_support_phase_at() {
+    use os_identifier::OS;
+
+    let os = OS::parse("Debian GNU/Linux 10 (buster)").unwrap();
+
+    assert_eq!(os.service_channel(), None);
+    assert!(!os.is_lts());
+    assert_eq!(os.to_string(), vec!["Debian Linux 10".to_string()]);
+    assert_eq!(os.service_channel_at("2021-01-01"), None);
+    assert_eq!(os.service_channel_at("2023-01-01"), Some("LTS".to_string()));
+    assert_eq!(os.service_channel_at("2025-01-01"), Some("ELTS".to_string()));
+    assert_eq!(os.service_channel_at("2030-01-01"), Some("EOL".to_string()));
+}
+
+#[test]
+fn test_hyperv_server_is_a_hypervisor_only() {
+    use os_identifier::{Windows, OS};
+
+    assert_eq!(OS::parse("Hyper-V Server 2019").unwrap().product(), "Hyper-V Server".to_string());
+    assert!(Windows::parse("Hyper-V Server 2019").is_err());
+    assert!(Windows::parse("Microsoft Hyper-V Server 2016").is_err());
+}
+
+#[test]
+fn test_release_cycle_per_family() {
+    use os_identifier::OS;
+
+    let os = OS::parse("Alpine Linux v3.20.3").unwrap();
+
+    assert_eq!(os.release_cycle(), "3.20".to_string());
+    assert_eq!(os.release_point(), Some(3));
+
+    let os = OS::parse("VMware ESXi 7.0.3 build-21930508").unwrap();
+
+    assert_eq!(os.release_cycle(), "7.0".to_string());
+    assert_eq!((os.release_major(), os.release_minor(), os.release_point()), (Some(7), Some(0), Some(3)));
+
+    assert_eq!(OS::parse("Amazon Linux 2023.6.20241010").unwrap().release_cycle(), "2023".to_string());
+    assert_eq!(OS::parse("Proxmox VE 8.2.4").unwrap().release_cycle(), "8".to_string());
+    assert_eq!(OS::parse("iOS 17.6.1").unwrap().release_cycle(), "17".to_string());
+    assert_eq!(OS::parse("Linux Mint 21.3").unwrap().release_cycle(), "21.3".to_string());
+    assert_eq!(OS::parse("Mac OS X 10.15.7").unwrap().release_cycle(), "10.15".to_string());
+    assert_eq!(OS::parse("Windows 11 Pro 24H2").unwrap().release_major(), None);
+    assert_eq!(OS::parse("Windows Server 2022 Standard 21H2").unwrap().release_cycle(), "2022".to_string());
+    assert_eq!(OS::parse("windows-server-2016").unwrap().release_cycle(), "2016".to_string());
+    assert_eq!(OS::parse("Windows Server 2008 R2 SP1").unwrap().release_cycle(), "2008 R2".to_string());
+    assert_eq!(OS::parse("Windows Server 1809").unwrap().release_cycle(), "1809".to_string());
+}
+
+#[test]
+fn test_os_windows_on_android_is_windows() {
+    use os_identifier::OS;
+
+    let os = OS::parse("Windows 10 Pro 22H2 on Android 14").unwrap();
+
+    assert_eq!(os.product(), "Windows 10".to_string());
+}
@@ -10,6 +10,30 @@ fn test_windows_7() {
     assert!(canonical_names.contains(&String::from("Microsoft Windows 7 Ultimate SP1")));
 }
 
+#[test]
+fn test_windows_endoflife_other_product() {
+    use os_identifier::Windows;
+
+    // Labels of other products sharing the shape of Windows labels
+    assert!(Windows::parse("debian-10-elts").is_err());
+    assert!(Windows::parse("debian-11-lts-e").is_err());
+    assert!(Windows::parse("ubuntu-server-2022").is_err());
+}
+
+#[test]
+fn test_windows_server_release_cycle() {
+    use os_identifier::Windows;
+
+    let windows = Windows::parse("Windows Server 2019 Datacenter").unwrap();
+
+    assert_eq!(windows.release(), "".to_string());
+    assert_eq!(windows.release_cycle(), "2019".to_string());
+
+    let windows = Windows::parse("Windows Server 2012 R2 Standard").unwrap();
+
+    assert_eq!(windows.release_cycle(), "2012 R2".to_string());
+}
+
 #[test]
 fn test_windows_8() {
     use os_identifier::Windows;
@@ -67,7 +91,7 @@ fn test_windows_11_endoflife_2() {
 
     let windows = windows.unwrap();
     let canonical_names = windows.to_string();
-    assert!(canonical_names.contains(&String::from("Microsoft Windows 11 Enterprise 24H2 LTSC")));
+    assert!(canonical_names.contains(&String::from("Microsoft Windows 11 Enterprise LTSC 2024")));
 }
 
 #[test]
@@ -115,7 +139,7 @@ fn test_windows_11_generic_2_w_sc_lts() {
 
     let windows = windows.unwrap();
     let canonical_names = windows.to_string();
-    assert!(canonical_names.contains(&String::from("Microsoft Windows 11 Enterprise 24H2 LTSC")));
+    assert!(canonical_names.contains(&String::from("Microsoft Windows 11 Enterprise LTSC 2024")));
 }
 
 #[test]
@@ -310,3 +334,252 @@ fn test_windows_xp() {
     let canonical_names = windows.to_string();
     assert!(canonical_names.contains(&String::from("Microsoft Windows XP Professional x64 SP3")));
 }
+
+#[test]
+fn test_windows_embedded_endoflife() {
+    use os_identifier::Windows;
+
+    let windows = Windows::parse("windows-embedded-compact-2013");
+    assert!(windows.is_ok());
+
+    let windows = windows.unwrap();
+    let canonical_names = windows.to_string();
+    assert!(canonical_names.contains(&String::from("Microsoft Windows Embedded Compact 2013")));
+}
+
+#[test]
+fn test_windows_embedded_generic() {
+    use os_identifier::Windows;
+
+    let windows = Windows::parse("Windows Embedded 8.1 Industry Pro");
+    assert!(windows.is_ok());
+
+    let windows = windows.unwrap();
+    assert_eq!(windows.release(), "8.1".to_string());
+    let canonical_names = windows.to_string();
+    assert!(canonical_names.contains(&String::from("Microsoft Windows Embedded 8.1 Industry Pro")));
+}
+
+#[test]
+fn test_windows_7_generic() {
+    use os_identifier::Windows;
+
+    let windows = Windows::parse("Microsoft Windows 7 Professional Service Pack 1");
+    assert!(windows.is_ok());
+
+    let windows = windows.unwrap();
+    assert_eq!(windows.release(), "SP1".to_string());
+    let canonical_names = windows.to_string();
+    assert!(canonical_names.contains(&String::from("Microsoft Windows 7 Professional SP1")));
+}
+
+#[test]
+fn test_windows_8_generic() {
+    use os_identifier::Windows;
+
+    let windows = Windows::parse("Windows 8.1 Pro (Build 9600)");
+    assert!(windows.is_ok());
+
+    let windows = windows.unwrap();
+    assert_eq!(windows.product(), "Windows 8.1".to_string());
+    let canonical_names = windows.to_string();
+    assert!(canonical_names.contains(&String::from("Microsoft Windows 8.1 Professional")));
+}
+
+#[test]
+fn test_windows_server_2008_r2_generic() {
+    use os_identifier::Windows;
+
+    let windows = Windows::parse("Windows Server 2008 R2 Enterprise SP1");
+    assert!(windows.is_ok());
+
+    let windows = windows.unwrap();
+    assert_eq!(windows.product(), "Windows Server 2008 R2".to_string());
+    assert_eq!(windows.release(), "SP1".to_string());
+}
+
+#[test]
+fn test_windows_server_2012_r2_generic() {
+    use os_identifier::Windows;
+
+    let windows = Windows::parse("Windows Server 2012 R2 Standard (Build 9600)");
+    assert!(windows.is_ok());
+
+    let windows = windows.unwrap();
+    assert_eq!(windows.product(), "Windows Server 2012 R2".to_string());
+    let canonical_names = windows.to_string();
+    assert!(canonical_names.contains(&String::from("Microsoft Windows Server 2012 R2 Standard")));
+}
+
+#[test]
+fn test_windows_server_2016_generic() {
+    use os_identifier::Windows;
+
+    let windows = Windows::parse("Windows Server 2016 Datacenter 14393");
+    assert!(windows.is_ok());
+
+    let windows = windows.unwrap();
+    assert_eq!(windows.product(), "Windows Server 2016".to_string());
+    let canonical_names = windows.to_string();
+    assert!(canonical_names.contains(&String::from("Microsoft Windows Server 2016 Datacenter")));
+}
+
+#[test]
+fn test_windows_server_1709ff_generic() {
+    use os_identifier::Windows;
+
+    let windows = Windows::parse("Windows Server Datacenter Version 20H2 (Build 19042)");
+    assert!(windows.is_ok());
+
+    let windows = windows.unwrap();
+    assert_eq!(windows.product(), "Windows Server".to_string());
+    assert_eq!(windows.release(), "20H2".to_string());
+    let canonical_names = windows.to_string();
+    assert!(canonical_names.contains(&String::from("Microsoft Windows Server Datacenter 20H2")));
+}
+
+#[test]
+fn test_windows_server_1809_generic() {
+    use os_identifier::Windows;
+
+    let windows = Windows::parse("Windows Server Standard, Version 1809 (Build 17763)").unwrap();
+    assert_eq!(windows.product(), "Windows Server".to_string());
+    assert_eq!(windows.release(), "1809".to_string());
+
+    let windows = Windows::parse("Windows Server 2019 Standard (Build 17763)").unwrap();
+    assert_eq!(windows.product(), "Windows Server 2019".to_string());
+}
+
+#[test]
+fn test_windows_11_generic_wo_edition() {
+    use os_identifier::Windows;
+
+    let windows = Windows::parse("Microsoft Windows 11 (Build 22631)");
+    assert!(windows.is_ok());
+
+    let windows = windows.unwrap();
+    assert_eq!(windows.release(), "23H2".to_string());
+    assert!(!windows.is_edition_known());
+    let canonical_names = windows.to_string();
+    assert!(canonical_names.contains(&String::from("Microsoft Windows 11 Pro 23H2")));
+    assert!(canonical_names.contains(&String::from("Microsoft Windows 11 Enterprise 23H2")));
+}
+
+#[test]
+fn test_windows_10_generic_edition_known() {
+    use os_identifier::Windows;
+
+    let windows = Windows::parse("Microsoft Windows 10 Pro 17763").unwrap();
+    assert!(windows.is_edition_known());
+}
+
+#[test]
+fn test_windows_10_generic_pro_for_workstations() {
+    use os_identifier::Windows;
+
+    let windows = Windows::parse("Windows 10 Pro for Workstations (Build 19045)");
+    assert!(windows.is_ok());
+
+    let windows = windows.unwrap();
+    let canonical_names = windows.to_string();
+    assert_eq!(canonical_names, vec![String::from("Microsoft Windows 10 Pro for Workstations 22H2")]);
+}
+
+#[test]
+fn test_windows_10_generic_ltsb_year() {
+    use os_identifier::Windows;
+
+    let windows = Windows::parse("Windows 10 Enterprise 2015 LTSB");
+    assert!(windows.is_ok());
+
+    let windows = windows.unwrap();
+    assert_eq!(windows.release(), "1507".to_string());
+    assert!(windows.is_lts());
+    let canonical_names = windows.to_string();
+    assert!(canonical_names.contains(&String::from("Microsoft Windows 10 Enterprise 2015 LTSB")));
+}
+
+#[test]
+fn test_windows_10_generic_cbb() {
+    use os_identifier::Windows;
+
+    let windows = Windows::parse("Windows 10 Enterprise 1607 Current Branch for Business");
+    assert!(windows.is_ok());
+
+    let windows = windows.unwrap();
+    assert!(!windows.is_lts());
+    let canonical_names = windows.to_string();
+    assert!(canonical_names.contains(&String::from("Microsoft Windows 10 Enterprise 1607 CBB")));
+}
+
+#[test]
+fn test_windows_server_azure_edition_generic() {
+    use os_identifier::Windows;
+
+    let windows = Windows::parse("Windows Server 2022 Datacenter: Azure Edition (Build 20348)");
+    assert!(windows.is_ok());
+
+    let windows = windows.unwrap();
+    assert_eq!(windows.product(), "Windows Server 2022".to_string());
+    let canonical_names = windows.to_string();
+    assert_eq!(canonical_names, vec![String::from("Microsoft Windows Server 2022 Datacenter: Azure Edition")]);
+}
+
+#[test]
+fn test_windows_server_hotpatch_generic() {
+    use os_identifier::Windows;
+
+    let windows = Windows::parse("Windows Server 2022 Datacenter: Azure Edition Hotpatch");
+    assert!(windows.is_ok());
+    assert!(windows.unwrap().supports_hotpatch());
+
+    let windows = Windows::parse("Windows Server 2022 Standard");
+    assert!(windows.is_ok());
+    assert!(!windows.unwrap().supports_hotpatch());
+
+    assert!(Windows::parse("Windows Server 2019 Datacenter: Azure Edition").is_err());
+}
+
+#[test]
+fn test_windows_storage_server_2016_generic() {
+    use os_identifier::Windows;
+
+    let windows = Windows::parse("Windows Storage Server 2016 Standard");
+    assert!(windows.is_ok());
+
+    let windows = windows.unwrap();
+    assert_eq!(windows.product(), "Windows Server 2016".to_string());
+    let canonical_names = windows.to_string();
+    assert_eq!(canonical_names, vec![String::from("Microsoft Windows Server 2016 Storage Server Standard")]);
+}
+
+#[test]
+fn test_windows_server_build_contradicts_product_generic() {
+    use os_identifier::Windows;
+
+    assert!(Windows::parse("Windows Server 2016 Standard 10.0.17763").is_err());
+    assert!(Windows::parse("Windows Server 2012 R2 Standard 6.2.9200").is_err());
+    assert!(Windows::parse("Windows Server 2008 R2 Enterprise SP2 6.1.7601").is_err());
+}
+
+#[test]
+fn test_windows_server_2003_r2_generic() {
+    use os_identifier::Windows;
+
+    let windows = Windows::parse("Windows Server 2003 R2 Standard Service Pack 2");
+    assert!(windows.is_ok());
+
+    let windows = windows.unwrap();
+    assert_eq!(windows.product(), "Windows Server 2003 R2".to_string());
+    let canonical_names = windows.to_string();
+    assert_eq!(canonical_names, vec![String::from("Microsoft Windows Server 2003 R2 Standard SP2")]);
+}
+
+#[test]
+fn test_windows_legacy_client_foreign_build_generic() {
+    use os_identifier::Windows;
+
+    assert!(Windows::parse("Windows 7 Professional Build 9600").is_err());
+    assert!(Windows::parse("Windows Vista Business SP2 (Build 7601)").is_err());
+    assert!(Windows::parse("Windows XP Embedded").is_err());
+}